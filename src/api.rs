@@ -0,0 +1,196 @@
+//! Read-only REST API over the observer snapshot.
+
+use std::sync::{Arc, RwLock};
+
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::routing::get;
+use axum::{Json, Router};
+
+use crate::simulation::{
+    ActiveShocks, Army, BehaviorReport, CurrencyQuote, Epidemics, Episode, NationResearch, NationStrategies, NationTreasuries,
+    ObserverSnapshot, Pilgrimage, RegionalWeather, RelationEntry, RelationSnapshot, War,
+};
+
+type SharedSnapshot = Arc<RwLock<ObserverSnapshot>>;
+
+pub fn router(observer: SharedSnapshot) -> Router {
+    Router::new()
+        .route("/npc/:id/relations", get(npc_relations))
+        .route("/npc/:id/memory", get(npc_memory))
+        .route("/analytics/behavior", get(behavior_report))
+        .route("/analytics/behavior.csv", get(behavior_csv))
+        .route("/wars", get(wars))
+        .route("/armies", get(armies))
+        .route("/pilgrimages", get(pilgrimages))
+        .route("/diplomacy", get(diplomacy))
+        .route("/strategy", get(strategy))
+        .route("/research", get(research))
+        .route("/treasury", get(treasury))
+        .route("/currency", get(currency))
+        .route("/shocks", get(shocks))
+        .route("/weather", get(weather))
+        .route("/epidemics", get(epidemics))
+        .route("/epidemics.csv", get(epidemics_csv))
+        .with_state(observer)
+}
+
+pub async fn serve(addr: &str, observer: SharedSnapshot) -> anyhow::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, router(observer)).await?;
+    Ok(())
+}
+
+async fn npc_relations(
+    State(observer): State<SharedSnapshot>,
+    Path(id): Path<u64>,
+) -> Result<Json<Vec<RelationSnapshot>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !snapshot.entities.iter().any(|entity| entity.id == id) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(Json(snapshot.relations.get(&id).cloned().unwrap_or_default()))
+}
+
+async fn npc_memory(
+    State(observer): State<SharedSnapshot>,
+    Path(id): Path<u64>,
+) -> Result<Json<Vec<Episode>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if !snapshot.entities.iter().any(|entity| entity.id == id) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(Json(snapshot.memories.get(&id).cloned().unwrap_or_default()))
+}
+
+async fn behavior_report(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<BehaviorReport>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.behavior.clone()))
+}
+
+async fn behavior_csv(
+    State(observer): State<SharedSnapshot>,
+) -> Result<([(header::HeaderName, &'static str); 1], String), StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/csv; charset=utf-8")],
+        snapshot.behavior.to_csv(),
+    ))
+}
+
+async fn wars(State(observer): State<SharedSnapshot>) -> Result<Json<Vec<War>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.wars.clone()))
+}
+
+async fn armies(State(observer): State<SharedSnapshot>) -> Result<Json<Vec<Army>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.armies.clone()))
+}
+
+async fn pilgrimages(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<Vec<Pilgrimage>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.pilgrimages.clone()))
+}
+
+async fn diplomacy(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<Vec<RelationEntry>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.diplomacy.clone()))
+}
+
+async fn strategy(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<NationStrategies>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.strategies.clone()))
+}
+
+async fn research(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<NationResearch>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.research.clone()))
+}
+
+async fn treasury(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<NationTreasuries>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.treasuries.clone()))
+}
+
+async fn currency(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<Vec<CurrencyQuote>>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.treasuries.quotes()))
+}
+
+async fn shocks(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<ActiveShocks>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.shocks.clone()))
+}
+
+async fn weather(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<RegionalWeather>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.weather.clone()))
+}
+
+async fn epidemics(
+    State(observer): State<SharedSnapshot>,
+) -> Result<Json<Epidemics>, StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(snapshot.epidemics.clone()))
+}
+
+async fn epidemics_csv(
+    State(observer): State<SharedSnapshot>,
+) -> Result<([(header::HeaderName, &'static str); 1], String), StatusCode> {
+    let snapshot = observer
+        .read()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/csv; charset=utf-8")],
+        snapshot.epidemics.to_csv(),
+    ))
+}
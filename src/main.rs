@@ -1,3 +1,4 @@
+use std::fs::File;
 use std::io::{self, stdout};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -9,17 +10,24 @@ use crossterm::{
 };
 use ratatui::{prelude::*, Terminal};
 use tokio::sync::{watch, Notify};
+use tracing_subscriber::EnvFilter;
 
+mod api;
 mod simulation;
 mod ui;
 
 use simulation::{ObserverSnapshot, SimulationConfig, SimulationWorld};
 
+const API_ADDR: &str = "127.0.0.1:3000";
+/// The TUI owns the terminal, so diagnostics are written here instead.
+const LOG_PATH: &str = "mk3_rust_studio.log";
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    init_tracing()?;
+
     // Simulation Setup
     let config = SimulationConfig {
-        tick_duration: Duration::from_secs(1),
         grid_radius: 10,
         ..Default::default()
     };
@@ -30,7 +38,7 @@ async fn main() -> anyhow::Result<()> {
     let observer = Arc::new(RwLock::new(ObserverSnapshot::default()));
     let shutdown_notify = Arc::new(Notify::new());
 
-    let mut simulation = SimulationWorld::with_observer(config, observer.clone());
+    let mut simulation = SimulationWorld::with_observer(config, observer.clone())?;
     let notify_for_simulation = shutdown_notify.clone();
     let simulation_task = tokio::spawn(async move {
         let mut interval = tokio::time::interval(*tick_duration_rx.borrow());
@@ -51,15 +59,24 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Observer API
+    let api_observer = observer.clone();
+    tokio::spawn(async move {
+        if let Err(err) = api::serve(API_ADDR, api_observer).await {
+            tracing::warn!("observer API unavailable: {err}");
+        }
+    });
+
     // TUI Setup
     let mut terminal = init_terminal()?;
     let mut app_should_run = true;
+    let mut weather_overlay = false;
 
     while app_should_run {
         terminal.draw(|frame| {
             let snapshot = observer.read().expect("Observer lock is poisoned").clone();
             let tick_duration = *tick_duration_tx.borrow();
-            ui::render(frame, &snapshot, tick_duration);
+            ui::render(frame, &snapshot, tick_duration, weather_overlay);
         })?;
 
         if event::poll(Duration::from_millis(100))? {
@@ -79,25 +96,26 @@ async fn main() -> anyhow::Result<()> {
                     KeyCode::Char('r') => {
                         tick_duration_tx.send(initial_tick_duration).ok();
                     }
+                    KeyCode::Char('w') => weather_overlay = !weather_overlay,
                     _ => {}
                 },
-                Event::Mouse(mouse) => {
-                    if mouse.kind == event::MouseEventKind::Down(event::MouseButton::Left) {
-                        // These coordinates are hardcoded based on the UI layout
-                        // A more robust solution would calculate them dynamically
-                        let button_y = 15; // Approximate line number for the buttons
-                        if mouse.row == button_y {
-                            if (1..=3).contains(&mouse.column) { // [-]
-                                let current_duration = *tick_duration_tx.borrow();
-                                let new_duration = current_duration * 2;
-                                tick_duration_tx.send(new_duration).ok();
-                            } else if (5..=7).contains(&mouse.column) { // [+]
-                                let current_duration = *tick_duration_tx.borrow();
-                                let new_duration = (current_duration / 2).max(Duration::from_millis(1));
-                                tick_duration_tx.send(new_duration).ok();
-                            } else if (9..=11).contains(&mouse.column) { // [R]
-                                tick_duration_tx.send(initial_tick_duration).ok();
-                            }
+                Event::Mouse(mouse) if mouse.kind == event::MouseEventKind::Down(event::MouseButton::Left) => {
+                    // These coordinates are hardcoded based on the UI layout
+                    // A more robust solution would calculate them dynamically
+                    let button_y = 15; // Approximate line number for the buttons
+                    if mouse.row == button_y {
+                        if (1..=3).contains(&mouse.column) { // [-]
+                            let current_duration = *tick_duration_tx.borrow();
+                            let new_duration = current_duration * 2;
+                            tick_duration_tx.send(new_duration).ok();
+                        } else if (5..=7).contains(&mouse.column) { // [+]
+                            let current_duration = *tick_duration_tx.borrow();
+                            let new_duration = (current_duration / 2).max(Duration::from_millis(1));
+                            tick_duration_tx.send(new_duration).ok();
+                        } else if (9..=11).contains(&mouse.column) { // [R]
+                            tick_duration_tx.send(initial_tick_duration).ok();
+                        } else if (13..=15).contains(&mouse.column) { // [W]
+                            weather_overlay = !weather_overlay;
                         }
                     }
                 }
@@ -114,6 +132,18 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Sends log output to `LOG_PATH`, at the level `RUST_LOG` asks for or
+/// warnings and above by default.
+fn init_tracing() -> anyhow::Result<()> {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(File::create(LOG_PATH)?)
+        .with_ansi(false)
+        .init();
+    Ok(())
+}
+
 fn init_terminal() -> io::Result<Terminal<impl Backend>> {
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?.execute(event::EnableMouseCapture)?;
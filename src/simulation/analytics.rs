@@ -0,0 +1,281 @@
+//! Empirical behaviour statistics, kept for balancing the AI against its
+//! configured transition tables.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use bevy_ecs::prelude::Resource;
+use serde::Serialize;
+
+use crate::simulation::{BehaviorState, Biome, DecisionModel, Faction, WorldMetadata, behavior_label};
+
+type Counts = HashMap<BehaviorState, HashMap<BehaviorState, u64>>;
+
+fn count_rows(counts: &Counts) -> Vec<Vec<u64>> {
+    BehaviorState::ALL
+        .iter()
+        .map(|from| {
+            BehaviorState::ALL
+                .iter()
+                .map(|to| counts.get(from).and_then(|row| row.get(to)).copied().unwrap_or(0))
+                .collect()
+        })
+        .collect()
+}
+
+/// Observed transition counts, `from -> to`, with those of the NPCs that
+/// decide from the transition tables kept apart for measuring drift.
+#[derive(Debug, Clone, Default)]
+pub struct TransitionCounts {
+    counts: Counts,
+    scripted: Counts,
+}
+
+impl TransitionCounts {
+    fn record(&mut self, from: BehaviorState, to: BehaviorState, model: DecisionModel) {
+        *self.counts.entry(from).or_default().entry(to).or_default() += 1;
+        if model == DecisionModel::TransitionTable {
+            *self.scripted.entry(from).or_default().entry(to).or_default() += 1;
+        }
+    }
+
+    fn matrix(&self, configured: &[Vec<f32>]) -> TransitionMatrix {
+        let counts = count_rows(&self.counts);
+        let observed: Vec<Vec<f32>> = counts.iter().map(|row| normalize_counts(row)).collect();
+
+        // Total variation distance between what the table-driven NPCs did
+        // and what the tables say; utility-driven NPCs never consult them.
+        let drift = count_rows(&self.scripted)
+            .iter()
+            .zip(configured)
+            .map(|(row, configured)| {
+                (row.iter().sum::<u64>() > 0).then(|| {
+                    normalize_counts(row)
+                        .iter()
+                        .zip(configured)
+                        .map(|(o, c)| (o - c).abs())
+                        .sum::<f32>()
+                        * 0.5
+                })
+            })
+            .collect();
+
+        TransitionMatrix {
+            samples: counts.iter().flatten().sum(),
+            counts,
+            observed,
+            drift,
+        }
+    }
+}
+
+fn normalize_counts(row: &[u64]) -> Vec<f32> {
+    let total: u64 = row.iter().sum();
+    row.iter()
+        .map(|count| {
+            if total == 0 {
+                0.0
+            } else {
+                *count as f32 / total as f32
+            }
+        })
+        .collect()
+}
+
+/// Running behaviour statistics across every NPC the engine has seen.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct BehaviorAnalytics {
+    overall: TransitionCounts,
+    by_faction: HashMap<Faction, TransitionCounts>,
+    by_biome: HashMap<Biome, TransitionCounts>,
+    by_season: HashMap<&'static str, TransitionCounts>,
+    /// NPC-ticks spent in each state.
+    occupancy: HashMap<BehaviorState, u64>,
+    /// Total length and number of completed spells per state.
+    dwell: HashMap<BehaviorState, (u64, u64)>,
+    /// Each NPC's current state and the tick it entered it.
+    spells: HashMap<u64, (BehaviorState, u64)>,
+}
+
+impl BehaviorAnalytics {
+    /// Records one NPC's state for this tick, counting the transition from
+    /// the state it held last tick.
+    #[allow(clippy::too_many_arguments)]
+    pub fn observe(
+        &mut self,
+        npc: u64,
+        faction: Faction,
+        model: DecisionModel,
+        biome: Biome,
+        season: &'static str,
+        state: BehaviorState,
+        tick: u64,
+    ) {
+        *self.occupancy.entry(state).or_default() += 1;
+
+        let Some((previous, since)) = self.spells.get(&npc).copied() else {
+            self.spells.insert(npc, (state, tick));
+            return;
+        };
+
+        self.overall.record(previous, state, model);
+        self.by_faction.entry(faction).or_default().record(previous, state, model);
+        self.by_biome.entry(biome).or_default().record(previous, state, model);
+        self.by_season.entry(season).or_default().record(previous, state, model);
+
+        if previous != state {
+            let (ticks, spells) = self.dwell.entry(previous).or_default();
+            *ticks += tick.saturating_sub(since);
+            *spells += 1;
+            self.spells.insert(npc, (state, tick));
+        }
+    }
+
+    /// Drops unfinished spells of NPCs that died or left.
+    pub fn forget_departed(&mut self, present: &HashSet<u64>) {
+        self.spells.retain(|npc, _| present.contains(npc));
+    }
+
+    pub fn report(&self, world_meta: &WorldMetadata) -> BehaviorReport {
+        let configured: Vec<Vec<f32>> = BehaviorState::ALL
+            .iter()
+            .map(|from| {
+                let options = world_meta.behavior_transitions(*from);
+                let total: f32 = options.iter().map(|(_, weight)| *weight).sum();
+                BehaviorState::ALL
+                    .iter()
+                    .map(|to| {
+                        let weight = options
+                            .iter()
+                            .find(|(next, _)| next == to)
+                            .map_or(0.0, |(_, weight)| *weight);
+                        if total > 0.0 { weight / total } else { 0.0 }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let total_ticks: u64 = self.occupancy.values().sum();
+        let occupancy = BehaviorState::ALL
+            .iter()
+            .map(|state| {
+                let ticks = self.occupancy.get(state).copied().unwrap_or(0);
+                StateOccupancy {
+                    state: *state,
+                    label: behavior_label(*state).to_string(),
+                    ticks,
+                    share: if total_ticks == 0 {
+                        0.0
+                    } else {
+                        ticks as f32 / total_ticks as f32
+                    },
+                    average_dwell: self
+                        .dwell
+                        .get(state)
+                        .filter(|(_, spells)| *spells > 0)
+                        .map(|(ticks, spells)| *ticks as f32 / *spells as f32),
+                }
+            })
+            .collect();
+
+        BehaviorReport {
+            states: BehaviorState::ALL.to_vec(),
+            overall: self.overall.matrix(&configured),
+            by_faction: self
+                .by_faction
+                .iter()
+                .map(|(faction, counts)| (*faction, counts.matrix(&configured)))
+                .collect(),
+            by_biome: self
+                .by_biome
+                .iter()
+                .map(|(biome, counts)| (*biome, counts.matrix(&configured)))
+                .collect(),
+            by_season: self
+                .by_season
+                .iter()
+                .map(|(season, counts)| (season.to_string(), counts.matrix(&configured)))
+                .collect(),
+            configured,
+            occupancy,
+        }
+    }
+}
+
+/// One scope's transitions, rows and columns ordered as `BehaviorReport::states`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TransitionMatrix {
+    pub samples: u64,
+    pub counts: Vec<Vec<u64>>,
+    /// Row-normalised empirical probabilities.
+    pub observed: Vec<Vec<f32>>,
+    /// Per source state, half the L1 distance between what table-driven NPCs
+    /// did and the configured row (0 = as designed, 1 = disjoint). `None`
+    /// where no table-driven NPC ever left the state.
+    pub drift: Vec<Option<f32>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StateOccupancy {
+    pub state: BehaviorState,
+    pub label: String,
+    pub ticks: u64,
+    pub share: f32,
+    /// Mean length in ticks of completed spells in this state.
+    pub average_dwell: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BehaviorReport {
+    pub states: Vec<BehaviorState>,
+    /// Row-normalised base weights from the world data, before any modifier.
+    pub configured: Vec<Vec<f32>>,
+    pub overall: TransitionMatrix,
+    pub by_faction: HashMap<Faction, TransitionMatrix>,
+    pub by_biome: HashMap<Biome, TransitionMatrix>,
+    pub by_season: HashMap<String, TransitionMatrix>,
+    pub occupancy: Vec<StateOccupancy>,
+}
+
+impl BehaviorReport {
+    /// Long-form CSV: one row per scope and transition.
+    pub fn to_csv(&self) -> String {
+        let mut scopes: Vec<(&str, String, &TransitionMatrix)> =
+            vec![("overall", String::new(), &self.overall)];
+        let mut factions: Vec<_> = self.by_faction.iter().collect();
+        factions.sort_by_key(|(faction, _)| **faction as u8);
+        scopes.extend(
+            factions
+                .into_iter()
+                .map(|(faction, matrix)| ("faction", format!("{faction:?}"), matrix)),
+        );
+        let mut biomes: Vec<_> = self.by_biome.iter().collect();
+        biomes.sort_by_key(|(biome, _)| **biome as u8);
+        scopes.extend(
+            biomes
+                .into_iter()
+                .map(|(biome, matrix)| ("biome", format!("{biome:?}"), matrix)),
+        );
+        let mut seasons: Vec<_> = self.by_season.iter().collect();
+        seasons.sort_by(|a, b| a.0.cmp(b.0));
+        scopes.extend(
+            seasons
+                .into_iter()
+                .map(|(season, matrix)| ("season", season.clone(), matrix)),
+        );
+
+        let mut csv = String::from("scope,key,from,to,count,observed,configured\n");
+        for (scope, key, matrix) in scopes {
+            for (i, from) in self.states.iter().enumerate() {
+                for (j, to) in self.states.iter().enumerate() {
+                    let _ = writeln!(
+                        csv,
+                        "{scope},{key},{from:?},{to:?},{},{:.4},{:.4}",
+                        matrix.counts[i][j], matrix.observed[i][j], self.configured[i][j],
+                    );
+                }
+            }
+        }
+        csv
+    }
+}
@@ -1,14 +1,17 @@
 //! Entity component definitions for the TERA simulation.
 
+use std::collections::{HashMap, VecDeque};
+
 use bevy_ecs::prelude::Component;
 use serde::{Deserialize, Serialize};
 
-use crate::simulation::Nation;
+use crate::simulation::{AxialCoord, Nation};
 
 #[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Identity {
     pub id: u64,
     pub name: String,
+    pub hangul_name: String,
     pub faction: Faction,
     pub nation: Nation,
 }
@@ -31,6 +34,48 @@ pub struct Attributes {
     pub fame: f32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReputationTier {
+    Unknown,
+    Noted,
+    Renowned,
+    Legendary,
+    Infamous,
+}
+
+/// Infamy and per-faction standing; fame itself lives in `Attributes`.
+#[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
+pub struct Reputation {
+    pub infamy: f32,
+    pub standing: HashMap<Faction, f32>,
+}
+
+impl Reputation {
+    pub fn tier(&self, fame: f32) -> ReputationTier {
+        if self.infamy >= 30.0 && self.infamy > fame {
+            ReputationTier::Infamous
+        } else if fame >= 60.0 {
+            ReputationTier::Legendary
+        } else if fame >= 35.0 {
+            ReputationTier::Renowned
+        } else if fame >= 15.0 {
+            ReputationTier::Noted
+        } else {
+            ReputationTier::Unknown
+        }
+    }
+
+    /// How a faction regards this NPC, from -100 (hostile) to 100 (honoured).
+    pub fn standing_with(&self, faction: Faction) -> f32 {
+        self.standing.get(&faction).copied().unwrap_or(0.0)
+    }
+
+    pub fn adjust_standing(&mut self, faction: Faction, delta: f32) {
+        let standing = self.standing.entry(faction).or_insert(0.0);
+        *standing = (*standing + delta).clamp(-100.0, 100.0);
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Biome {
     Forest,
@@ -40,6 +85,16 @@ pub enum Biome {
     Market,
 }
 
+impl Biome {
+    pub const ALL: [Biome; 5] = [
+        Biome::Forest,
+        Biome::Plains,
+        Biome::Desert,
+        Biome::Village,
+        Biome::Market,
+    ];
+}
+
 #[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
 pub struct Position {
     pub x: f32,
@@ -59,13 +114,87 @@ pub struct ItemStack {
     pub quantity: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Units of each tool, weapon or relic an NPC never sells.
+const KEPT_GEAR: u32 = 1;
+
+impl Inventory {
+    pub fn quantity_of(&self, name: &str) -> u32 {
+        self.items
+            .iter()
+            .filter(|stack| stack.item.name() == name)
+            .map(|stack| stack.quantity)
+            .sum()
+    }
+
+    pub fn total_quantity(&self) -> u32 {
+        self.items.iter().map(|stack| stack.quantity).sum()
+    }
+
+    pub fn add(&mut self, item: ItemKind, quantity: u32) {
+        if let Some(stack) = self.items.iter_mut().find(|stack| stack.item == item) {
+            stack.quantity += quantity;
+        } else {
+            self.items.push(ItemStack { item, quantity });
+        }
+    }
+
+    /// Removes `quantity` units of the named item, returning `false` (and
+    /// leaving the inventory untouched) if not enough are held.
+    pub fn remove(&mut self, name: &str, quantity: u32) -> bool {
+        if self.quantity_of(name) < quantity {
+            return false;
+        }
+
+        let mut remaining = quantity;
+        for stack in self.items.iter_mut().filter(|stack| stack.item.name() == name) {
+            let taken = remaining.min(stack.quantity);
+            stack.quantity -= taken;
+            remaining -= taken;
+        }
+        self.items.retain(|stack| stack.quantity > 0);
+        true
+    }
+
+    /// Takes up to `quantity` units of trade goods and returns them: raw
+    /// resources first, then crafted goods beyond the `KEPT_GEAR` of each
+    /// kind an NPC keeps to work and fight with.
+    pub fn take_for_sale(&mut self, quantity: u32) -> Vec<ItemStack> {
+        let mut taken = Vec::new();
+        let mut remaining = quantity;
+        self.items.sort_by_key(|stack| !matches!(stack.item, ItemKind::Resource(_)));
+        for stack in self.items.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let kept = if matches!(stack.item, ItemKind::Resource(_)) { 0 } else { KEPT_GEAR };
+            let units = remaining.min(stack.quantity.saturating_sub(kept));
+            if units == 0 {
+                continue;
+            }
+            stack.quantity -= units;
+            remaining -= units;
+            taken.push(ItemStack { item: stack.item.clone(), quantity: units });
+        }
+        self.items.retain(|stack| stack.quantity > 0);
+        taken
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ItemKind {
     Resource(String),
     Equipment(String),
     Artifact(String),
 }
 
+impl ItemKind {
+    pub fn name(&self) -> &str {
+        match self {
+            ItemKind::Resource(name) | ItemKind::Equipment(name) | ItemKind::Artifact(name) => name,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Personality {
     pub aggressive: f32,
@@ -82,6 +211,42 @@ pub enum BehaviorState {
     Trade,
     Hunt,
     Rest,
+    Pray,
+    Raid,
+    Patrol,
+    Craft,
+    Migrate,
+}
+
+impl BehaviorState {
+    pub const ALL: [BehaviorState; 11] = [
+        BehaviorState::Idle,
+        BehaviorState::Explore,
+        BehaviorState::Gather,
+        BehaviorState::Trade,
+        BehaviorState::Hunt,
+        BehaviorState::Rest,
+        BehaviorState::Pray,
+        BehaviorState::Raid,
+        BehaviorState::Patrol,
+        BehaviorState::Craft,
+        BehaviorState::Migrate,
+    ];
+}
+
+/// Which decision layer picks an NPC's next behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DecisionModel {
+    /// Weighted draw from the per-state transition tables.
+    TransitionTable,
+    /// Scores every behaviour against the NPC's current needs.
+    Utility,
+}
+
+/// Per-NPC override of the faction's default decision model.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct DecisionPolicy {
+    pub model: DecisionModel,
 }
 
 #[derive(Debug, Clone, Component, Serialize, Deserialize)]
@@ -114,4 +279,218 @@ pub struct Combatants {
 
 }
 
+/// Why a nation went to war.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CasusBelli {
+    BorderDispute,
+    TradeRivalry,
+    HolyWar,
+    Revenge,
+    Expansion,
+    /// Joining an ally's defensive war.
+    AllyCall,
+    /// Rebels fighting to hold the land they broke away with.
+    Independence,
+}
+
+/// What the aggressor wants out of the war.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WarGoal {
+    Conquest,
+    Tribute,
+    Humiliation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PeaceTerms {
+    WhitePeace,
+    Concession,
+    Tribute,
+    Humiliation,
+}
+
+/// A declared war, alive from its declaration until a peace treaty is signed.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct War {
+    pub id: u64,
+    pub aggressor: Nation,
+    pub defender: Nation,
+    pub casus_belli: CasusBelli,
+    pub goal: WarGoal,
+    pub declared_at: u64,
+    /// -100..100; positive favours the aggressor.
+    pub score: f32,
+    pub aggressor_exhaustion: f32,
+    pub defender_exhaustion: f32,
+    pub battles: u32,
+    /// Border hexes where the two nations currently meet.
+    pub front: Vec<AxialCoord>,
+}
+
+impl War {
+    pub fn involves(&self, nation: Nation) -> bool {
+        self.aggressor == nation || self.defender == nation
+    }
+
+    pub fn opponent_of(&self, nation: Nation) -> Nation {
+        if nation == self.aggressor { self.defender } else { self.aggressor }
+    }
+
+    /// War score from `nation`'s side.
+    pub fn score_for(&self, nation: Nation) -> f32 {
+        if nation == self.aggressor { self.score } else { -self.score }
+    }
+
+    pub fn exhaustion_mut(&mut self, nation: Nation) -> &mut f32 {
+        if nation == self.aggressor {
+            &mut self.aggressor_exhaustion
+        } else {
+            &mut self.defender_exhaustion
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ArmyOrder {
+    /// Hold the capital.
+    Garrison,
+    /// March on the objective of a war.
+    Advance,
+    /// Fall back to the capital to recover.
+    Retreat,
+}
+
+/// A field army raised from a nation's military budget.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct Army {
+    pub id: u64,
+    pub nation: Nation,
+    pub size: f32,
+    /// 0..100; a broken army retreats to its capital.
+    pub morale: f32,
+    /// 0..100; drains outside friendly territory.
+    pub supply: f32,
+    pub position: AxialCoord,
+    pub order: ArmyOrder,
+    pub objective: Option<AxialCoord>,
+}
+
+impl Army {
+    /// Fighting weight on the field, before terrain and luck.
+    pub fn strength(&self) -> f32 {
+        self.size * (self.morale / 100.0) * (0.5 + self.supply / 200.0)
+    }
+}
+
+/// A band of the faithful walking to the holiest hex in the world.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct Pilgrimage {
+    pub nation: Nation,
+    /// Ids of the NPCs walking with the band.
+    pub pilgrims: Vec<u64>,
+    pub position: AxialCoord,
+    pub destination: AxialCoord,
+    pub departed_at: u64,
+}
+
+/// Marks an NPC walking with a pilgrim band: it prays on the road and
+/// does no other work until the band arrives or breaks up.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct Pilgrim;
+
+/// A production run in progress; inputs are consumed when the job starts.
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct CraftingJob {
+    pub recipe: String,
+    pub progress: f32,
+    pub work_ticks: f32,
+}
+
+/// What last hurt an NPC; reported as the cause of death if health runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HarmCause {
+    Hazard,
+    Hunting,
+    Combat,
+    Exhaustion,
+    /// Worn down by a macro shock such as plague.
+    Calamity,
+    /// Died of an infection.
+    Disease,
+}
+
+/// How a skirmish between two NPCs ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CombatOutcome {
+    AttackerVictory,
+    DefenderVictory,
+    Standoff,
+}
+
+#[derive(Debug, Clone, Component, Serialize, Deserialize)]
+pub struct Injury {
+    pub cause: HarmCause,
+}
+
+/// An NPC carrying the sickness; NPCs without it or `Immunity` can catch it.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct Infection {
+    pub contracted_at: u64,
+}
+
+/// Left behind by a recovery; the NPC cannot fall sick again until it wears off.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct Immunity {
+    pub until: u64,
+}
+
+/// Something an NPC lived through, kept in its episodic memory.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Episode {
+    Trade { tick: u64, biome: Biome, profit: f32 },
+    Attacked { tick: u64, biome: Biome, attacker: u64 },
+    Visited { tick: u64, biome: Biome },
+    Gathering { tick: u64, biome: Biome, convener: u64 },
+}
+
+const MEMORY_CAPACITY: usize = 24;
 
+/// Bounded episodic memory; the oldest episode is forgotten first.
+#[derive(Debug, Clone, Default, Component, Serialize, Deserialize)]
+pub struct Memory {
+    pub episodes: VecDeque<Episode>,
+}
+
+impl Memory {
+    pub fn remember(&mut self, episode: Episode) {
+        if self.episodes.len() == MEMORY_CAPACITY {
+            self.episodes.pop_front();
+        }
+        self.episodes.push_back(episode);
+    }
+
+    pub fn ambushes_in(&self, biome: Biome) -> usize {
+        self.episodes
+            .iter()
+            .filter(|episode| matches!(episode, Episode::Attacked { biome: b, .. } if *b == biome))
+            .count()
+    }
+
+    /// Average remembered trade profit in a biome, if the NPC has traded there.
+    pub fn trade_profit_in(&self, biome: Biome) -> Option<f32> {
+        let profits: Vec<f32> = self
+            .episodes
+            .iter()
+            .filter_map(|episode| match episode {
+                Episode::Trade { biome: b, profit, .. } if *b == biome => Some(*profit),
+                _ => None,
+            })
+            .collect();
+        if profits.is_empty() {
+            None
+        } else {
+            Some(profits.iter().sum::<f32>() / profits.len() as f32)
+        }
+    }
+}
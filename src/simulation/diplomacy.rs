@@ -0,0 +1,136 @@
+//! Nation-to-nation relations: opinion, trust and the treaties between them.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::simulation::{Nation, pair_key};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TreatyKind {
+    NonAggression,
+    TradePact,
+    Alliance,
+    /// The weaker party follows its overlord's lead and pays it tribute.
+    Vassalage,
+}
+
+impl TreatyKind {
+    pub const ALL: [TreatyKind; 4] = [
+        TreatyKind::NonAggression,
+        TreatyKind::TradePact,
+        TreatyKind::Alliance,
+        TreatyKind::Vassalage,
+    ];
+}
+
+/// How two nations regard each other. Opinion runs -100..100; trust 0..100.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NationRelation {
+    pub opinion: f32,
+    pub trust: f32,
+    /// Treaties in force, with the tick each was signed.
+    pub treaties: HashMap<TreatyKind, u64>,
+    /// Set while the pair is bound by vassalage.
+    pub overlord: Option<Nation>,
+    /// Average gap in faith across the pair's shared border; derived by `faith_system`.
+    pub religious_tension: f32,
+}
+
+impl NationRelation {
+    pub fn has(&self, kind: TreatyKind) -> bool {
+        self.treaties.contains_key(&kind)
+    }
+
+    pub fn adjust(&mut self, opinion: f32, trust: f32) {
+        self.opinion = (self.opinion + opinion).clamp(-100.0, 100.0);
+        self.trust = (self.trust + trust).clamp(0.0, 100.0);
+    }
+}
+
+/// Symmetric matrix of relations between every pair of nations.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct DiplomacyMatrix {
+    relations: HashMap<(Nation, Nation), NationRelation>,
+}
+
+impl DiplomacyMatrix {
+    pub fn get(&self, a: Nation, b: Nation) -> Option<&NationRelation> {
+        self.relations.get(&pair_key(a, b))
+    }
+
+    pub fn relation_mut(&mut self, a: Nation, b: Nation) -> &mut NationRelation {
+        self.relations.entry(pair_key(a, b)).or_default()
+    }
+
+    pub fn opinion(&self, a: Nation, b: Nation) -> f32 {
+        self.get(a, b).map_or(0.0, |relation| relation.opinion)
+    }
+
+    pub fn has_treaty(&self, a: Nation, b: Nation, kind: TreatyKind) -> bool {
+        self.get(a, b).is_some_and(|relation| relation.has(kind))
+    }
+
+    /// The sharpest religious tension along any of `nation`'s borders.
+    pub fn religious_tension(&self, nation: Nation) -> f32 {
+        self.relations
+            .iter()
+            .filter(|((a, b), _)| *a == nation || *b == nation)
+            .map(|(_, relation)| relation.religious_tension)
+            .fold(0.0, f32::max)
+    }
+
+    /// Nations `nation` is allied with, or whose overlord or vassal it is.
+    pub fn allies_of(&self, nation: Nation) -> Vec<Nation> {
+        let mut allies: Vec<Nation> = self
+            .relations
+            .iter()
+            .filter(|(_, relation)| {
+                relation.has(TreatyKind::Alliance) || relation.has(TreatyKind::Vassalage)
+            })
+            .filter_map(|((a, b), _)| {
+                if *a == nation {
+                    Some(*b)
+                } else if *b == nation {
+                    Some(*a)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        allies.sort_by_key(|ally| ally.name());
+        allies
+    }
+
+    pub fn snapshot(&self) -> Vec<RelationEntry> {
+        let mut entries: Vec<RelationEntry> = self
+            .relations
+            .iter()
+            .map(|((a, b), relation)| RelationEntry {
+                nations: (*a, *b),
+                opinion: relation.opinion,
+                trust: relation.trust,
+                treaties: TreatyKind::ALL
+                    .into_iter()
+                    .filter(|kind| relation.has(*kind))
+                    .collect(),
+                overlord: relation.overlord,
+                religious_tension: relation.religious_tension,
+            })
+            .collect();
+        entries.sort_by_key(|entry| (entry.nations.0.name(), entry.nations.1.name()));
+        entries
+    }
+}
+
+/// Serializable row of the diplomacy matrix.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationEntry {
+    pub nations: (Nation, Nation),
+    pub opinion: f32,
+    pub trust: f32,
+    pub treaties: Vec<TreatyKind>,
+    pub overlord: Option<Nation>,
+    pub religious_tension: f32,
+}
@@ -0,0 +1,90 @@
+//! Epidemics: how many of each nation's people are susceptible, sick and
+//! recovered over time, and the outbreak each nation is living through.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+
+use bevy_ecs::prelude::Resource;
+use serde::Serialize;
+
+use crate::simulation::Nation;
+
+/// Samples kept per nation; the oldest is dropped first.
+const CURVE_LENGTH: usize = 500;
+
+/// One point on a nation's infection curve.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct InfectionSample {
+    pub tick: u64,
+    pub susceptible: u32,
+    pub infected: u32,
+    pub recovered: u32,
+}
+
+/// An epidemic running in a nation, from the tick cases crossed the
+/// threshold until the last of the sick recovers or dies.
+#[derive(Debug, Clone, Serialize)]
+pub struct Outbreak {
+    pub started_at: u64,
+    /// Most people sick at once.
+    pub peak: u32,
+    /// People who fell sick since the outbreak began.
+    pub cases: u32,
+    pub deaths: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InfectionCurve {
+    pub samples: VecDeque<InfectionSample>,
+    pub outbreak: Option<Outbreak>,
+    /// People who fell sick since the world began.
+    pub total_cases: u32,
+}
+
+#[derive(Debug, Clone, Default, Resource, Serialize)]
+pub struct Epidemics(pub HashMap<Nation, InfectionCurve>);
+
+impl Epidemics {
+    pub fn curve_mut(&mut self, nation: Nation) -> &mut InfectionCurve {
+        self.0.entry(nation).or_default()
+    }
+
+    pub fn record(&mut self, nation: Nation, sample: InfectionSample) {
+        let samples = &mut self.curve_mut(nation).samples;
+        samples.push_back(sample);
+        if samples.len() > CURVE_LENGTH {
+            samples.pop_front();
+        }
+    }
+
+    /// The latest sample for `nation`, if any was taken.
+    pub fn latest(&self, nation: Nation) -> Option<InfectionSample> {
+        self.0.get(&nation).and_then(|curve| curve.samples.back().copied())
+    }
+
+    pub fn outbreak(&self, nation: Nation) -> Option<&Outbreak> {
+        self.0.get(&nation).and_then(|curve| curve.outbreak.as_ref())
+    }
+
+    /// Long-form CSV: one row per nation and sample.
+    pub fn to_csv(&self) -> String {
+        let mut nations: Vec<_> = self.0.iter().collect();
+        nations.sort_by_key(|(nation, _)| nation.name());
+
+        let mut csv = String::from("nation,tick,susceptible,infected,recovered\n");
+        for (nation, curve) in nations {
+            for sample in &curve.samples {
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{},{}",
+                    nation.name(),
+                    sample.tick,
+                    sample.susceptible,
+                    sample.infected,
+                    sample.recovered,
+                );
+            }
+        }
+        csv
+    }
+}
@@ -6,7 +6,29 @@ use crate::simulation::Nation;
 use bevy_ecs::prelude::Resource;
 use serde::{Deserialize, Serialize};
 
-use crate::simulation::{BehaviorState, Biome, Faction};
+use crate::simulation::{
+    AxialCoord, BehaviorState, Biome, CasusBelli, CombatOutcome, Faction, HarmCause, Identity, PeaceTerms,
+    Position, WarGoal, WorldMetadata, behavior_label, casus_belli_label, combat_outcome_label,
+    BudgetLine, Doctrine, Outbreak, ShockMetadata, TechEffect, TreatyKind, budget_line_label, shock_modifier_label, doctrine_label, faction_label, harm_cause_label, peace_terms_label, tech_effect_label, treaty_label, war_goal_label,
+};
+
+fn nation_list(nations: &[Nation]) -> String {
+    nations.iter().map(|nation| nation.name()).collect::<Vec<_>>().join(", ")
+}
+
+/// Names whoever a skirmish killed, e.g. " (치명상: 가더)".
+pub fn casualties(attacker: &EventActor, defender: &EventActor, attacker_slain: bool, defender_slain: bool) -> String {
+    let slain: Vec<&str> = [(attacker, attacker_slain), (defender, defender_slain)]
+        .into_iter()
+        .filter(|(_, slain)| *slain)
+        .map(|(actor, _)| actor.hangul_name.as_str())
+        .collect();
+    if slain.is_empty() {
+        String::new()
+    } else {
+        format!(" (치명상: {})", slain.join(", "))
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -22,14 +44,162 @@ pub enum WorldEventKind {
         cohesion_level: String,
     },
     MacroShock {
-        stressor: String,
-        catalyst: String,
-        projected_impact: String,
+        shock: String,
+        nations: Vec<Nation>,
+        /// Biomes the shock reaches; empty for everywhere.
+        biomes: Vec<String>,
+        /// Display labels for what the shock does.
+        effects: Vec<String>,
+        /// Ticks the shock is set to run; zero once it has passed.
+        duration: u64,
+        boon: bool,
+        ended: bool,
     },
     Warfare {
+        war_id: u64,
         winner: Nation,
         loser: Nation,
-        territory_change: f32,
+        /// The border hex the battle was fought over.
+        contested: AxialCoord,
+        captured: bool,
+        /// Score from the winner's side after the battle.
+        war_score: f32,
+    },
+    WarDeclared {
+        war_id: u64,
+        aggressor: Nation,
+        defender: Nation,
+        casus_belli: CasusBelli,
+        casus_belli_label: String,
+        goal: WarGoal,
+        goal_label: String,
+    },
+    WarEnded {
+        war_id: u64,
+        aggressor: Nation,
+        defender: Nation,
+        victor: Option<Nation>,
+        terms: PeaceTerms,
+        terms_label: String,
+        duration: u64,
+    },
+    Treaty {
+        party: Nation,
+        counterparty: Nation,
+        treaty: TreatyKind,
+        treaty_label: String,
+        signed: bool,
+        /// Why the treaty was broken; `None` when signed.
+        reason: Option<String>,
+    },
+    PolicyShift {
+        nation: Nation,
+        doctrine: Doctrine,
+        doctrine_label: String,
+        focus: BudgetLine,
+        focus_label: String,
+        reason: String,
+    },
+    ArmyClash {
+        winner: Nation,
+        loser: Nation,
+        position: AxialCoord,
+        /// Soldiers each side lost.
+        winner_losses: f32,
+        loser_losses: f32,
+        /// The losing army was destroyed rather than driven back.
+        routed: bool,
+    },
+    Rebellion {
+        nation: Nation,
+        rebels: Nation,
+        /// The rebels raised the banner of a fallen nation rather than a new one.
+        reborn: bool,
+        seat: AxialCoord,
+        hexes: u32,
+        /// Residents who went over to the rebels.
+        defectors: u32,
+        war_id: u64,
+    },
+    Succession {
+        nation: Nation,
+        /// Stability the old regime fell at.
+        stability: f32,
+        reason: String,
+    },
+    Collapse {
+        nation: Nation,
+        /// Whoever holds the fallen capital, if anyone.
+        conqueror: Option<Nation>,
+        absorbed: u32,
+        migrated: u32,
+    },
+    CulturalShift {
+        /// The culture, or nation, that won the hexes over.
+        nation: Nation,
+        from: Nation,
+        hexes: u32,
+        /// The hexes changed hands, not just their people's culture.
+        defected: bool,
+    },
+    Debasement {
+        nation: Nation,
+        /// Silver left in one coin after the cut.
+        silver: f32,
+        /// Coin the recoinage put in the state's coffers.
+        minted: f32,
+        price_level: f32,
+    },
+    Epidemic {
+        nation: Nation,
+        /// People sick when the outbreak was declared, or at its height once
+        /// it has passed.
+        infected: u32,
+        /// The nation's people when the outbreak was declared; zero once it
+        /// has passed.
+        population: u32,
+        /// People who fell sick over the whole outbreak; zero while it runs.
+        cases: u32,
+        deaths: u32,
+        ended: bool,
+    },
+    Breakthrough {
+        nation: Nation,
+        tech: String,
+        line: BudgetLine,
+        line_label: String,
+        /// Display labels for what the technology does.
+        effects: Vec<String>,
+    },
+    Crafting {
+        artisan: EventActor,
+        recipe: String,
+        output: String,
+        quantity: u32,
+    },
+    Death {
+        npc: EventActor,
+        cause: HarmCause,
+        cause_label: String,
+        heir: Option<String>,
+    },
+    Demographics {
+        nation: Nation,
+        births: u32,
+        immigrants: u32,
+        emigrants: u32,
+        population: u32,
+    },
+    Combat {
+        attacker: EventActor,
+        defender: EventActor,
+        outcome: CombatOutcome,
+        outcome_label: String,
+        loot_currency: f32,
+        loot_items: Option<String>,
+        /// Whether each side died of its wounds, whoever won.
+        attacker_slain: bool,
+        defender_slain: bool,
     },
 }
 
@@ -45,6 +215,7 @@ pub enum Sentiment {
 pub struct EventActor {
     pub id: u64,
     pub name: String,
+    pub hangul_name: String,
     pub nation: crate::simulation::Nation,
     pub faction: Faction,
     pub faction_label: String,
@@ -54,6 +225,32 @@ pub struct EventActor {
     pub behavior_hint_label: String,
 }
 
+impl EventActor {
+    pub fn new(
+        identity: &Identity,
+        position: &Position,
+        behavior_hint: BehaviorState,
+        world_meta: &WorldMetadata,
+    ) -> Self {
+        Self {
+            id: identity.id,
+            name: identity.name.clone(),
+            hangul_name: identity.hangul_name.clone(),
+            nation: identity.nation,
+            faction: identity.faction,
+            faction_label: faction_label(identity.faction).to_string(),
+            biome: position.biome,
+            biome_label: world_meta
+                .biomes
+                .get(&position.biome)
+                .map(|meta| meta.label.to_string())
+                .unwrap_or_else(|| format!("{:?}", position.biome)),
+            behavior_hint,
+            behavior_hint_label: behavior_label(behavior_hint).to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldEvent {
     pub tick: u64,
@@ -67,8 +264,29 @@ impl WorldEvent {
         match &self.kind {
             WorldEventKind::Trade { .. } => "무역",
             WorldEventKind::Social { .. } => "사회",
-            WorldEventKind::MacroShock { .. } => "거시충격",
+            WorldEventKind::MacroShock { ended: false, .. } => "거시충격",
+            WorldEventKind::MacroShock { ended: true, .. } => "충격 해소",
             WorldEventKind::Warfare { .. } => "전쟁",
+            WorldEventKind::WarDeclared { .. } => "선전포고",
+            WorldEventKind::WarEnded { .. } => "강화",
+            WorldEventKind::ArmyClash { .. } => "야전",
+            WorldEventKind::PolicyShift { .. } => "정책",
+            WorldEventKind::Treaty { signed: true, .. } => "조약",
+            WorldEventKind::Treaty { signed: false, .. } => "파기",
+            WorldEventKind::Rebellion { reborn: false, .. } => "내전",
+            WorldEventKind::Rebellion { reborn: true, .. } => "부활",
+            WorldEventKind::Succession { .. } => "정변",
+            WorldEventKind::Collapse { .. } => "멸망",
+            WorldEventKind::CulturalShift { defected: false, .. } => "동화",
+            WorldEventKind::CulturalShift { defected: true, .. } => "문화 편입",
+            WorldEventKind::Debasement { .. } => "화폐 절하",
+            WorldEventKind::Epidemic { ended: false, .. } => "역병 유행",
+            WorldEventKind::Epidemic { ended: true, .. } => "역병 종식",
+            WorldEventKind::Breakthrough { .. } => "기술",
+            WorldEventKind::Crafting { .. } => "제작",
+            WorldEventKind::Death { .. } => "사망",
+            WorldEventKind::Demographics { .. } => "인구",
+            WorldEventKind::Combat { .. } => "전투",
         }
     }
 
@@ -76,8 +294,40 @@ impl WorldEvent {
         match &self.kind {
             WorldEventKind::Trade { .. } => Sentiment::Positive,
             WorldEventKind::Social { .. } => Sentiment::Positive,
-            WorldEventKind::MacroShock { .. } => Sentiment::Negative,
+            WorldEventKind::MacroShock { boon: true, ended: false, .. } => Sentiment::Positive,
+            WorldEventKind::MacroShock { boon: false, ended: false, .. } => Sentiment::Negative,
+            WorldEventKind::MacroShock { boon: true, ended: true, .. } => Sentiment::Neutral,
+            WorldEventKind::MacroShock { boon: false, ended: true, .. } => Sentiment::Positive,
             WorldEventKind::Warfare { .. } => Sentiment::Negative,
+            WorldEventKind::WarDeclared { .. } => Sentiment::Negative,
+            WorldEventKind::WarEnded { .. } => Sentiment::Positive,
+            WorldEventKind::ArmyClash { .. } => Sentiment::Negative,
+            WorldEventKind::PolicyShift { .. } => Sentiment::Neutral,
+            WorldEventKind::Treaty { signed: true, .. } => Sentiment::Positive,
+            WorldEventKind::Treaty { signed: false, .. } => Sentiment::Negative,
+            WorldEventKind::Rebellion { .. } => Sentiment::Negative,
+            WorldEventKind::Succession { .. } => Sentiment::Neutral,
+            WorldEventKind::Collapse { .. } => Sentiment::Negative,
+            WorldEventKind::CulturalShift { .. } => Sentiment::Neutral,
+            WorldEventKind::Debasement { .. } => Sentiment::Negative,
+            WorldEventKind::Epidemic { ended: false, .. } => Sentiment::Negative,
+            WorldEventKind::Epidemic { ended: true, .. } => Sentiment::Positive,
+            WorldEventKind::Breakthrough { .. } => Sentiment::Positive,
+            WorldEventKind::Crafting { .. } => Sentiment::Positive,
+            WorldEventKind::Death { .. } => Sentiment::Negative,
+            WorldEventKind::Demographics {
+                births,
+                immigrants,
+                emigrants,
+                ..
+            } => {
+                if births + immigrants >= *emigrants {
+                    Sentiment::Positive
+                } else {
+                    Sentiment::Negative
+                }
+            }
+            WorldEventKind::Combat { .. } => Sentiment::Negative,
         }
     }
 
@@ -90,7 +340,7 @@ impl WorldEvent {
                 market_pressure,
             } => format!(
                 "{} 님이 {} 거래를 조율합니다 | 압력: {}",
-                actor.name, trade_focus, market_pressure
+                actor.hangul_name, trade_focus, market_pressure
             ),
             WorldEventKind::Social {
                 convener,
@@ -98,19 +348,294 @@ impl WorldEvent {
                 cohesion_level,
             } => format!(
                 "{} 님이 \"{}\" 주제로 모임을 주관합니다 | 응집도: {}",
-                convener.name, gathering_theme, cohesion_level
+                convener.hangul_name, gathering_theme, cohesion_level
             ),
             WorldEventKind::MacroShock {
-                stressor,
-                catalyst,
-                projected_impact,
+                shock,
+                nations,
+                ended: true,
+                ..
+            } => format!("{}이(가) {}에서 물러갔습니다", shock, nation_list(nations)),
+            WorldEventKind::MacroShock {
+                shock,
+                nations,
+                biomes,
+                effects,
+                duration,
+                ..
+            } => format!(
+                "{}이(가) {}에 닥쳤습니다 | 지역: {} | {}틱 예상 | 효과: {}",
+                shock,
+                nation_list(nations),
+                if biomes.is_empty() { "전역".to_string() } else { biomes.join(", ") },
+                duration,
+                effects.join(", ")
+            ),
+            WorldEventKind::Warfare {
+                winner,
+                loser,
+                contested,
+                captured,
+                war_score,
+                ..
+            } => format!(
+                "{}가 {}와의 전투에서 승리했습니다 | ({}, {}) {} | 전쟁 점수 {:+.0}",
+                winner.name(),
+                loser.name(),
+                contested.q,
+                contested.r,
+                if *captured { "점령" } else { "방어" },
+                war_score
+            ),
+            WorldEventKind::WarDeclared {
+                aggressor,
+                defender,
+                casus_belli_label,
+                goal_label,
+                ..
+            } => format!(
+                "{}가 {}에 선전포고했습니다 | 명분: {} | 목표: {}",
+                aggressor.name(),
+                defender.name(),
+                casus_belli_label,
+                goal_label
+            ),
+            WorldEventKind::WarEnded {
+                aggressor,
+                defender,
+                victor,
+                terms_label,
+                duration,
+                ..
+            } => format!(
+                "{}–{} 전쟁이 {}(으)로 끝났습니다 | 승자: {} | {}틱 지속",
+                aggressor.name(),
+                defender.name(),
+                terms_label,
+                victor.map_or("없음", |nation| nation.name()),
+                duration
+            ),
+            WorldEventKind::Treaty {
+                party,
+                counterparty,
+                treaty_label,
+                signed: true,
+                ..
+            } => format!(
+                "{}와 {}가 {}을 맺었습니다",
+                party.name(),
+                counterparty.name(),
+                treaty_label
+            ),
+            WorldEventKind::Treaty {
+                party,
+                counterparty,
+                treaty_label,
+                reason,
+                ..
             } => format!(
-                "{} | 촉발 요인: {} | 영향: {}",
-                stressor, catalyst, projected_impact
+                "{}가 {}와의 {}을 파기했습니다 | 사유: {}",
+                party.name(),
+                counterparty.name(),
+                treaty_label,
+                reason.as_deref().unwrap_or("불명")
             ),
-            WorldEventKind::Warfare { winner, loser, territory_change } => format!(
-                "{}가 {}와의 전쟁에서 승리하여 영토 {:.2}를 획득했습니다.",
-                winner.name(), loser.name(), territory_change
+            WorldEventKind::PolicyShift {
+                nation,
+                doctrine_label,
+                focus_label,
+                reason,
+                ..
+            } => format!(
+                "{}가 국정 중점을 {}(으)로 옮겼습니다 | 교리: {} | 사유: {}",
+                nation.name(),
+                focus_label,
+                doctrine_label,
+                reason
+            ),
+            WorldEventKind::ArmyClash {
+                winner,
+                loser,
+                position,
+                winner_losses,
+                loser_losses,
+                routed,
+            } => format!(
+                "{} 군이 ({}, {})에서 {} 군을 {} | 손실 {:.0} 대 {:.0}",
+                winner.name(),
+                position.q,
+                position.r,
+                loser.name(),
+                if *routed { "궤멸시켰습니다" } else { "격퇴했습니다" },
+                winner_losses,
+                loser_losses
+            ),
+            WorldEventKind::Rebellion {
+                nation,
+                rebels,
+                reborn,
+                seat,
+                hexes,
+                defectors,
+                ..
+            } => format!(
+                "{}에서 반란이 일어나 {}가 {} | 거점 ({}, {}) · {}개 지역 · 이탈 주민 {}명",
+                nation.name(),
+                rebels.name(),
+                if *reborn { "다시 일어섰습니다" } else { "독립을 선포했습니다" },
+                seat.q,
+                seat.r,
+                hexes,
+                defectors
+            ),
+            WorldEventKind::Succession {
+                nation,
+                stability,
+                reason,
+            } => format!(
+                "{}의 정권이 무너지고 새 지도부가 들어섰습니다 | 안정도 {:.0} | 사유: {}",
+                nation.name(),
+                stability,
+                reason
+            ),
+            WorldEventKind::Collapse {
+                nation,
+                conqueror,
+                absorbed,
+                migrated,
+            } => format!(
+                "{}가 멸망했습니다 | 정복자: {} | 흡수 {}명 · 이주 {}명",
+                nation.name(),
+                conqueror.map_or("없음", |nation| nation.name()),
+                absorbed,
+                migrated
+            ),
+            WorldEventKind::CulturalShift {
+                nation,
+                from,
+                hexes,
+                defected: false,
+            } => format!(
+                "{} 문화가 {} 문화의 헥스 {}곳을 동화했습니다",
+                nation.name(),
+                from.name(),
+                hexes
+            ),
+            WorldEventKind::CulturalShift {
+                nation,
+                from,
+                hexes,
+                defected: true,
+            } => format!(
+                "{}의 헥스 {}곳이 문화적 유대를 따라 {}에 평화롭게 편입되었습니다",
+                from.name(),
+                hexes,
+                nation.name()
+            ),
+            WorldEventKind::Debasement {
+                nation,
+                silver,
+                minted,
+                price_level,
+            } => format!(
+                "{}가 화폐의 은 함량을 {:.0}%로 깎고 {:.0}닢을 새로 주조했습니다 | 물가 {:.2}",
+                nation.name(),
+                silver * 100.0,
+                minted,
+                price_level
+            ),
+            WorldEventKind::Epidemic {
+                nation,
+                infected,
+                cases,
+                deaths,
+                ended: true,
+                ..
+            } => format!(
+                "{}의 역병이 잦아들었습니다 | 누적 감염 {}명 · 최다 {}명 · 사망 {}명",
+                nation.name(),
+                cases,
+                infected,
+                deaths
+            ),
+            WorldEventKind::Epidemic {
+                nation,
+                infected,
+                population,
+                ..
+            } => format!(
+                "{}에 역병이 번졌습니다 | 환자 {}명 / 인구 {}명",
+                nation.name(),
+                infected,
+                population
+            ),
+            WorldEventKind::Breakthrough {
+                nation,
+                tech,
+                line_label,
+                effects,
+                ..
+            } => format!(
+                "{}가 {}을(를) 완성했습니다 | 분야: {} | 효과: {}",
+                nation.name(),
+                tech,
+                line_label,
+                effects.join(", ")
+            ),
+            WorldEventKind::Crafting {
+                artisan,
+                recipe,
+                output,
+                quantity,
+            } => format!(
+                "{} 님이 {} 공정으로 {} {}개를 완성했습니다",
+                artisan.hangul_name, recipe, output, quantity
+            ),
+            WorldEventKind::Death {
+                npc,
+                cause_label,
+                heir,
+                ..
+            } => format!(
+                "{} 님이 {}(으)로 사망했습니다 | 유산: {}",
+                npc.hangul_name,
+                cause_label,
+                heir.as_deref().unwrap_or("소실")
+            ),
+            WorldEventKind::Demographics {
+                nation,
+                births,
+                immigrants,
+                emigrants,
+                population,
+            } => format!(
+                "{} 인구 변동: 출생 {} · 이주 유입 {} · 이주 유출 {} | 총 {}명",
+                nation.name(),
+                births,
+                immigrants,
+                emigrants,
+                population
+            ),
+            WorldEventKind::Combat {
+                attacker,
+                defender,
+                outcome_label,
+                loot_currency,
+                loot_items,
+                attacker_slain,
+                defender_slain,
+                ..
+            } => format!(
+                "{} 님이 {} 님을 습격했습니다 | 결과: {}{} | 전리품: {:.1}{}",
+                attacker.hangul_name,
+                defender.hangul_name,
+                outcome_label,
+                casualties(attacker, defender, *attacker_slain, *defender_slain),
+                loot_currency,
+                loot_items
+                    .as_deref()
+                    .map(|items| format!(" · {}", items))
+                    .unwrap_or_default()
             ),
         }
     }
@@ -159,38 +684,494 @@ impl WorldEvent {
         tick: u64,
         epoch: &str,
         season: &str,
-        stressor: String,
-        catalyst: String,
-        projected_impact: String,
+        shock: &ShockMetadata,
+        nations: &[Nation],
+        duration: u64,
+        world_meta: &WorldMetadata,
     ) -> Self {
         Self {
             tick,
             epoch: epoch.to_string(),
             season: season.to_string(),
             kind: WorldEventKind::MacroShock {
-                stressor,
-                catalyst,
-                projected_impact,
+                shock: shock.label.to_string(),
+                nations: nations.to_vec(),
+                biomes: shock
+                    .biomes
+                    .iter()
+                    .filter_map(|biome| world_meta.biomes.get(biome))
+                    .map(|biome| biome.label.to_string())
+                    .collect(),
+                effects: shock.modifiers.iter().map(|modifier| shock_modifier_label(*modifier)).collect(),
+                duration,
+                boon: shock.boon,
+                ended: false,
+            },
+        }
+    }
+
+    pub fn macro_shock_ended(tick: u64, epoch: &str, season: &str, shock: &ShockMetadata, nations: &[Nation]) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::MacroShock {
+                shock: shock.label.to_string(),
+                nations: nations.to_vec(),
+                biomes: Vec::new(),
+                effects: Vec::new(),
+                duration: 0,
+                boon: shock.boon,
+                ended: true,
             },
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn warfare(
         tick: u64,
         epoch: &str,
         season: &str,
+        war_id: u64,
         winner: Nation,
         loser: Nation,
-        territory_change: f32,
+        contested: AxialCoord,
+        captured: bool,
+        war_score: f32,
     ) -> Self {
         Self {
             tick,
             epoch: epoch.to_string(),
             season: season.to_string(),
             kind: WorldEventKind::Warfare {
+                war_id,
+                winner,
+                loser,
+                contested,
+                captured,
+                war_score,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn war_declared(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        war_id: u64,
+        aggressor: Nation,
+        defender: Nation,
+        casus_belli: CasusBelli,
+        goal: WarGoal,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::WarDeclared {
+                war_id,
+                aggressor,
+                defender,
+                casus_belli,
+                casus_belli_label: casus_belli_label(casus_belli).to_string(),
+                goal,
+                goal_label: war_goal_label(goal).to_string(),
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn war_ended(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        war_id: u64,
+        aggressor: Nation,
+        defender: Nation,
+        victor: Option<Nation>,
+        terms: PeaceTerms,
+        duration: u64,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::WarEnded {
+                war_id,
+                aggressor,
+                defender,
+                victor,
+                terms,
+                terms_label: peace_terms_label(terms).to_string(),
+                duration,
+            },
+        }
+    }
+
+    pub fn policy_shift(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        doctrine: Doctrine,
+        focus: BudgetLine,
+        reason: &str,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::PolicyShift {
+                nation,
+                doctrine,
+                doctrine_label: doctrine_label(doctrine).to_string(),
+                focus,
+                focus_label: budget_line_label(focus).to_string(),
+                reason: reason.to_string(),
+            },
+        }
+    }
+
+    pub fn treaty_signed(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        party: Nation,
+        counterparty: Nation,
+        treaty: TreatyKind,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Treaty {
+                party,
+                counterparty,
+                treaty,
+                treaty_label: treaty_label(treaty).to_string(),
+                signed: true,
+                reason: None,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn treaty_broken(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        party: Nation,
+        counterparty: Nation,
+        treaty: TreatyKind,
+        reason: &str,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Treaty {
+                party,
+                counterparty,
+                treaty,
+                treaty_label: treaty_label(treaty).to_string(),
+                signed: false,
+                reason: Some(reason.to_string()),
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn army_clash(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        winner: Nation,
+        loser: Nation,
+        position: AxialCoord,
+        winner_losses: f32,
+        loser_losses: f32,
+        routed: bool,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::ArmyClash {
                 winner,
                 loser,
-                territory_change,
+                position,
+                winner_losses,
+                loser_losses,
+                routed,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn rebellion(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        rebels: Nation,
+        reborn: bool,
+        seat: AxialCoord,
+        hexes: u32,
+        defectors: u32,
+        war_id: u64,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Rebellion {
+                nation,
+                rebels,
+                reborn,
+                seat,
+                hexes,
+                defectors,
+                war_id,
+            },
+        }
+    }
+
+    pub fn succession(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        stability: f32,
+        reason: &str,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Succession {
+                nation,
+                stability,
+                reason: reason.to_string(),
+            },
+        }
+    }
+
+    pub fn collapse(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        conqueror: Option<Nation>,
+        absorbed: u32,
+        migrated: u32,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Collapse {
+                nation,
+                conqueror,
+                absorbed,
+                migrated,
+            },
+        }
+    }
+
+    pub fn cultural_shift(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        from: Nation,
+        hexes: u32,
+        defected: bool,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::CulturalShift {
+                nation,
+                from,
+                hexes,
+                defected,
+            },
+        }
+    }
+
+    pub fn debasement(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        silver: f32,
+        minted: f32,
+        price_level: f32,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Debasement {
+                nation,
+                silver,
+                minted,
+                price_level,
+            },
+        }
+    }
+
+    pub fn epidemic(tick: u64, epoch: &str, season: &str, nation: Nation, infected: u32, population: u32) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Epidemic {
+                nation,
+                infected,
+                population,
+                cases: 0,
+                deaths: 0,
+                ended: false,
+            },
+        }
+    }
+
+    pub fn epidemic_ended(tick: u64, epoch: &str, season: &str, nation: Nation, outbreak: &Outbreak) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Epidemic {
+                nation,
+                infected: outbreak.peak,
+                population: 0,
+                cases: outbreak.cases,
+                deaths: outbreak.deaths,
+                ended: true,
+            },
+        }
+    }
+
+    pub fn breakthrough(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        tech: &str,
+        line: BudgetLine,
+        effects: &[TechEffect],
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Breakthrough {
+                nation,
+                tech: tech.to_string(),
+                line,
+                line_label: budget_line_label(line).to_string(),
+                effects: effects.iter().map(|effect| tech_effect_label(*effect)).collect(),
+            },
+        }
+    }
+
+    pub fn crafting(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        artisan: EventActor,
+        recipe: String,
+        output: String,
+        quantity: u32,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Crafting {
+                artisan,
+                recipe,
+                output,
+                quantity,
+            },
+        }
+    }
+
+    pub fn death(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        npc: EventActor,
+        cause: HarmCause,
+        heir: Option<String>,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Death {
+                npc,
+                cause,
+                cause_label: harm_cause_label(cause).to_string(),
+                heir,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn demographics(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        nation: Nation,
+        births: u32,
+        immigrants: u32,
+        emigrants: u32,
+        population: u32,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Demographics {
+                nation,
+                births,
+                immigrants,
+                emigrants,
+                population,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn combat(
+        tick: u64,
+        epoch: &str,
+        season: &str,
+        attacker: EventActor,
+        defender: EventActor,
+        outcome: CombatOutcome,
+        loot_currency: f32,
+        loot_items: Option<String>,
+        attacker_slain: bool,
+        defender_slain: bool,
+    ) -> Self {
+        Self {
+            tick,
+            epoch: epoch.to_string(),
+            season: season.to_string(),
+            kind: WorldEventKind::Combat {
+                attacker,
+                defender,
+                outcome,
+                outcome_label: combat_outcome_label(outcome).to_string(),
+                loot_currency,
+                loot_items,
+                attacker_slain,
+                defender_slain,
             },
         }
     }
@@ -217,6 +1198,10 @@ impl WorldEventLog {
         self.events.push_back(event);
     }
 
+    pub fn events_at(&self, tick: u64) -> impl Iterator<Item = &WorldEvent> {
+        self.events.iter().rev().take_while(move |event| event.tick == tick)
+    }
+
     pub fn snapshot(&self) -> Vec<WorldEvent> {
         self.events.iter().cloned().collect()
     }
@@ -2,7 +2,11 @@ use bevy_ecs::prelude::{Component, Entity, Resource};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::simulation::Nation;
+use crate::simulation::{Biome, Nation};
+
+/// Half-width of the world space NPC positions and biome anchors live in;
+/// the hex map is stretched to cover it whatever its radius.
+const WORLD_EXTENT: f32 = 7.0;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Component)]
 pub struct AxialCoord {
@@ -25,15 +29,79 @@ impl AxialCoord {
             AxialCoord::new(self.q - 1, self.r + 1),
         ]
     }
+
+    pub fn distance(&self, other: &AxialCoord) -> i32 {
+        ((self.q - other.q).abs() + (self.q + self.r - other.q - other.r).abs() + (self.r - other.r).abs()) / 2
+    }
+
+    /// Centre of the hex in world space on a map of the given radius.
+    pub fn to_world(self, radius: i32) -> (f32, f32) {
+        let scale = WORLD_EXTENT / radius.max(1) as f32;
+        (
+            (self.q as f32 + self.r as f32 / 2.0) * scale,
+            self.r as f32 * 3.0_f32.sqrt() / 2.0 * scale,
+        )
+    }
 }
 
 #[derive(Debug, Clone, Component, Serialize, Deserialize)]
 pub struct Hex {
     pub owner: Nation,
+    /// Terrain of the hex: the biome whose anchor lies nearest.
+    pub biome: Biome,
+}
+
+/// How much of a hex's population follows the Temple of Suns, 0..100.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct Faith {
+    pub adherence: f32,
+}
+
+/// The culture a hex's people hold to, and how firmly, 0..100.
+#[derive(Debug, Clone, Copy, Component, Serialize, Deserialize)]
+pub struct Heritage {
+    pub culture: Nation,
+    pub cohesion: f32,
+}
+
+/// Sickness lingering in a hex's wells, markets and lodgings, 0..100.
+#[derive(Debug, Clone, Copy, Default, Component, Serialize, Deserialize)]
+pub struct Contagion {
+    pub load: f32,
 }
 
 #[derive(Debug, Clone, Resource, Serialize, Deserialize, Default)]
 pub struct HexGrid {
     pub hexes: HashMap<AxialCoord, Entity>,
     pub radius: i32,
+    /// Seat of each nation; territory cut off from it cannot be held.
+    pub capitals: HashMap<Nation, AxialCoord>,
+}
+
+impl HexGrid {
+    /// The hex under a world-space position, or the nearest one for
+    /// positions beyond the edge of the map.
+    pub fn coord_at(&self, x: f32, y: f32) -> AxialCoord {
+        let scale = WORLD_EXTENT / self.radius.max(1) as f32;
+        let r = y / (3.0_f32.sqrt() / 2.0 * scale);
+        let q = x / scale - r / 2.0;
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        // Cube rounding: fix up whichever axis drifted furthest.
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        let rounded = AxialCoord::new(rq as i32, rr as i32);
+        if self.hexes.contains_key(&rounded) {
+            return rounded;
+        }
+        self.hexes
+            .keys()
+            .copied()
+            .min_by_key(|coord| (coord.distance(&rounded), coord.q, coord.r))
+            .unwrap_or(rounded)
+    }
 }
@@ -2,7 +2,10 @@
 
 use colored::Color;
 
-use crate::simulation::{BehaviorState, Faction, Sentiment};
+use crate::simulation::{
+    BehaviorState, BudgetLine, CasusBelli, CombatOutcome, Doctrine, Faction, HarmCause,
+    PeaceTerms, ReputationTier, Sentiment, ShockModifier, TechEffect, TreatyKind, WarGoal, Weather,
+};
 
 pub fn behavior_label(state: BehaviorState) -> &'static str {
     match state {
@@ -12,6 +15,11 @@ pub fn behavior_label(state: BehaviorState) -> &'static str {
         BehaviorState::Trade => "거래",
         BehaviorState::Hunt => "사냥",
         BehaviorState::Rest => "회복",
+        BehaviorState::Pray => "기도",
+        BehaviorState::Raid => "약탈",
+        BehaviorState::Patrol => "순찰",
+        BehaviorState::Craft => "공예",
+        BehaviorState::Migrate => "이주",
     }
 }
 
@@ -26,6 +34,127 @@ pub fn faction_label(faction: Faction) -> &'static str {
     }
 }
 
+pub fn harm_cause_label(cause: HarmCause) -> &'static str {
+    match cause {
+        HarmCause::Hazard => "지형 재해",
+        HarmCause::Hunting => "사냥 중 부상",
+        HarmCause::Combat => "전투",
+        HarmCause::Exhaustion => "탈진",
+        HarmCause::Calamity => "재난",
+        HarmCause::Disease => "질병",
+    }
+}
+
+pub fn combat_outcome_label(outcome: CombatOutcome) -> &'static str {
+    match outcome {
+        CombatOutcome::AttackerVictory => "습격 성공",
+        CombatOutcome::DefenderVictory => "격퇴",
+        CombatOutcome::Standoff => "교착",
+    }
+}
+
+pub fn casus_belli_label(casus_belli: CasusBelli) -> &'static str {
+    match casus_belli {
+        CasusBelli::BorderDispute => "국경 분쟁",
+        CasusBelli::TradeRivalry => "교역 경쟁",
+        CasusBelli::HolyWar => "성전",
+        CasusBelli::Revenge => "설욕",
+        CasusBelli::Expansion => "팽창 야욕",
+        CasusBelli::AllyCall => "동맹 의무",
+        CasusBelli::Independence => "독립 투쟁",
+    }
+}
+
+pub fn doctrine_label(doctrine: Doctrine) -> &'static str {
+    match doctrine {
+        Doctrine::Militarist => "군국주의",
+        Doctrine::Mercantile => "중상주의",
+        Doctrine::Theocratic => "신정주의",
+        Doctrine::Scholarly => "학문주의",
+    }
+}
+
+pub fn budget_line_label(line: BudgetLine) -> &'static str {
+    match line {
+        BudgetLine::Military => "군사",
+        BudgetLine::Economy => "경제",
+        BudgetLine::Science => "과학",
+        BudgetLine::Culture => "문화",
+        BudgetLine::Diplomacy => "외교",
+        BudgetLine::Religion => "종교",
+    }
+}
+
+pub fn treaty_label(kind: TreatyKind) -> &'static str {
+    match kind {
+        TreatyKind::NonAggression => "불가침 조약",
+        TreatyKind::TradePact => "통상 조약",
+        TreatyKind::Alliance => "동맹",
+        TreatyKind::Vassalage => "종속 조약",
+    }
+}
+
+pub fn war_goal_label(goal: WarGoal) -> &'static str {
+    match goal {
+        WarGoal::Conquest => "영토 정복",
+        WarGoal::Tribute => "공물 강요",
+        WarGoal::Humiliation => "위신 실추",
+    }
+}
+
+pub fn peace_terms_label(terms: PeaceTerms) -> &'static str {
+    match terms {
+        PeaceTerms::WhitePeace => "현상 유지 강화",
+        PeaceTerms::Concession => "영토 할양",
+        PeaceTerms::Tribute => "배상금 지불",
+        PeaceTerms::Humiliation => "굴욕 조약",
+    }
+}
+
+pub fn tech_effect_label(effect: TechEffect) -> String {
+    match effect {
+        TechEffect::GatherYield(factor) => format!("채집 수확 {:+.0}%", (factor - 1.0) * 100.0),
+        TechEffect::UnlockRecipe(recipe) => format!("제작법 {}", recipe),
+        TechEffect::ArmyStrength(factor) => format!("군세 {:+.0}%", (factor - 1.0) * 100.0),
+        TechEffect::UpkeepRelief(share) => format!("유지비 -{:.0}%", share * 100.0),
+    }
+}
+
+pub fn shock_modifier_label(modifier: ShockModifier) -> String {
+    match modifier {
+        ShockModifier::GatherYield(factor) => format!("채집 수확 {:+.0}%", (factor - 1.0) * 100.0),
+        ShockModifier::TradeYield(factor) => format!("교역 수익 {:+.0}%", (factor - 1.0) * 100.0),
+        ShockModifier::Prices(factor) => format!("물가 {:+.0}%", (factor - 1.0) * 100.0),
+        ShockModifier::Metric(line, delta) => format!("{} {:+.2}/틱", budget_line_label(line), delta),
+        ShockModifier::Stability(delta) => format!("안정 {:+.2}/틱", delta),
+        ShockModifier::Behavior(state, factor) => {
+            format!("{} 성향 {:+.0}%", behavior_label(state), (factor - 1.0) * 100.0)
+        }
+        ShockModifier::Health(loss) => format!("체력 -{:.2}/틱", loss),
+        ShockModifier::Contagion(chance) => format!("감염 {:.1}%/틱", chance * 100.0),
+    }
+}
+
+pub fn weather_label(weather: Weather) -> &'static str {
+    match weather {
+        Weather::Clear => "맑음",
+        Weather::Rain => "비",
+        Weather::Heat => "폭염",
+        Weather::Storm => "폭풍",
+        Weather::Sandstorm => "모래폭풍",
+    }
+}
+
+pub fn reputation_label(tier: ReputationTier) -> &'static str {
+    match tier {
+        ReputationTier::Unknown => "무명",
+        ReputationTier::Noted => "주목받는",
+        ReputationTier::Renowned => "명망 높은",
+        ReputationTier::Legendary => "전설적인",
+        ReputationTier::Infamous => "악명 높은",
+    }
+}
+
 pub fn behavior_color(state: BehaviorState) -> Color {
     match state {
         BehaviorState::Idle => Color::BrightBlack,
@@ -34,6 +163,11 @@ pub fn behavior_color(state: BehaviorState) -> Color {
         BehaviorState::Trade => Color::BrightCyan,
         BehaviorState::Hunt => Color::BrightRed,
         BehaviorState::Rest => Color::Magenta,
+        BehaviorState::Pray => Color::BrightYellow,
+        BehaviorState::Raid => Color::Red,
+        BehaviorState::Patrol => Color::Blue,
+        BehaviorState::Craft => Color::Green,
+        BehaviorState::Migrate => Color::BrightMagenta,
     }
 }
 
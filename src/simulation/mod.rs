@@ -3,27 +3,47 @@ use std::sync::{Arc, RwLock};
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::Schedule;
 use std::collections::{HashMap, HashSet};
-use rand::{Rng, SeedableRng};
+use rand::SeedableRng;
 use rand::rngs::SmallRng;
 
+pub mod analytics;
 pub mod components;
+pub mod diplomacy;
+pub mod disease;
 pub mod events;
 pub mod grid;
 pub mod localization;
+pub mod naming;
 pub mod nation;
 pub mod observer;
+pub mod relations;
+pub mod research;
 pub mod resources;
+pub mod shocks;
+pub mod strategy;
 pub mod systems;
+pub mod treasury;
+pub mod weather;
 pub mod world;
 
+pub use analytics::*;
 pub use components::*;
+pub use diplomacy::*;
+pub use disease::*;
 pub use events::*;
 pub use grid::*;
 pub use localization::*;
+pub use naming::*;
 pub use nation::*;
 pub use observer::*;
+pub use relations::*;
+pub use research::*;
 pub use resources::*;
+pub use shocks::*;
+pub use strategy::*;
 pub use systems::*;
+pub use treasury::*;
+pub use weather::*;
 pub use world::*;
 
 pub struct SimulationWorld {
@@ -33,21 +53,31 @@ pub struct SimulationWorld {
 }
 
 impl SimulationWorld {
-    #[allow(dead_code)]
-    pub fn new(config: SimulationConfig) -> Self {
-        Self::with_observer(config, Arc::new(RwLock::new(ObserverSnapshot::default())))
-    }
-
     pub fn with_observer(
         config: SimulationConfig,
         observer: Arc<RwLock<ObserverSnapshot>>,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
+        let world_meta = WorldMetadata::default();
+        world_meta.validate()?;
+
         let mut world = World::default();
         world.insert_resource(config);
         world.insert_resource(AllNationMetrics::default());
         world.insert_resource(WorldTime::default());
-        world.insert_resource(WorldMetadata::default());
+        world.insert_resource(world_meta);
         world.insert_resource(WorldEventLog::default());
+        world.insert_resource(RelationshipGraph::default());
+        world.insert_resource(BehaviorAnalytics::default());
+        world.insert_resource(WarLedger::default());
+        world.insert_resource(ArmyRoster::default());
+        world.insert_resource(DiplomacyMatrix::default());
+        world.insert_resource(NationStrategies::default());
+        world.insert_resource(PolityLedger::default());
+        world.insert_resource(NationResearch::default());
+        world.insert_resource(NationTreasuries::default());
+        world.insert_resource(ActiveShocks::default());
+        world.insert_resource(RegionalWeather::default());
+        world.insert_resource(Epidemics::default());
 
         seed_entities(&mut world);
         seed_grid(&mut world);
@@ -55,22 +85,57 @@ impl SimulationWorld {
         let mut schedule = Schedule::default();
         schedule.add_systems(
             (
-                ai_state_transition_system,
-                combat_cleanup_system, // Clean up combat from previous tick
-                economy_system,
-                warfare_system, // Handles starting new combat
-                territory_system,
-                event_generation_system,
-                logging_system,
+                // NPC decisions and the economy they drive.
+                (
+                    weather_system,
+                    ai_state_transition_system,
+                    behavior_analytics_system,
+                    travel_system,
+                    npc_combat_system,
+                    combat_cleanup_system, // Clean up combat from previous tick
+                    strategy_system,
+                    research_system,
+                    macro_shock_system,
+                    economy_system,
+                    monetary_system,
+                    crafting_system,
+                    vitals_system,
+                    disease_system,
+                    mortality_system,
+                    population_system,
+                )
+                    .chain(),
+                // Nations: armies, wars, treaties and the borders they leave.
+                (
+                    army_logistics_system,
+                    army_engagement_system,
+                    warfare_system, // Handles starting new combat
+                    faith_system,
+                    pilgrimage_system,
+                    culture_system,
+                    diplomacy_system,
+                    territory_system,
+                    collapse_system,
+                    stability_system,
+                    rebellion_system,
+                )
+                    .chain(),
+                (
+                    event_generation_system,
+                    reputation_system,
+                    relationship_system,
+                    logging_system,
+                )
+                    .chain(),
             )
                 .chain(),
         );
 
-        Self {
+        Ok(Self {
             world,
             schedule,
             observer,
-        }
+        })
     }
 
     pub fn tick(&mut self) {
@@ -91,9 +156,19 @@ impl SimulationWorld {
         // We need to construct a new HexGrid snapshot because the resource now holds entities.
         let grid_snapshot = {
             let mut hexes = HashMap::new();
-            let mut query = self.world.query::<(&AxialCoord, &Hex)>();
-            for (coord, hex) in query.iter(&self.world) {
-                hexes.insert(*coord, observer::HexSnapshot { owner: hex.owner });
+            let mut query = self.world.query::<(&AxialCoord, &Hex, &Faith, &Heritage, &Contagion)>();
+            for (coord, hex, faith, heritage, contagion) in query.iter(&self.world) {
+                hexes.insert(
+                    *coord,
+                    observer::HexSnapshot {
+                        owner: hex.owner,
+                        biome: hex.biome,
+                        adherence: faith.adherence,
+                        culture: heritage.culture,
+                        cohesion: heritage.cohesion,
+                        contagion: contagion.load,
+                    },
+                );
             }
             observer::HexGridSnapshot { hexes, radius: self.world.resource::<HexGrid>().radius }
         };
@@ -110,14 +185,25 @@ impl SimulationWorld {
 
         let mut entity_query = self
             .world
-            .query::<(&Identity, &Position, &Behavior, &Inventory, &Attributes)>();
+            .query::<(
+                &Identity,
+                &Position,
+                &Behavior,
+                &Inventory,
+                &Attributes,
+                &Reputation,
+                Option<&DecisionPolicy>,
+                Option<&Infection>,
+            )>();
 
         let entities = entity_query
             .iter(&self.world)
-            .map(
-                |(identity, position, behavior, inventory, attributes)| EntitySnapshot {
+            .map(|(identity, position, behavior, inventory, attributes, reputation, policy, infection)| {
+                let tier = reputation.tier(attributes.fame);
+                EntitySnapshot {
                     id: identity.id,
                     name: identity.name.clone(),
+                    hangul_name: identity.hangul_name.clone(),
                     faction: identity.faction,
                     faction_label: faction_label(identity.faction).to_string(),
                     biome: position.biome,
@@ -129,12 +215,57 @@ impl SimulationWorld {
                     behavior_state: behavior.state,
                     behavior_label: behavior_label(behavior.state).to_string(),
                     currency: inventory.currency,
+                    health: attributes.health,
+                    stamina: attributes.stamina,
+                    infected: infection.is_some(),
                     wealth: attributes.wealth,
                     fame: attributes.fame,
-                },
-            )
+                    infamy: reputation.infamy,
+                    reputation_tier: tier,
+                    reputation_label: reputation_label(tier).to_string(),
+                    decision_model: policy
+                        .map(|policy| policy.model)
+                        .unwrap_or_else(|| world_meta.faction_decision_model(identity.faction)),
+                }
+            })
             .collect::<Vec<_>>();
 
+        let relations = {
+            let graph = self.world.resource::<RelationshipGraph>();
+            let names: HashMap<u64, &str> = entities
+                .iter()
+                .map(|entity| (entity.id, entity.name.as_str()))
+                .collect();
+            entities
+                .iter()
+                .map(|entity| {
+                    let mut outgoing: Vec<_> = graph.outgoing(entity.id).collect();
+                    outgoing.sort_by(|a, b| b.1.regard().total_cmp(&a.1.regard()));
+                    let edges = outgoing
+                        .into_iter()
+                        .filter_map(|(other, relation)| {
+                            names.get(&other).map(|name| RelationSnapshot {
+                                id: other,
+                                name: name.to_string(),
+                                affinity: relation.affinity,
+                                trust: relation.trust,
+                                rivalry: relation.rivalry,
+                            })
+                        })
+                        .collect();
+                    (entity.id, edges)
+                })
+                .collect()
+        };
+
+        let memories = {
+            let mut query = self.world.query::<(&Identity, &Memory)>();
+            query
+                .iter(&self.world)
+                .map(|(identity, memory)| (identity.id, memory.episodes.iter().copied().collect()))
+                .collect()
+        };
+
         let combat_hexes = {
             let mut combat_hexes = HashSet::new();
             let mut query = self.world.query::<(&AxialCoord, &InCombat)>();
@@ -144,14 +275,71 @@ impl SimulationWorld {
             combat_hexes
         };
 
+        let behavior = self
+            .world
+            .resource::<BehaviorAnalytics>()
+            .report(&world_meta);
+
+        let wars = {
+            let mut query = self.world.query::<&War>();
+            let mut wars: Vec<War> = query.iter(&self.world).cloned().collect();
+            wars.sort_by_key(|war| war.id);
+            wars
+        };
+
+        let diplomacy = self.world.resource::<DiplomacyMatrix>().snapshot();
+        let strategies = self.world.resource::<NationStrategies>().clone();
+        let research = self.world.resource::<NationResearch>().clone();
+        let treasuries = self.world.resource::<NationTreasuries>().clone();
+        let shocks = self.world.resource::<ActiveShocks>().clone();
+        let weather = self.world.resource::<RegionalWeather>().clone();
+        let epidemics = self.world.resource::<Epidemics>().clone();
+
+        let armies = {
+            let mut query = self.world.query::<&Army>();
+            let mut armies: Vec<Army> = query.iter(&self.world).cloned().collect();
+            armies.sort_by_key(|army| army.id);
+            armies
+        };
+
+        let pilgrimages = {
+            let mut query = self.world.query::<&Pilgrimage>();
+            let mut pilgrimages: Vec<Pilgrimage> = query.iter(&self.world).cloned().collect();
+            pilgrimages.sort_by_key(|band| (band.departed_at, band.nation.name()));
+            pilgrimages
+        };
+
         if let Ok(mut snapshot) = self.observer.write() {
-            snapshot.update(tick, epoch, season, &metrics, grid_snapshot, entities, events, combat_hexes);
+            snapshot.update(
+                tick,
+                epoch,
+                season,
+                &metrics,
+                grid_snapshot,
+                entities,
+                relations,
+                memories,
+                events,
+                combat_hexes,
+                behavior,
+                wars,
+                armies,
+                pilgrimages,
+                diplomacy,
+                strategies,
+                research,
+                treasuries,
+                shocks,
+                weather,
+                epidemics,
+            );
         }
     }
 }
 
 fn seed_grid(world: &mut World) {
     let config = world.resource::<SimulationConfig>().clone();
+    let world_meta = world.resource::<WorldMetadata>().clone();
     let radius = config.grid_radius;
     let mut hex_entities = HashMap::new();
 
@@ -172,11 +360,25 @@ fn seed_grid(world: &mut World) {
                 Nation::Tera // Southwest
             };
 
-            let hex_entity = world.spawn((coord, Hex { owner })).id();
+            let (x, y) = coord.to_world(radius);
+            let biome = world_meta.nearest_biome(x, y);
+            // Village shrines start out as the faith's strongholds.
+            let adherence = if biome == Biome::Village { 40.0 } else { 25.0 };
+            let heritage = Heritage { culture: owner, cohesion: 60.0 };
+            let hex_entity = world
+                .spawn((coord, Hex { owner, biome }, Faith { adherence }, heritage, Contagion::default()))
+                .id();
             hex_entities.insert(coord, hex_entity);
         }
     }
-    world.insert_resource(HexGrid { hexes: hex_entities, radius });
+    // Each capital sits halfway out along the middle of its nation's sector.
+    let reach = (radius / 2).max(1);
+    let capitals = HashMap::from([
+        (Nation::Aqua, AxialCoord::new(reach, 0)),
+        (Nation::Sora, AxialCoord::new(-reach, reach)),
+        (Nation::Tera, AxialCoord::new(0, -reach)),
+    ]);
+    world.insert_resource(HexGrid { hexes: hex_entities, radius, capitals });
 }
 
 fn seed_entities(world: &mut World) {
@@ -190,6 +392,7 @@ fn seed_entities(world: &mut World) {
             Identity {
                 id: 1,
                 name: "Calix".to_string(),
+                hangul_name: "칼릭스".to_string(),
                 faction: Faction::MerchantGuild,
                 nation: Tera,
             },
@@ -214,11 +417,14 @@ fn seed_entities(world: &mut World) {
                 curious: 0.5,
             },
             Behavior { state: Idle },
+            Reputation::default(),
+            None,
         ),
         (
             Identity {
                 id: 2,
                 name: "Rena".to_string(),
+                hangul_name: "레나".to_string(),
                 faction: Faction::BanditClans,
                 nation: Sora,
             },
@@ -243,11 +449,17 @@ fn seed_entities(world: &mut World) {
                 curious: 0.4,
             },
             Behavior { state: Explore },
+            Reputation {
+                infamy: 40.0,
+                ..Default::default()
+            },
+            Some(DecisionPolicy { model: DecisionModel::Utility }),
         ),
         (
             Identity {
                 id: 3,
                 name: "Aria".to_string(),
+                hangul_name: "아리아".to_string(),
                 faction: Faction::ExplorersLeague,
                 nation: Aqua,
             },
@@ -269,11 +481,14 @@ fn seed_entities(world: &mut World) {
                 curious: 0.7,
             },
             Behavior { state: Gather },
+            Reputation::default(),
+            None,
         ),
         (
             Identity {
                 id: 4,
                 name: "Lys".to_string(),
+                hangul_name: "리스".to_string(),
                 faction: Faction::TempleOfSuns,
                 nation: Tera,
             },
@@ -298,17 +513,91 @@ fn seed_entities(world: &mut World) {
                 curious: 0.6,
             },
             Behavior { state: Idle },
+            Reputation::default(),
+            None,
         ),
     ];
 
-    for (identity, position, inventory, attributes, personality, behavior) in npc_templates {
-        world.spawn((
+    let seed = world.resource::<SimulationConfig>().world_seed;
+    let mut names = NameRegistry::new(seed);
+    let mut next_id = 1;
+    let seeded = npc_templates.len();
+    for (identity, position, inventory, attributes, personality, behavior, reputation, policy) in
+        npc_templates
+    {
+        next_id = next_id.max(identity.id + 1);
+        names.register(&identity.name, &identity.hangul_name);
+        let mut npc = world.spawn((
             identity,
             position,
             inventory,
             attributes,
             personality,
             behavior,
+            reputation,
+            Memory::default(),
         ));
+        if let Some(policy) = policy {
+            npc.insert(policy);
+        }
+    }
+
+    // Fill out the rest of the starting population procedurally.
+    let target = world.resource::<SimulationConfig>().initial_population;
+    let mut rng = SmallRng::seed_from_u64(seed);
+    let mut ledger = PopulationLedger {
+        next_id,
+        ..Default::default()
+    };
+    for _ in seeded..target {
+        let id = ledger.allocate_id();
+        let nation = draw_nation(&mut rng, &world_meta);
+        world.spawn(generate_npc(&mut rng, &mut names, id, nation, None, &world_meta));
+    }
+    world.insert_resource(ledger);
+    world.insert_resource(names);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Coin in every purse and coffer, coin the mints have struck, and coin
+    /// migrants have carried in net of what they took away.
+    fn money(sim: &mut SimulationWorld) -> (f32, f32, f32) {
+        let purses: f32 = sim.world.query::<&Inventory>().iter(&sim.world).map(|inventory| inventory.currency).sum();
+        let treasuries = sim.world.resource::<NationTreasuries>();
+        let coffers: f32 = treasuries.0.values().map(|treasury| treasury.balance + treasury.payroll).sum();
+        let printed: f32 = treasuries.0.values().map(|treasury| treasury.coinage.printed).sum();
+        let ledger = sim.world.resource::<PopulationLedger>();
+        (purses + coffers, printed, ledger.coin_arrived - ledger.coin_departed)
+    }
+
+    #[test]
+    fn coin_is_only_minted_or_carried_by_migrants() {
+        let mut sim = SimulationWorld::with_observer(
+            SimulationConfig {
+                grid_radius: 10,
+                ..Default::default()
+            },
+            Arc::new(RwLock::new(ObserverSnapshot::default())),
+        )
+        .unwrap();
+        let (start, start_printed, start_migration) = money(&mut sim);
+
+        // Coin changing hands between nations is only conserved in sum while
+        // every currency still trades at par.
+        let mut ticks = 0;
+        while ticks < 300
+            && sim.world.resource::<NationTreasuries>().0.values().all(|treasury| treasury.exchange_rate() == 1.0)
+        {
+            sim.tick();
+            ticks += 1;
+        }
+        assert!(ticks >= 100, "currencies left par after {ticks} ticks");
+
+        let (end, printed, migration) = money(&mut sim);
+        let unexplained = (end - start) - (printed - start_printed) - (migration - start_migration);
+        assert!(unexplained.abs() < 0.5, "{unexplained} coin appeared or vanished over {ticks} ticks");
     }
 }
@@ -0,0 +1,169 @@
+//! Seeded, per-nation name generation with romanised and Hangul forms.
+
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::Resource;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{Faction, Nation, Phoneme, WorldMetadata, weighted_choice};
+
+const HANGUL_BASE: u32 = 0xAC00;
+const MAX_ATTEMPTS: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedName {
+    pub roman: String,
+    pub hangul: String,
+}
+
+/// Draws a phoneme by its weight.
+fn pick(rng: &mut SmallRng, phonemes: &[Phoneme]) -> Option<Phoneme> {
+    let options: Vec<(Phoneme, f32)> = phonemes.iter().map(|phoneme| (*phoneme, phoneme.weight)).collect();
+    weighted_choice(&options, rng)
+}
+
+fn compose_syllable(onset: u32, vowel: u32, coda: u32) -> char {
+    char::from_u32(HANGUL_BASE + (onset * 21 + vowel) * 28 + coda).unwrap_or('?')
+}
+
+fn capitalize(roman: &str) -> String {
+    let mut chars = roman.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Hands out world-unique NPC names. Every name ever issued (or registered by
+/// hand) stays reserved, in both scripts, so the chronicle never confuses two
+/// people: several onsets share a jamo, so distinct romanised names can still
+/// meet in Hangul.
+#[derive(Debug, Resource)]
+pub struct NameRegistry {
+    rng: SmallRng,
+    taken: HashSet<String>,
+    taken_hangul: HashSet<String>,
+}
+
+impl NameRegistry {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: SmallRng::seed_from_u64(seed),
+            taken: HashSet::new(),
+            taken_hangul: HashSet::new(),
+        }
+    }
+
+    pub fn register(&mut self, roman: &str, hangul: &str) {
+        self.taken.insert(roman.to_lowercase());
+        self.taken_hangul.insert(hangul.to_string());
+    }
+
+    fn is_taken(&self, roman: &str, hangul: &str) -> bool {
+        self.taken.contains(&roman.to_lowercase()) || self.taken_hangul.contains(hangul)
+    }
+
+    pub fn generate(
+        &mut self,
+        nation: Nation,
+        faction: Faction,
+        world_meta: &WorldMetadata,
+    ) -> GeneratedName {
+        let mut candidate = GeneratedName {
+            roman: String::new(),
+            hangul: String::new(),
+        };
+
+        for _ in 0..MAX_ATTEMPTS {
+            candidate = self.compose(nation, faction, world_meta);
+            if !candidate.roman.is_empty() && !self.is_taken(&candidate.roman, &candidate.hangul) {
+                self.register(&candidate.roman, &candidate.hangul);
+                return candidate;
+            }
+        }
+
+        // The tables are exhausted for this draw; number the name to keep it unique.
+        let stem = candidate;
+        let mut ordinal = 2;
+        loop {
+            let roman = format!("{} {}", stem.roman, ordinal);
+            let hangul = format!("{} {}", stem.hangul, ordinal);
+            if !self.is_taken(&roman, &hangul) {
+                self.register(&roman, &hangul);
+                return GeneratedName { roman, hangul };
+            }
+            ordinal += 1;
+        }
+    }
+
+    fn compose(&mut self, nation: Nation, faction: Faction, world_meta: &WorldMetadata) -> GeneratedName {
+        let naming = &world_meta.naming;
+        let mut roman = String::new();
+        let mut hangul = String::new();
+
+        if let Some(phonology) = naming.nations.get(&nation) {
+            let (min, max) = phonology.syllables;
+            let count = self.rng.gen_range(min.max(1)..=max.max(min).max(1));
+
+            for _ in 0..count {
+                let (Some(onset), Some(vowel)) = (
+                    pick(&mut self.rng, &phonology.onsets),
+                    pick(&mut self.rng, &phonology.vowels),
+                ) else {
+                    break;
+                };
+                let coda = if self.rng.gen_bool(phonology.coda_chance.clamp(0.0, 1.0) as f64) {
+                    pick(&mut self.rng, &phonology.codas)
+                } else {
+                    None
+                };
+
+                roman.push_str(onset.roman);
+                roman.push_str(vowel.roman);
+                if let Some(coda) = coda {
+                    roman.push_str(coda.roman);
+                }
+                hangul.push(compose_syllable(
+                    onset.jamo,
+                    vowel.jamo,
+                    coda.map_or(0, |coda| coda.jamo),
+                ));
+            }
+        }
+
+        if let Some(endings) = naming.faction_endings.get(&faction)
+            && !endings.is_empty()
+            && self.rng.gen_bool(naming.ending_chance.clamp(0.0, 1.0) as f64)
+        {
+            let (ending_roman, ending_hangul) = endings[self.rng.gen_range(0..endings.len())];
+            roman.push_str(ending_roman);
+            hangul.push_str(ending_hangul);
+        }
+
+        GeneratedName {
+            roman: capitalize(&roman),
+            hangul,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_unique_in_both_scripts() {
+        let world_meta = WorldMetadata::default();
+        let mut names = NameRegistry::new(7);
+        let mut roman = HashSet::new();
+        let mut hangul = HashSet::new();
+        for i in 0..600 {
+            let nation = Nation::ALL[i % Nation::ALL.len()];
+            let faction = [Faction::Neutral, Faction::MerchantGuild, Faction::TempleOfSuns][i % 3];
+            let name = names.generate(nation, faction, &world_meta);
+            assert!(roman.insert(name.roman.to_lowercase()), "romanised name reused: {}", name.roman);
+            assert!(hangul.insert(name.hangul.clone()), "Hangul name reused: {}", name.hangul);
+        }
+    }
+}
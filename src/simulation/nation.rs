@@ -7,14 +7,23 @@ pub enum Nation {
     Tera,
     Sora,
     Aqua,
+    /// Successor polities: absent at the founding, they only enter the world
+    /// when rebels break away from an existing nation.
+    Ignis,
+    Ventus,
 }
 
 impl Nation {
+    /// Every polity the world can hold, founders first.
+    pub const ALL: [Nation; 5] = [Nation::Tera, Nation::Sora, Nation::Aqua, Nation::Ignis, Nation::Ventus];
+
     pub fn name(&self) -> &'static str {
         match self {
             Nation::Tera => "Tera",
             Nation::Sora => "Sora",
             Nation::Aqua => "Aqua",
+            Nation::Ignis => "Ignis",
+            Nation::Ventus => "Ventus",
         }
     }
 
@@ -23,6 +32,8 @@ impl Nation {
             Nation::Tera => Color::Blue,
             Nation::Sora => Color::Red,
             Nation::Aqua => Color::Green,
+            Nation::Ignis => Color::Yellow,
+            Nation::Ventus => Color::Magenta,
         }
     }
 
@@ -31,6 +42,13 @@ impl Nation {
             Nation::Tera => ColoredColor::Blue,
             Nation::Sora => ColoredColor::Red,
             Nation::Aqua => ColoredColor::Green,
+            Nation::Ignis => ColoredColor::Yellow,
+            Nation::Ventus => ColoredColor::Magenta,
         }
     }
 }
+
+/// Orders a pair of nations so either argument order yields the same key.
+pub(crate) fn pair_key(a: Nation, b: Nation) -> (Nation, Nation) {
+    if a.name() <= b.name() { (a, b) } else { (b, a) }
+}
@@ -1,6 +1,11 @@
 //! Shared observer snapshot structures exported via the API.
 
-use crate::simulation::{AllNationMetrics, BehaviorState, Biome, Faction, WorldEvent, AxialCoord, Nation};
+use crate::simulation::{
+    ActiveShocks, AllNationMetrics, Army, AxialCoord, BehaviorReport, BehaviorState, Biome, DecisionModel, Episode,
+    Epidemics,
+    Faction, Nation, NationResearch, NationStrategies, NationTreasuries, Pilgrimage, RegionalWeather, RelationEntry,
+    ReputationTier, War, WorldEvent,
+};
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
@@ -8,6 +13,7 @@ use std::collections::{HashMap, HashSet};
 pub struct EntitySnapshot {
     pub id: u64,
     pub name: String,
+    pub hangul_name: String,
     pub faction: Faction,
     pub faction_label: String,
     pub biome: Biome,
@@ -15,8 +21,24 @@ pub struct EntitySnapshot {
     pub behavior_state: BehaviorState,
     pub behavior_label: String,
     pub currency: f32,
+    pub health: f32,
+    pub stamina: f32,
+    pub infected: bool,
     pub wealth: f32,
     pub fame: f32,
+    pub infamy: f32,
+    pub reputation_tier: ReputationTier,
+    pub reputation_label: String,
+    pub decision_model: DecisionModel,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationSnapshot {
+    pub id: u64,
+    pub name: String,
+    pub affinity: f32,
+    pub trust: f32,
+    pub rivalry: f32,
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -28,6 +50,14 @@ pub struct HexGridSnapshot {
 #[derive(Debug, Clone, Serialize)]
 pub struct HexSnapshot {
     pub owner: Nation,
+    pub biome: Biome,
+    /// Temple of Suns adherence in the hex, 0..100.
+    pub adherence: f32,
+    /// The culture the hex's people hold to, and how firmly.
+    pub culture: Nation,
+    pub cohesion: f32,
+    /// Sickness lingering in the hex, 0..100.
+    pub contagion: f32,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -38,8 +68,21 @@ pub struct ObserverSnapshot {
     pub all_metrics: AllNationMetrics,
     pub grid: HexGridSnapshot,
     pub entities: Vec<EntitySnapshot>,
+    pub relations: HashMap<u64, Vec<RelationSnapshot>>,
+    pub memories: HashMap<u64, Vec<Episode>>,
     pub events: Vec<WorldEvent>,
     pub combat_hexes: HashSet<AxialCoord>,
+    pub behavior: BehaviorReport,
+    pub wars: Vec<War>,
+    pub armies: Vec<Army>,
+    pub pilgrimages: Vec<Pilgrimage>,
+    pub diplomacy: Vec<RelationEntry>,
+    pub strategies: NationStrategies,
+    pub research: NationResearch,
+    pub treasuries: NationTreasuries,
+    pub shocks: ActiveShocks,
+    pub weather: RegionalWeather,
+    pub epidemics: Epidemics,
 }
 
 impl ObserverSnapshot {
@@ -51,11 +94,25 @@ impl ObserverSnapshot {
             all_metrics: AllNationMetrics::default(),
             grid: HexGridSnapshot::default(),
             entities: Vec::new(),
+            relations: HashMap::new(),
+            memories: HashMap::new(),
             events: Vec::new(),
             combat_hexes: HashSet::new(),
+            behavior: BehaviorReport::default(),
+            wars: Vec::new(),
+            armies: Vec::new(),
+            pilgrimages: Vec::new(),
+            diplomacy: Vec::new(),
+            strategies: NationStrategies::default(),
+            research: NationResearch::default(),
+            treasuries: NationTreasuries::default(),
+            shocks: ActiveShocks::default(),
+            weather: RegionalWeather::default(),
+            epidemics: Epidemics::default(),
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update(
         &mut self,
         tick: u64,
@@ -64,8 +121,21 @@ impl ObserverSnapshot {
         metrics: &AllNationMetrics,
         grid: HexGridSnapshot,
         entities: Vec<EntitySnapshot>,
+        relations: HashMap<u64, Vec<RelationSnapshot>>,
+        memories: HashMap<u64, Vec<Episode>>,
         events: Vec<WorldEvent>,
         combat_hexes: HashSet<AxialCoord>,
+        behavior: BehaviorReport,
+        wars: Vec<War>,
+        armies: Vec<Army>,
+        pilgrimages: Vec<Pilgrimage>,
+        diplomacy: Vec<RelationEntry>,
+        strategies: NationStrategies,
+        research: NationResearch,
+        treasuries: NationTreasuries,
+        shocks: ActiveShocks,
+        weather: RegionalWeather,
+        epidemics: Epidemics,
     ) {
         self.tick = tick;
         self.epoch = epoch;
@@ -73,8 +143,21 @@ impl ObserverSnapshot {
         self.all_metrics = metrics.clone();
         self.grid = grid;
         self.entities = entities;
+        self.relations = relations;
+        self.memories = memories;
         self.events = events;
         self.combat_hexes = combat_hexes;
+        self.behavior = behavior;
+        self.wars = wars;
+        self.armies = armies;
+        self.pilgrimages = pilgrimages;
+        self.diplomacy = diplomacy;
+        self.strategies = strategies;
+        self.research = research;
+        self.treasuries = treasuries;
+        self.shocks = shocks;
+        self.weather = weather;
+        self.epidemics = epidemics;
     }
 }
 
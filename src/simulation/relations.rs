@@ -0,0 +1,97 @@
+//! Directed NPC-to-NPC relationship graph.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+/// How one NPC regards another. Affinity runs -100..100; trust and rivalry 0..100.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Relationship {
+    pub affinity: f32,
+    pub trust: f32,
+    pub rivalry: f32,
+}
+
+impl Relationship {
+    /// Single score used when choosing whom to deal with.
+    pub fn regard(&self) -> f32 {
+        self.affinity + self.trust - self.rivalry
+    }
+
+    pub fn adjust(&mut self, affinity: f32, trust: f32, rivalry: f32) {
+        self.affinity = (self.affinity + affinity).clamp(-100.0, 100.0);
+        self.trust = (self.trust + trust).clamp(0.0, 100.0);
+        self.rivalry = (self.rivalry + rivalry).clamp(0.0, 100.0);
+    }
+}
+
+#[derive(Debug, Clone, Default, Resource)]
+pub struct RelationshipGraph {
+    edges: HashMap<u64, HashMap<u64, Relationship>>,
+    /// The trader each trader last paired up with at market, both ways.
+    partners: HashMap<u64, u64>,
+}
+
+impl RelationshipGraph {
+    pub fn get(&self, from: u64, to: u64) -> Option<&Relationship> {
+        self.edges.get(&from).and_then(|outgoing| outgoing.get(&to))
+    }
+
+    /// Returns the edge from `from` to `to`, creating it at `baseline` affinity if absent.
+    pub fn edge_mut(&mut self, from: u64, to: u64, baseline: f32) -> &mut Relationship {
+        self.edges
+            .entry(from)
+            .or_default()
+            .entry(to)
+            .or_insert_with(|| Relationship {
+                affinity: baseline,
+                ..Default::default()
+            })
+    }
+
+    pub fn outgoing(&self, from: u64) -> impl Iterator<Item = (u64, &Relationship)> {
+        self.edges
+            .get(&from)
+            .into_iter()
+            .flat_map(|outgoing| outgoing.iter().map(|(to, relation)| (*to, relation)))
+    }
+
+    /// Replaces the trading pairs with this tick's.
+    pub fn set_partners(&mut self, pairs: &[(u64, u64)]) {
+        self.partners.clear();
+        for &(a, b) in pairs {
+            self.partners.insert(a, b);
+            self.partners.insert(b, a);
+        }
+    }
+
+    /// Price multiplier a trader gets dealing through its partner: one it
+    /// trusts vouches for its goods, one it resents drives a hard bargain.
+    pub fn partner_terms(&self, npc: u64) -> f32 {
+        self.partners
+            .get(&npc)
+            .and_then(|partner| self.get(npc, *partner))
+            .map_or(1.0, |relation| (1.0 + relation.regard() / 400.0).clamp(0.8, 1.25))
+    }
+
+    /// Relaxes every edge toward its doctrinal baseline and forgets edges that
+    /// carry no more history, or that point at NPCs no longer in the world.
+    pub fn decay(&mut self, alive: &HashSet<u64>, baseline: impl Fn(u64, u64) -> f32) {
+        self.edges.retain(|from, _| alive.contains(from));
+        for (from, outgoing) in self.edges.iter_mut() {
+            outgoing.retain(|to, relation| {
+                let base = baseline(*from, *to);
+                relation.affinity += (base - relation.affinity) * 0.01;
+                relation.trust *= 0.995;
+                relation.rivalry *= 0.99;
+
+                alive.contains(to)
+                    && ((relation.affinity - base).abs() >= 0.5
+                        || relation.trust >= 0.5
+                        || relation.rivalry >= 0.5)
+            });
+        }
+        self.edges.retain(|_, outgoing| !outgoing.is_empty());
+    }
+}
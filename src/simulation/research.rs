@@ -0,0 +1,71 @@
+//! Technology research: what each nation has discovered and what it is
+//! working toward.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::Resource;
+use serde::Serialize;
+
+use crate::simulation::{Nation, TechEffect, WorldMetadata};
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResearchState {
+    /// Technologies discovered, in the order they were made.
+    pub known: Vec<&'static str>,
+    /// The technology being researched, if one has been chosen.
+    pub current: Option<&'static str>,
+    /// Research accumulated toward `current`.
+    pub progress: f32,
+}
+
+#[derive(Debug, Clone, Default, Resource, Serialize)]
+pub struct NationResearch(pub HashMap<Nation, ResearchState>);
+
+impl NationResearch {
+    fn effects<'a>(&'a self, nation: Nation, world_meta: &'a WorldMetadata) -> impl Iterator<Item = TechEffect> + 'a {
+        self.0
+            .get(&nation)
+            .into_iter()
+            .flat_map(|state| &state.known)
+            .filter_map(|label| world_meta.tech(label))
+            .flat_map(|tech| tech.effects.iter().copied())
+    }
+
+    pub fn gather_multiplier(&self, nation: Nation, world_meta: &WorldMetadata) -> f32 {
+        self.effects(nation, world_meta)
+            .filter_map(|effect| match effect {
+                TechEffect::GatherYield(factor) => Some(factor),
+                _ => None,
+            })
+            .product()
+    }
+
+    pub fn army_multiplier(&self, nation: Nation, world_meta: &WorldMetadata) -> f32 {
+        self.effects(nation, world_meta)
+            .filter_map(|effect| match effect {
+                TechEffect::ArmyStrength(factor) => Some(factor),
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Share of upkeep the nation's technologies save, capped at half.
+    pub fn upkeep_relief(&self, nation: Nation, world_meta: &WorldMetadata) -> f32 {
+        self.effects(nation, world_meta)
+            .filter_map(|effect| match effect {
+                TechEffect::UpkeepRelief(share) => Some(share),
+                _ => None,
+            })
+            .sum::<f32>()
+            .min(0.5)
+    }
+
+    /// Recipes no technology gates are open to all; the rest need the
+    /// technology that unlocks them.
+    pub fn recipe_available(&self, nation: Nation, recipe: &str, world_meta: &WorldMetadata) -> bool {
+        !world_meta.recipe_needs_tech(recipe)
+            || self
+                .effects(nation, world_meta)
+                .any(|effect| matches!(effect, TechEffect::UnlockRecipe(unlocked) if unlocked == recipe))
+    }
+}
@@ -2,10 +2,10 @@
 
 use std::time::Duration;
 
-use crate::simulation::Nation;
+use crate::simulation::{BudgetLine, Nation, pair_key};
 use bevy_ecs::prelude::Resource;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Resource, Serialize, Deserialize)]
 pub struct NationMetrics {
@@ -15,7 +15,11 @@ pub struct NationMetrics {
     pub diplomacy: f32,    // 외교
     pub religion: f32,     // 종교
     pub military: f32,
+    /// Share of the map's hexes held, in percent; derived by `territory_system`.
     pub territory: f32,
+    /// Internal cohesion, 0..100; derived by `stability_system`. Unstable
+    /// nations fall to coups and civil war.
+    pub stability: f32,
     pub is_destroyed: bool,
 }
 
@@ -29,11 +33,36 @@ impl Default for NationMetrics {
             religion: 25.0,
             military: 20.0,
             territory: 33.33,
+            stability: 60.0,
             is_destroyed: false,
         }
     }
 }
 
+impl NationMetrics {
+    pub fn budget_line(&self, line: BudgetLine) -> f32 {
+        match line {
+            BudgetLine::Military => self.military,
+            BudgetLine::Economy => self.economy,
+            BudgetLine::Science => self.science,
+            BudgetLine::Culture => self.culture,
+            BudgetLine::Diplomacy => self.diplomacy,
+            BudgetLine::Religion => self.religion,
+        }
+    }
+
+    pub fn budget_line_mut(&mut self, line: BudgetLine) -> &mut f32 {
+        match line {
+            BudgetLine::Military => &mut self.military,
+            BudgetLine::Economy => &mut self.economy,
+            BudgetLine::Science => &mut self.science,
+            BudgetLine::Culture => &mut self.culture,
+            BudgetLine::Diplomacy => &mut self.diplomacy,
+            BudgetLine::Religion => &mut self.religion,
+        }
+    }
+}
+
 #[derive(Debug, Resource, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AllNationMetrics(pub HashMap<Nation, NationMetrics>);
 
@@ -47,19 +76,12 @@ impl Default for AllNationMetrics {
     }
 }
 
-#[derive(Debug, Resource)]
-pub struct DeltaTime(pub f32);
-
-impl Default for DeltaTime {
-    fn default() -> Self {
-        Self(1.0)
-    }
-}
-
 #[derive(Debug, Clone, Resource)]
 pub struct SimulationConfig {
     pub tick_duration: Duration,
     pub grid_radius: i32,
+    pub initial_population: usize,
+    pub world_seed: u64,
 }
 
 impl Default for SimulationConfig {
@@ -67,17 +89,130 @@ impl Default for SimulationConfig {
         Self {
             tick_duration: Duration::from_secs(1),
             grid_radius: 5,
+            initial_population: 120,
+            world_seed: 0x7E7A,
         }
     }
 }
 
-#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Resource, Serialize, Deserialize)]
 pub struct WorldTime {
     pub tick: u64,
 }
 
-impl Default for WorldTime {
+/// Running tallies of NPC generation; also hands out unique NPC ids.
+#[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+pub struct PopulationLedger {
+    pub next_id: u64,
+    pub births: u64,
+    pub immigrants: u64,
+    pub emigrants: u64,
+    /// Coin immigrants brought into the world, in their new nation's coin.
+    pub coin_arrived: f32,
+    /// Coin emigrants took out of the world, in their nation's coin.
+    pub coin_departed: f32,
+}
+
+impl PopulationLedger {
+    pub fn allocate_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+}
+
+impl Default for PopulationLedger {
     fn default() -> Self {
-        Self { tick: 0 }
+        Self {
+            next_id: 1,
+            births: 0,
+            immigrants: 0,
+            emigrants: 0,
+            coin_arrived: 0.0,
+            coin_departed: 0.0,
+        }
+    }
+}
+
+/// Diplomatic memory of past wars: treaty truces and unavenged defeats.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct WarLedger {
+    next_id: u64,
+    /// Tick until which a peace treaty forbids a new war between the pair.
+    truces: HashMap<(Nation, Nation), u64>,
+    /// `(aggrieved, enemy)` pairs where the first lost a war to the second.
+    grievances: HashSet<(Nation, Nation)>,
+}
+
+impl WarLedger {
+    pub fn allocate_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    pub fn in_truce(&self, a: Nation, b: Nation, tick: u64) -> bool {
+        self.truces.get(&pair_key(a, b)).is_some_and(|until| tick < *until)
+    }
+
+    pub fn sign_truce(&mut self, a: Nation, b: Nation, until: u64) {
+        self.truces.insert(pair_key(a, b), until);
+    }
+
+    pub fn has_grievance(&self, aggrieved: Nation, enemy: Nation) -> bool {
+        self.grievances.contains(&(aggrieved, enemy))
+    }
+
+    pub fn add_grievance(&mut self, aggrieved: Nation, enemy: Nation) {
+        self.grievances.insert((aggrieved, enemy));
+    }
+
+    pub fn settle_grievance(&mut self, aggrieved: Nation, enemy: Nation) {
+        self.grievances.remove(&(aggrieved, enemy));
+    }
+}
+
+/// The rise and fall of polities: which nations have fallen and when each
+/// last went through a civil war or a change of regime.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct PolityLedger {
+    /// Fallen nations, with the tick their people were scattered.
+    fallen: HashMap<Nation, u64>,
+    last_upheaval: HashMap<Nation, u64>,
+}
+
+impl PolityLedger {
+    pub fn has_fallen(&self, nation: Nation) -> bool {
+        self.fallen.contains_key(&nation)
+    }
+
+    pub fn record_fall(&mut self, nation: Nation, tick: u64) {
+        self.fallen.insert(nation, tick);
+    }
+
+    /// A fallen nation rises again under rebel banners.
+    pub fn record_rebirth(&mut self, nation: Nation, tick: u64) {
+        self.fallen.remove(&nation);
+        self.last_upheaval.insert(nation, tick);
+    }
+
+    pub fn last_upheaval(&self, nation: Nation) -> Option<u64> {
+        self.last_upheaval.get(&nation).copied()
+    }
+
+    pub fn record_upheaval(&mut self, nation: Nation, tick: u64) {
+        self.last_upheaval.insert(nation, tick);
+    }
+}
+
+/// Hands out ids for newly raised armies.
+#[derive(Debug, Default, Resource)]
+pub struct ArmyRoster {
+    next_id: u64,
+}
+
+impl ArmyRoster {
+    pub fn allocate_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
     }
 }
@@ -0,0 +1,134 @@
+//! Macro shocks in progress: the droughts, invasions, crashes and boons
+//! running across the world, whom they strike and where.
+
+use bevy_ecs::prelude::Resource;
+use serde::Serialize;
+
+use crate::simulation::{BehaviorState, Biome, Nation, ShockMetadata, ShockModifier, WorldMetadata};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveShock {
+    pub label: &'static str,
+    pub nations: Vec<Nation>,
+    /// Biomes the shock reaches; empty for everywhere.
+    pub biomes: Vec<Biome>,
+    pub started_at: u64,
+    pub ends_at: u64,
+}
+
+impl ActiveShock {
+    pub fn reaches(&self, nation: Nation, biome: Biome) -> bool {
+        self.nations.contains(&nation) && (self.biomes.is_empty() || self.biomes.contains(&biome))
+    }
+}
+
+#[derive(Debug, Clone, Default, Resource, Serialize)]
+pub struct ActiveShocks(pub Vec<ActiveShock>);
+
+impl ActiveShocks {
+    fn modifiers<'a>(
+        &'a self,
+        world_meta: &'a WorldMetadata,
+    ) -> impl Iterator<Item = (&'a ActiveShock, ShockModifier)> + 'a {
+        self.0.iter().flat_map(move |active| {
+            world_meta
+                .shock(active.label)
+                .into_iter()
+                .flat_map(|shock| shock.modifiers.iter().copied())
+                .map(move |modifier| (active, modifier))
+        })
+    }
+
+    /// Whether `label` is already running against `nation`.
+    pub fn strikes(&self, label: &str, nation: Nation) -> bool {
+        self.0.iter().any(|active| active.label == label && active.nations.contains(&nation))
+    }
+
+    /// Starts `shock` against `nations` for `duration` ticks, or prolongs it
+    /// where it already strikes the first of them. Returns whether it broke
+    /// out anew.
+    pub fn strike(&mut self, shock: &ShockMetadata, nations: Vec<Nation>, tick: u64, duration: u64) -> bool {
+        if let Some(active) = self
+            .0
+            .iter_mut()
+            .find(|active| active.label == shock.label && nations.first().is_some_and(|nation| active.nations.contains(nation)))
+        {
+            active.ends_at = active.ends_at.max(tick + duration);
+            return false;
+        }
+        self.0.push(ActiveShock {
+            label: shock.label,
+            nations,
+            biomes: shock.biomes.clone(),
+            started_at: tick,
+            ends_at: tick + duration,
+        });
+        true
+    }
+
+    pub fn gather_multiplier(&self, nation: Nation, biome: Biome, world_meta: &WorldMetadata) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::GatherYield(factor) if active.reaches(nation, biome) => Some(factor),
+                _ => None,
+            })
+            .product()
+    }
+
+    pub fn trade_multiplier(&self, nation: Nation, biome: Biome, world_meta: &WorldMetadata) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::TradeYield(factor) if active.reaches(nation, biome) => Some(factor),
+                _ => None,
+            })
+            .product()
+    }
+
+    pub fn behavior_multiplier(
+        &self,
+        nation: Nation,
+        biome: Biome,
+        state: BehaviorState,
+        world_meta: &WorldMetadata,
+    ) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::Behavior(affected, factor) if affected == state && active.reaches(nation, biome) => {
+                    Some(factor)
+                }
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Health an NPC of `nation` loses each tick in `biome`.
+    pub fn health_loss(&self, nation: Nation, biome: Biome, world_meta: &WorldMetadata) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::Health(loss) if active.reaches(nation, biome) => Some(loss),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Chance per tick that a susceptible NPC of `nation` in `biome` falls sick.
+    pub fn contagion(&self, nation: Nation, biome: Biome, world_meta: &WorldMetadata) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::Contagion(chance) if active.reaches(nation, biome) => Some(chance),
+                _ => None,
+            })
+            .sum()
+    }
+
+    /// Multiplier on the price level `nation`'s coin settles toward; prices
+    /// are national, so the shock's biomes do not matter.
+    pub fn price_multiplier(&self, nation: Nation, world_meta: &WorldMetadata) -> f32 {
+        self.modifiers(world_meta)
+            .filter_map(|(active, modifier)| match modifier {
+                ShockModifier::Prices(factor) if active.nations.contains(&nation) => Some(factor),
+                _ => None,
+            })
+            .product()
+    }
+}
@@ -0,0 +1,59 @@
+//! Nation-level strategy: doctrines and the budget each nation settles on.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::simulation::Nation;
+
+/// Standing outlook that shapes how a nation spends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Doctrine {
+    Militarist,
+    Mercantile,
+    Theocratic,
+    Scholarly,
+}
+
+/// A `NationMetrics` field the budget can be spent on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BudgetLine {
+    Military,
+    Economy,
+    Science,
+    Culture,
+    Diplomacy,
+    Religion,
+}
+
+impl BudgetLine {
+    pub const ALL: [BudgetLine; 6] = [
+        BudgetLine::Military,
+        BudgetLine::Economy,
+        BudgetLine::Science,
+        BudgetLine::Culture,
+        BudgetLine::Diplomacy,
+        BudgetLine::Religion,
+    ];
+}
+
+/// The budget a nation's strategic AI last settled on.
+#[derive(Debug, Clone, Serialize)]
+pub struct NationStrategy {
+    pub doctrine: Doctrine,
+    /// Share of spending per line; sums to 1. The economy share is kept in reserve.
+    pub budget: HashMap<BudgetLine, f32>,
+    /// The line receiving the largest share.
+    pub focus: BudgetLine,
+    pub revised_at: u64,
+}
+
+impl NationStrategy {
+    pub fn share(&self, line: BudgetLine) -> f32 {
+        self.budget.get(&line).copied().unwrap_or(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Default, Resource, Serialize)]
+pub struct NationStrategies(pub HashMap<Nation, NationStrategy>);
@@ -1,148 +1,150 @@
 //! AI state transition system.
+//!
+//! Transition tables and their modifiers live in `WorldMetadata::behaviors`.
 
 use bevy_ecs::prelude::*;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 
 use crate::simulation::{
-    Behavior, BehaviorState, Identity, Personality, Position, WorldMetadata, WorldTime,
+    Attributes, Behavior, BehaviorState, DecisionModel, DecisionPolicy, Identity, Inventory,
+    ActiveShocks, NationTreasuries, Personality, Pilgrim, Position, Reputation, ReputationTier, UtilityStrategy, WorldMetadata, WorldTime,
+    reputation_behavior_modifier,
 };
 
-const IDLE_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Idle, 0.2),
-    (BehaviorState::Explore, 0.3),
-    (BehaviorState::Trade, 0.25),
-    (BehaviorState::Rest, 0.25),
-];
-
-const EXPLORE_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Explore, 0.2),
-    (BehaviorState::Gather, 0.35),
-    (BehaviorState::Hunt, 0.25),
-    (BehaviorState::Trade, 0.2),
-];
-
-const GATHER_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Gather, 0.25),
-    (BehaviorState::Trade, 0.4),
-    (BehaviorState::Rest, 0.2),
-    (BehaviorState::Explore, 0.15),
-];
-
-const TRADE_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Trade, 0.25),
-    (BehaviorState::Rest, 0.25),
-    (BehaviorState::Idle, 0.2),
-    (BehaviorState::Explore, 0.3),
-];
-
-const HUNT_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Hunt, 0.25),
-    (BehaviorState::Rest, 0.3),
-    (BehaviorState::Gather, 0.2),
-    (BehaviorState::Trade, 0.25),
-];
-
-const REST_TRANSITIONS: &[(BehaviorState, f32)] = &[
-    (BehaviorState::Rest, 0.3),
-    (BehaviorState::Idle, 0.35),
-    (BehaviorState::Explore, 0.2),
-    (BehaviorState::Trade, 0.15),
-];
-
-fn transition_options(state: BehaviorState) -> &'static [(BehaviorState, f32)] {
-    match state {
-        BehaviorState::Idle => IDLE_TRANSITIONS,
-        BehaviorState::Explore => EXPLORE_TRANSITIONS,
-        BehaviorState::Gather => GATHER_TRANSITIONS,
-        BehaviorState::Trade => TRADE_TRANSITIONS,
-        BehaviorState::Hunt => HUNT_TRANSITIONS,
-        BehaviorState::Rest => REST_TRANSITIONS,
+/// Everything a decision strategy may look at when picking the next state.
+pub struct DecisionContext<'a> {
+    pub identity: &'a Identity,
+    pub position: &'a Position,
+    pub personality: &'a Personality,
+    pub attributes: &'a Attributes,
+    pub inventory: &'a Inventory,
+    /// Prices in the NPC's own coin; purses are weighed by what they buy.
+    pub price_level: f32,
+    pub tier: ReputationTier,
+    pub current: BehaviorState,
+    pub segment: &'a str,
+    pub season: &'a str,
+    pub shocks: &'a ActiveShocks,
+    pub world_meta: &'a WorldMetadata,
+}
+
+impl DecisionContext<'_> {
+    /// Biome, faction, time-of-day, season, reputation and macro-shock
+    /// pressure shared by every strategy.
+    pub fn situational_modifier(&self, state: BehaviorState) -> f32 {
+        self.world_meta.biome_behavior_bias(self.position.biome, state)
+            * self
+                .world_meta
+                .faction_behavior_modifier(self.identity.faction, state)
+            * self.world_meta.epoch_behavior_modifier(self.segment, state)
+            * self.world_meta.season_behavior_modifier(self.season, state)
+            * reputation_behavior_modifier(self.tier, state)
+            * self
+                .shocks
+                .behavior_multiplier(self.identity.nation, self.position.biome, state, self.world_meta)
     }
 }
 
-fn personality_modifier(personality: &Personality, state: BehaviorState) -> f32 {
-    let mut modifier = 1.0;
+/// A pluggable decision layer: given the context, choose the next behaviour.
+pub trait DecisionStrategy: Sync {
+    fn decide(&self, context: &DecisionContext, rng: &mut SmallRng) -> BehaviorState;
+}
+
+/// The probabilistic state machine driven by the world data's transition tables.
+pub struct TransitionTableStrategy;
+
+impl DecisionStrategy for TransitionTableStrategy {
+    fn decide(&self, context: &DecisionContext, rng: &mut SmallRng) -> BehaviorState {
+        let options = context.world_meta.behavior_transitions(context.current);
+
+        let mut weighted_options = Vec::with_capacity(options.len());
+        for (next_state, base_weight) in options {
+            let mut weight = *base_weight;
+
+            weight *= context
+                .world_meta
+                .personality_behavior_modifier(context.personality, *next_state);
+            weight *= context.situational_modifier(*next_state);
 
-    modifier += match state {
-        BehaviorState::Hunt => personality.aggressive * 0.6 - personality.cautious * 0.3,
-        BehaviorState::Trade => personality.social * 0.5,
-        BehaviorState::Explore => personality.curious * 0.6 - personality.cautious * 0.2,
-        BehaviorState::Gather => personality.curious * 0.2 + personality.cautious * 0.2,
-        BehaviorState::Rest => personality.cautious * 0.4 - personality.aggressive * 0.2,
-        BehaviorState::Idle => (personality.cautious - personality.curious) * 0.1,
-    };
+            // Ensure we never end up with non-positive weights.
+            weight = weight.max(0.01);
+            weighted_options.push((*next_state, weight));
+        }
 
-    modifier.clamp(0.1, 2.5)
+        weighted_choice(&weighted_options, rng).unwrap_or(context.current)
+    }
 }
 
-fn epoch_modifier(segment: &str, state: BehaviorState) -> f32 {
-    match segment {
-        "새벽" => match state {
-            BehaviorState::Explore | BehaviorState::Gather => 1.15,
-            BehaviorState::Rest => 0.85,
-            _ => 1.0,
-        },
-        "한낮" => match state {
-            BehaviorState::Trade => 1.2,
-            BehaviorState::Idle => 0.85,
-            _ => 1.0,
-        },
-        "해질녘" => match state {
-            BehaviorState::Hunt => 1.25,
-            BehaviorState::Rest => 1.1,
-            BehaviorState::Trade => 0.75,
-            _ => 1.0,
-        },
-        _ => 1.0,
+pub fn strategy_for(model: DecisionModel) -> &'static dyn DecisionStrategy {
+    match model {
+        DecisionModel::TransitionTable => &TransitionTableStrategy,
+        DecisionModel::Utility => &UtilityStrategy,
     }
 }
 
-fn season_modifier(season: &str, state: BehaviorState) -> f32 {
-    match season {
-        "꽃피움 계절" => match state {
-            BehaviorState::Gather => 1.2,
-            BehaviorState::Trade => 1.05,
-            _ => 1.0,
-        },
-        "불꽃 절정" => match state {
-            BehaviorState::Explore | BehaviorState::Hunt => 1.1,
-            BehaviorState::Rest => 0.95,
-            _ => 1.0,
-        },
-        "잿불 내림" => match state {
-            BehaviorState::Rest => 1.25,
-            BehaviorState::Trade => 0.9,
-            _ => 1.0,
-        },
-        _ => 1.0,
+/// Roulette-wheel draw over positive weights; negative weights count as zero.
+pub fn weighted_choice<T: Copy>(options: &[(T, f32)], rng: &mut SmallRng) -> Option<T> {
+    let total_weight: f32 = options.iter().map(|(_, w)| w.max(0.0)).sum();
+    if total_weight <= 0.0 {
+        return None;
+    }
+    let mut threshold = rng.gen_range(0.0..total_weight);
+
+    for (candidate, weight) in options {
+        threshold -= weight.max(0.0);
+        if threshold <= 0.0 {
+            return Some(*candidate);
+        }
     }
+    options.last().map(|(candidate, _)| *candidate)
 }
 
+#[allow(clippy::type_complexity)]
 pub fn ai_state_transition_system(
-    mut query: Query<(&Identity, &Position, &Personality, &mut Behavior)>,
+    mut query: Query<(
+        &Identity,
+        &Position,
+        &Personality,
+        &Attributes,
+        &Reputation,
+        &Inventory,
+        Option<&DecisionPolicy>,
+        Option<&Pilgrim>,
+        &mut Behavior,
+    )>,
+    treasuries: Res<NationTreasuries>,
+    shocks: Res<ActiveShocks>,
     world_meta: Res<WorldMetadata>,
     time: Res<WorldTime>,
 ) {
     let (segment, season) = world_meta.epoch_for_tick(time.tick);
 
-    for (identity, position, personality, mut behavior) in &mut query {
-        let options = transition_options(behavior.state);
-
-        let mut weighted_options = Vec::with_capacity(options.len());
-        for (next_state, base_weight) in options {
-            let mut weight = *base_weight;
-
-            weight *= personality_modifier(personality, *next_state);
-            weight *= world_meta.biome_behavior_bias(position.biome, *next_state);
-            weight *= world_meta.faction_behavior_modifier(identity.faction, *next_state);
-            weight *= epoch_modifier(segment, *next_state);
-            weight *= season_modifier(season, *next_state);
-
-            // Ensure we never end up with non-positive weights.
-            weight = weight.max(0.01);
-            weighted_options.push((*next_state, weight));
+    for (identity, position, personality, attributes, reputation, inventory, policy, pilgrim, mut behavior) in
+        &mut query
+    {
+        // Pilgrims pray on the road until their band is done.
+        if pilgrim.is_some() {
+            behavior.state = BehaviorState::Pray;
+            continue;
         }
+        let model = policy
+            .map(|policy| policy.model)
+            .unwrap_or_else(|| world_meta.faction_decision_model(identity.faction));
+        let context = DecisionContext {
+            identity,
+            position,
+            personality,
+            attributes,
+            inventory,
+            price_level: treasuries.price_level(identity.nation),
+            tier: reputation.tier(attributes.fame),
+            current: behavior.state,
+            segment,
+            season,
+            shocks: &shocks,
+            world_meta: &world_meta,
+        };
 
         let mut rng = SmallRng::seed_from_u64(
             time.tick
@@ -150,15 +152,6 @@ pub fn ai_state_transition_system(
                 .wrapping_add(identity.id)
                 .wrapping_mul(53),
         );
-        let total_weight: f32 = weighted_options.iter().map(|(_, w)| *w).sum();
-        let mut threshold = rng.gen_range(0.0..total_weight);
-
-        for (candidate, weight) in weighted_options {
-            threshold -= weight;
-            if threshold <= 0.0 {
-                behavior.state = candidate;
-                break;
-            }
-        }
+        behavior.state = strategy_for(model).decide(&context, &mut rng);
     }
 }
@@ -0,0 +1,34 @@
+//! Feeds every NPC's behaviour into the running analytics.
+
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::*;
+
+use crate::simulation::{
+    Behavior, BehaviorAnalytics, DecisionPolicy, Identity, Position, WorldMetadata, WorldTime,
+};
+
+pub fn behavior_analytics_system(
+    query: Query<(&Identity, &Position, &Behavior, Option<&DecisionPolicy>)>,
+    mut analytics: ResMut<BehaviorAnalytics>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+) {
+    let (_, season) = world_meta.epoch_for_tick(time.tick);
+
+    let mut present = HashSet::new();
+    for (identity, position, behavior, policy) in &query {
+        let model = policy.map_or_else(|| world_meta.faction_decision_model(identity.faction), |policy| policy.model);
+        analytics.observe(
+            identity.id,
+            identity.faction,
+            model,
+            position.biome,
+            season,
+            behavior.state,
+            time.tick,
+        );
+        present.insert(identity.id);
+    }
+    analytics.forget_departed(&present);
+}
@@ -0,0 +1,303 @@
+//! Field armies: raised from military spending and paid from the treasury,
+//! marched toward war goals, kept in supply, and thrown at each other when
+//! hostile columns meet.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use super::warfare::supply_range;
+use crate::simulation::{
+    AllNationMetrics, Army, ArmyOrder, ArmyRoster, AxialCoord, Hex, HexGrid, Nation, NationResearch,
+    NationTreasuries, RegionalWeather, War, WarGoal, CREDIT_LIMIT, WorldEvent, WorldEventLog, WorldMetadata,
+    WorldTime,
+};
+
+/// Military spending consumed to raise one army.
+const RECRUIT_COST: f32 = 20.0;
+/// Coin the treasury pays to muster one army.
+const MUSTER_COIN: f32 = 60.0;
+/// Coin each soldier is paid per tick in the field.
+const SOLDIER_PAY: f32 = 0.01;
+/// Share of an unpaid army that deserts each tick.
+const DESERTION_RATE: f32 = 0.01;
+const SOLDIERS_PER_POINT: f32 = 10.0;
+const MAX_ARMIES: usize = 3;
+/// A nation raises at most one army per this many ticks.
+const RECRUIT_INTERVAL: u64 = 25;
+/// An army shrunk below this many soldiers disbands.
+const MIN_ARMY_SIZE: f32 = 20.0;
+/// Ticks an army needs to cross one hex.
+const MARCH_TICKS: u64 = 2;
+/// Below this morale an army falls back to its capital.
+const BREAKING_MORALE: f32 = 30.0;
+/// Morale a retreating army needs before it marches again.
+const RALLIED_MORALE: f32 = 60.0;
+/// Extra fighting weight for standing on friendly ground.
+const HOME_GROUND_BONUS: f32 = 1.2;
+
+/// Neighbouring hex on the map that brings `from` closest to `to`.
+pub(crate) fn step_toward(owners: &HashMap<AxialCoord, Nation>, from: AxialCoord, to: AxialCoord) -> Option<AxialCoord> {
+    from.neighbors()
+        .into_iter()
+        .filter(|neighbor| owners.contains_key(neighbor))
+        .filter(|neighbor| neighbor.distance(&to) < from.distance(&to))
+        .min_by_key(|neighbor| (neighbor.distance(&to), neighbor.q, neighbor.r))
+}
+
+/// Where an advancing army of `nation` should head in `war`: the enemy's
+/// capital when the aim is to humble or bleed it, otherwise the nearest enemy
+/// hex on the front.
+fn war_objective(
+    war: &War,
+    nation: Nation,
+    position: AxialCoord,
+    owners: &HashMap<AxialCoord, Nation>,
+    grid: &HexGrid,
+) -> Option<AxialCoord> {
+    let enemy = war.opponent_of(nation);
+    if nation == war.aggressor && war.goal != WarGoal::Conquest {
+        return grid.capitals.get(&enemy).copied();
+    }
+    war.front
+        .iter()
+        .filter(|coord| owners.get(coord) == Some(&enemy))
+        .min_by_key(|coord| (position.distance(coord), coord.q, coord.r))
+        .copied()
+}
+
+/// Recruits armies, issues orders, moves them a hex at a time and keeps
+/// track of their supply.
+#[allow(clippy::too_many_arguments)]
+pub fn army_logistics_system(
+    mut commands: Commands,
+    mut all_metrics: ResMut<AllNationMetrics>,
+    mut treasuries: ResMut<NationTreasuries>,
+    mut roster: ResMut<ArmyRoster>,
+    mut armies: Query<(Entity, &mut Army)>,
+    wars: Query<&War>,
+    grid: Res<HexGrid>,
+    hexes: Query<(&Hex, &AxialCoord)>,
+    time: Res<WorldTime>,
+) {
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord)| (*coord, hex.owner)).collect();
+
+    let mut wars: Vec<&War> = wars.iter().collect();
+    wars.sort_by_key(|war| war.id);
+
+    // 1. Recruitment: a nation with spending to spare musters a new army at its capital.
+    if time.tick.is_multiple_of(RECRUIT_INTERVAL) {
+        let mut fielded: HashMap<Nation, usize> = HashMap::new();
+        for (_, army) in armies.iter() {
+            *fielded.entry(army.nation).or_insert(0) += 1;
+        }
+
+        let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+        nations.sort_by_key(|nation| nation.name());
+        for nation in nations {
+            let metrics = all_metrics.0.get_mut(&nation).unwrap();
+            let Some(capital) = grid.capitals.get(&nation).copied() else {
+                continue;
+            };
+            if metrics.is_destroyed
+                || owners.get(&capital) != Some(&nation)
+                || fielded.get(&nation).copied().unwrap_or(0) >= MAX_ARMIES
+                || metrics.military < RECRUIT_COST * 2.0
+                || treasuries.debt(nation) + MUSTER_COIN * treasuries.price_level(nation) > CREDIT_LIMIT
+            {
+                continue;
+            }
+
+            metrics.military -= RECRUIT_COST;
+            let muster = MUSTER_COIN * treasuries.price_level(nation);
+            treasuries.treasury_mut(nation).spend(muster);
+            commands.spawn(Army {
+                id: roster.allocate_id(),
+                nation,
+                size: RECRUIT_COST * SOLDIERS_PER_POINT,
+                morale: 70.0,
+                supply: 100.0,
+                position: capital,
+                order: ArmyOrder::Garrison,
+                objective: Some(capital),
+            });
+        }
+    }
+
+    // 2. Orders, marching and supply.
+    let mut columns: Vec<(Entity, Mut<Army>)> = armies.iter_mut().collect();
+    columns.sort_by_key(|(_, army)| army.id);
+
+    // The oldest army of each nation stays home to hold the capital.
+    let mut garrisoned: HashSet<Nation> = HashSet::new();
+    for (entity, mut army) in columns {
+        let nation = army.nation;
+        let Some(metrics) = all_metrics.0.get(&nation) else {
+            continue;
+        };
+        let capital = grid.capitals.get(&nation).copied();
+        let range = supply_range(metrics);
+
+        if metrics.is_destroyed {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let war = wars.iter().find(|war| war.involves(nation));
+        army.order = if garrisoned.insert(nation) {
+            ArmyOrder::Garrison
+        } else if army.morale < BREAKING_MORALE
+            || (army.order == ArmyOrder::Retreat && army.morale < RALLIED_MORALE)
+        {
+            ArmyOrder::Retreat
+        } else if war.is_some() {
+            ArmyOrder::Advance
+        } else {
+            ArmyOrder::Garrison
+        };
+        army.objective = match (army.order, war) {
+            (ArmyOrder::Advance, Some(war)) => war_objective(war, nation, army.position, &owners, &grid),
+            _ => capital,
+        };
+
+        if let Some(objective) = army.objective
+            && (time.tick + army.id).is_multiple_of(MARCH_TICKS)
+            && let Some(next) = step_toward(&owners, army.position, objective)
+        {
+            // Advancing columns will not outrun their supply lines.
+            let within_reach = capital.is_none_or(|capital| capital.distance(&next) <= range);
+            if army.order != ArmyOrder::Advance || within_reach {
+                army.position = next;
+            }
+        }
+
+        let friendly = owners.get(&army.position) == Some(&nation);
+        let beyond_reach = capital.is_none_or(|capital| capital.distance(&army.position) > range);
+        if friendly {
+            army.supply = (army.supply + 10.0).min(100.0);
+            army.morale = (army.morale + 1.0).min(100.0);
+        } else {
+            army.supply -= if beyond_reach { 6.0 } else { 3.0 };
+        }
+        // Soldiers are paid from the treasury, and walk away once it is broke.
+        let pay = army.size * SOLDIER_PAY * treasuries.price_level(nation);
+        if treasuries.treasury_mut(nation).spend(pay) < pay {
+            army.size *= 1.0 - DESERTION_RATE;
+            army.morale = (army.morale - 1.0).max(0.0);
+        }
+
+        if army.supply <= 0.0 {
+            // Starving armies melt away.
+            army.supply = 0.0;
+            army.size *= 0.97;
+            army.morale = (army.morale - 2.0).max(0.0);
+        }
+
+        if army.size < MIN_ARMY_SIZE {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Hostile armies that come within a hex of each other give battle.
+#[allow(clippy::too_many_arguments)]
+pub fn army_engagement_system(
+    mut commands: Commands,
+    mut armies: Query<(Entity, &mut Army)>,
+    wars: Query<&War>,
+    all_metrics: Res<AllNationMetrics>,
+    research: Res<NationResearch>,
+    hexes: Query<(&Hex, &AxialCoord)>,
+    weather: Res<RegionalWeather>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(613).wrapping_add(29));
+
+    let hostile: HashSet<(Nation, Nation)> = wars
+        .iter()
+        .flat_map(|war| [(war.aggressor, war.defender), (war.defender, war.aggressor)])
+        .collect();
+    if hostile.is_empty() {
+        return;
+    }
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord)| (*coord, hex.owner)).collect();
+
+    let mut columns: Vec<(Entity, u64, Nation, AxialCoord)> = armies
+        .iter()
+        .map(|(entity, army)| (entity, army.id, army.nation, army.position))
+        .collect();
+    columns.sort_by_key(|(_, id, _, _)| *id);
+
+    let mut engaged: HashSet<Entity> = HashSet::new();
+    for (i, (first, _, first_nation, first_position)) in columns.iter().enumerate() {
+        for (second, _, second_nation, second_position) in &columns[i + 1..] {
+            if engaged.contains(first)
+                || engaged.contains(second)
+                || !hostile.contains(&(*first_nation, *second_nation))
+                || first_position.distance(second_position) > 1
+            {
+                continue;
+            }
+
+            let Ok([(_, mut a), (_, mut b)]) = armies.get_many_mut([*first, *second]) else {
+                continue;
+            };
+
+            let power = |army: &Army, rng: &mut SmallRng| {
+                let science = all_metrics.0.get(&army.nation).map_or(0.0, |m| m.science);
+                // Defenders fight on their own ground; invaders also fight the weather.
+                let ground = if owners.get(&army.position) == Some(&army.nation) {
+                    HOME_GROUND_BONUS
+                } else {
+                    weather.weather(army.position).assault_modifier()
+                };
+                army.strength()
+                    * (1.0 + science / 100.0)
+                    * research.army_multiplier(army.nation, &world_meta)
+                    * ground
+                    * rng.gen_range(0.8..1.2)
+            };
+            let (power_a, power_b) = (power(&a, &mut rng), power(&b, &mut rng));
+            let (winner, loser, loser_entity) = if power_a >= power_b {
+                (&mut a, &mut b, *second)
+            } else {
+                (&mut b, &mut a, *first)
+            };
+
+            let winner_losses = winner.size * 0.1;
+            let loser_losses = loser.size * 0.3;
+            winner.size -= winner_losses;
+            winner.morale = (winner.morale + 5.0).min(100.0);
+            loser.size -= loser_losses;
+            loser.morale = (loser.morale - 25.0).max(0.0);
+            loser.order = ArmyOrder::Retreat;
+
+            let routed = loser.size < MIN_ARMY_SIZE;
+            if routed {
+                commands.entity(loser_entity).despawn();
+            }
+
+            event_log.push(WorldEvent::army_clash(
+                time.tick,
+                epoch,
+                season,
+                winner.nation,
+                loser.nation,
+                loser.position,
+                winner_losses,
+                loser_losses,
+                routed,
+            ));
+
+            engaged.insert(*first);
+            engaged.insert(*second);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Village workshop production chains.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use crate::simulation::{
+    AllNationMetrics, Behavior, BehaviorState, CraftingJob, EventActor, Identity, Inventory,
+    Nation, NationResearch, Position, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Concurrent workshop jobs a nation can sustain; one slot per 20 science.
+fn production_capacity(science: f32) -> usize {
+    1 + (science / 20.0).floor() as usize
+}
+
+#[allow(clippy::type_complexity)]
+pub fn crafting_system(
+    mut commands: Commands,
+    mut artisans: Query<(
+        Entity,
+        &Identity,
+        &Position,
+        &Behavior,
+        &mut Inventory,
+        Option<&mut CraftingJob>,
+    )>,
+    all_metrics: Res<AllNationMetrics>,
+    research: Res<NationResearch>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+
+    let mut active_jobs: HashMap<Nation, usize> = HashMap::new();
+    for (_, identity, _, _, _, job) in &artisans {
+        if job.is_some() {
+            *active_jobs.entry(identity.nation).or_default() += 1;
+        }
+    }
+
+    for (entity, identity, position, behavior, mut inventory, job) in &mut artisans {
+        // Workshop labour happens while an NPC gathers or crafts in a workshop biome.
+        if !matches!(behavior.state, BehaviorState::Gather | BehaviorState::Craft) {
+            continue;
+        }
+
+        if let Some(mut job) = job {
+            let Some(recipe) = world_meta.recipe(&job.recipe) else {
+                commands.entity(entity).remove::<CraftingJob>();
+                continue;
+            };
+            if recipe.workshop != position.biome {
+                continue;
+            }
+
+            job.progress += world_meta.faction_craft_yield(identity.faction);
+            if job.progress >= job.work_ticks {
+                inventory.add(recipe.output.clone(), recipe.output_quantity);
+                commands.entity(entity).remove::<CraftingJob>();
+
+                event_log.push(WorldEvent::crafting(
+                    time.tick,
+                    epoch,
+                    season,
+                    EventActor::new(identity, position, behavior.state, &world_meta),
+                    recipe.label.to_string(),
+                    recipe.output.name().to_string(),
+                    recipe.output_quantity,
+                ));
+            }
+            continue;
+        }
+
+        let capacity = all_metrics
+            .0
+            .get(&identity.nation)
+            .map_or(0, |metrics| production_capacity(metrics.science));
+        let used = active_jobs.entry(identity.nation).or_default();
+        if *used >= capacity {
+            continue;
+        }
+
+        let Some(recipe) = world_meta.recipes.iter().find(|recipe| {
+            recipe.workshop == position.biome
+                && research.recipe_available(identity.nation, recipe.label, &world_meta)
+                && recipe
+                    .inputs
+                    .iter()
+                    .all(|(name, quantity)| inventory.quantity_of(name) >= *quantity)
+        }) else {
+            continue;
+        };
+
+        for (name, quantity) in &recipe.inputs {
+            inventory.remove(name, *quantity);
+        }
+        *used += 1;
+        commands.entity(entity).insert(CraftingJob {
+            recipe: recipe.label.to_string(),
+            progress: 0.0,
+            work_ticks: recipe.work_ticks,
+        });
+    }
+}
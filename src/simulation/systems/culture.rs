@@ -0,0 +1,156 @@
+//! Cultural identity on the map: each hex's people hold to a nation's
+//! culture, which spreads from the most vibrant nations into their
+//! neighbours, assimilates conquered land, and can carry a hex over to the
+//! nation it feels it belongs to without a shot fired.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    AllNationMetrics, AxialCoord, Heritage, Hex, HexGrid, Nation, War, WorldEvent, WorldEventLog,
+    WorldMetadata, WorldTime,
+};
+
+/// Cohesion a hex gains or loses per tick for each point of net pressure.
+const CULTURE_SPREAD: f32 = 0.3;
+/// Weight of the ruler's schools and officials in a hex's culture, relative
+/// to a single fully cohesive neighbour.
+const RULER_INFLUENCE: f32 = 0.5;
+/// Vigour a culture keeps in folk memory once its nation has fallen.
+const FOLK_MEMORY: f32 = 0.15;
+/// Cohesion a hex starts from in the culture that has just won it over.
+const ASSIMILATED_COHESION: f32 = 20.0;
+/// A hex must hold its culture this firmly before it will change allegiance.
+const DEFECTION_COHESION: f32 = 80.0;
+/// Culture lead the hex's own nation needs over its ruler to draw it away.
+const DEFECTION_GAP: f32 = 20.0;
+/// Chance per tick that a hex ready to defect does so.
+const DEFECTION_CHANCE: f64 = 0.01;
+
+/// How strongly `nation`'s culture radiates: its culture metric, or what
+/// folk memory keeps of it once the nation is gone.
+fn vigor(all_metrics: &AllNationMetrics, nation: Nation) -> f32 {
+    all_metrics
+        .0
+        .get(&nation)
+        .filter(|metrics| !metrics.is_destroyed)
+        .map_or(0.0, |metrics| metrics.culture / 100.0)
+        .max(FOLK_MEMORY)
+}
+
+/// Share of each nation's land whose people hold to another nation's
+/// culture, 0..100.
+pub fn foreign_shares<'a>(hexes: impl Iterator<Item = (&'a Hex, &'a Heritage)>) -> HashMap<Nation, f32> {
+    let mut counts: HashMap<Nation, (u32, u32)> = HashMap::new();
+    for (hex, heritage) in hexes {
+        let entry = counts.entry(hex.owner).or_insert((0, 0));
+        entry.1 += 1;
+        if heritage.culture != hex.owner {
+            entry.0 += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .map(|(nation, (foreign, total))| (nation, foreign as f32 / total.max(1) as f32 * 100.0))
+        .collect()
+}
+
+/// Spreads culture across the map, assimilates hexes to whichever culture
+/// presses hardest on them, and lets hexes devoted to a stronger neighbour's
+/// culture go over to it in peacetime.
+pub fn culture_system(
+    all_metrics: Res<AllNationMetrics>,
+    grid: Res<HexGrid>,
+    mut hexes: Query<(&mut Hex, &AxialCoord, &mut Heritage)>,
+    wars: Query<&War>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(769).wrapping_add(53));
+
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord, _)| (*coord, hex.owner)).collect();
+    let heritages: HashMap<AxialCoord, Heritage> =
+        hexes.iter().map(|(_, coord, heritage)| (*coord, *heritage)).collect();
+    let hostile: HashSet<(Nation, Nation)> = wars
+        .iter()
+        .flat_map(|war| [(war.aggressor, war.defender), (war.defender, war.aggressor)])
+        .collect();
+    let capitals: HashSet<AxialCoord> = grid.capitals.values().copied().collect();
+
+    // (winner, loser) -> hexes; BTreeMap keyed by name keeps events in a stable order.
+    let mut assimilated: BTreeMap<(&'static str, &'static str), (Nation, Nation, u32)> = BTreeMap::new();
+    let mut defected: BTreeMap<(&'static str, &'static str), (Nation, Nation, u32)> = BTreeMap::new();
+
+    let mut ordered: Vec<_> = hexes.iter_mut().collect();
+    ordered.sort_by_key(|(_, coord, _)| (coord.q, coord.r));
+    for (mut hex, coord, mut heritage) in ordered {
+        // 1. Every culture presses on the hex through its neighbours; the
+        //    ruler adds the weight of its schools and officials.
+        let mut pressure: HashMap<Nation, f32> = HashMap::new();
+        for neighbor in coord.neighbors() {
+            if let Some(other) = heritages.get(&neighbor) {
+                *pressure.entry(other.culture).or_insert(0.0) +=
+                    vigor(&all_metrics, other.culture) * other.cohesion / 100.0;
+            }
+        }
+        *pressure.entry(hex.owner).or_insert(0.0) += vigor(&all_metrics, hex.owner) * RULER_INFLUENCE;
+
+        let home = pressure.get(&heritage.culture).copied().unwrap_or(0.0)
+            + vigor(&all_metrics, heritage.culture) * heritage.cohesion / 100.0;
+        let rival = pressure
+            .iter()
+            .filter(|(culture, _)| **culture != heritage.culture)
+            .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.name().cmp(a.0.name())))
+            .map(|(culture, weight)| (*culture, *weight));
+
+        let rival_weight = rival.map_or(0.0, |(_, weight)| weight);
+        heritage.cohesion = (heritage.cohesion + (home - rival_weight) * CULTURE_SPREAD).min(100.0);
+        if heritage.cohesion <= 0.0
+            && let Some((culture, _)) = rival
+        {
+            assimilated
+                .entry((culture.name(), heritage.culture.name()))
+                .or_insert((culture, heritage.culture, 0))
+                .2 += 1;
+            *heritage = Heritage {
+                culture,
+                cohesion: ASSIMILATED_COHESION,
+            };
+        }
+
+        // 2. A hex devoted to a stronger neighbour's culture may go over to
+        //    it, so long as the two are at peace.
+        let (culture, owner) = (heritage.culture, hex.owner);
+        let kin_nearby = coord.neighbors().iter().any(|neighbor| owners.get(neighbor) == Some(&culture));
+        let culture_gap = all_metrics.0.get(&culture).zip(all_metrics.0.get(&owner)).map_or(0.0, |(kin, ruler)| {
+            if kin.is_destroyed { 0.0 } else { kin.culture - ruler.culture }
+        });
+        if culture != owner
+            && kin_nearby
+            && heritage.cohesion >= DEFECTION_COHESION
+            && culture_gap >= DEFECTION_GAP
+            && !capitals.contains(coord)
+            && !hostile.contains(&(culture, owner))
+            && rng.gen_bool(DEFECTION_CHANCE)
+        {
+            hex.owner = culture;
+            defected
+                .entry((culture.name(), owner.name()))
+                .or_insert((culture, owner, 0))
+                .2 += 1;
+        }
+    }
+
+    for (nation, from, hexes) in assimilated.into_values() {
+        event_log.push(WorldEvent::cultural_shift(time.tick, epoch, season, nation, from, hexes, false));
+    }
+    for (nation, from, hexes) in defected.into_values() {
+        event_log.push(WorldEvent::cultural_shift(time.tick, epoch, season, nation, from, hexes, true));
+    }
+}
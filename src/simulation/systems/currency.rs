@@ -0,0 +1,115 @@
+//! Money: each nation's coin supply, the prices it sets, and the debasements
+//! a state in debt resorts to when creditors stop trusting it.
+//!
+//! Prices follow the quantity theory loosely: a coin is worth the silver in
+//! it, watered down by whatever share of the supply the mint struck with
+//! nothing behind it. The price level closes on that target a little each
+//! tick, so printing and debasement show up as inflation over the following
+//! seasons rather than at once.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    ActiveShocks, AllNationMetrics, CREDIT_LIMIT, Identity, Inventory, Nation, NationTreasuries, War, WorldEvent,
+    WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Debt, as a share of the credit limit, past which a state considers
+/// clipping its coin.
+const DEBASEMENT_DEBT: f32 = 0.75;
+/// Coffers below this leave a state at war tempted to clip its coin.
+const WAR_CHEST: f32 = 150.0;
+/// Chance per tick that a state so pressed debases.
+const DEBASEMENT_CHANCE: f64 = 0.005;
+/// Share of the silver in each coin a debasement clips.
+const DEBASEMENT_CUT: f32 = 0.2;
+/// Share of the money supply the state remints at the lighter standard,
+/// keeping the silver clipped from it.
+const RECOINAGE_SHARE: f32 = 0.01;
+/// A coin is never clipped below this much silver.
+const MIN_SILVER: f32 = 0.2;
+/// Coffers above this hold enough to start buying back printed coin.
+const RETIREMENT_RESERVE: f32 = 300.0;
+/// Share of the excess spent buying back printed coin each tick.
+const RETIREMENT_RATE: f32 = 0.1;
+/// Share of the gap to its target the price level closes each tick.
+const PRICE_ADJUSTMENT: f32 = 0.02;
+
+/// Measures each nation's money supply, lets deeply indebted states debase
+/// their coin, retires printed coin from full coffers, and moves prices
+/// toward what the supply will bear.
+#[allow(clippy::too_many_arguments)]
+pub fn monetary_system(
+    all_metrics: Res<AllNationMetrics>,
+    mut treasuries: ResMut<NationTreasuries>,
+    purses: Query<(&Identity, &Inventory)>,
+    wars: Query<&War>,
+    shocks: Res<ActiveShocks>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(887).wrapping_add(61));
+
+    let mut held: HashMap<Nation, f32> = HashMap::new();
+    for (identity, inventory) in purses.iter() {
+        *held.entry(identity.nation).or_insert(0.0) += inventory.currency;
+    }
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+    for nation in nations {
+        if all_metrics.0[&nation].is_destroyed {
+            continue;
+        }
+        let treasury = treasuries.treasury_mut(nation);
+
+        // 1. A state drowning in debt, or fighting a war on empty coffers,
+        //    remints coin at a lighter standard and keeps the silver saved.
+        let at_war = wars.iter().any(|war| war.aggressor == nation || war.defender == nation);
+        let pressed =
+            treasury.debt() > CREDIT_LIMIT * DEBASEMENT_DEBT || (at_war && treasury.balance < WAR_CHEST);
+        if pressed && treasury.coinage.silver > MIN_SILVER && rng.gen_bool(DEBASEMENT_CHANCE) {
+            let cut = treasury.coinage.silver - (treasury.coinage.silver * (1.0 - DEBASEMENT_CUT)).max(MIN_SILVER);
+            let minted = treasury.coinage.money_supply * RECOINAGE_SHARE * cut / treasury.coinage.silver;
+            treasury.coinage.silver -= cut;
+            treasury.balance += minted;
+            treasury.coinage.printed += minted;
+            event_log.push(WorldEvent::debasement(
+                time.tick,
+                epoch,
+                season,
+                nation,
+                treasury.coinage.silver,
+                minted,
+                treasury.coinage.price_level,
+            ));
+        }
+
+        // 2. Full coffers buy back printed coin and melt it down.
+        let excess = (treasury.balance - RETIREMENT_RESERVE).max(0.0);
+        let retired = (excess * RETIREMENT_RATE).min(treasury.coinage.printed);
+        treasury.balance -= retired;
+        treasury.coinage.printed -= retired;
+
+        // 3. Prices move toward the silver in a coin, diluted by the share of
+        //    the supply struck with nothing behind it and pushed about by
+        //    whatever shocks the nation is weathering.
+        let supply = held.get(&nation).copied().unwrap_or(0.0) + treasury.payroll + treasury.balance.max(0.0);
+        let dilution = if supply > 0.0 {
+            supply / (supply - treasury.coinage.printed).max(supply * 0.1)
+        } else {
+            1.0
+        };
+        let target = dilution / treasury.coinage.silver * shocks.price_multiplier(nation, &world_meta);
+        let previous = treasury.coinage.price_level;
+        treasury.coinage.price_level += (target - previous) * PRICE_ADJUSTMENT;
+        treasury.coinage.inflation = (treasury.coinage.price_level / previous - 1.0) * 100.0;
+        treasury.coinage.money_supply = supply;
+    }
+}
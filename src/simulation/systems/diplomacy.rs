@@ -0,0 +1,356 @@
+//! Nation-level diplomacy: opinion drift, treaties signed and broken on
+//! review, and allies called to arms when one of them is attacked.
+
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::*;
+
+use super::warfare::war_goal;
+use crate::simulation::{
+    AllNationMetrics, CasusBelli, DiplomacyMatrix, Nation, NationMetrics, NationTreasuries,
+    TreatyKind, pair_key, War, WarLedger, WorldEvent, WorldEventKind, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Treaties are reconsidered once every this many ticks.
+const TREATY_REVIEW_INTERVAL: u64 = 10;
+/// Share of opinion that fades each tick, pulling relations back to neutral.
+const OPINION_DECAY: f32 = 0.01;
+/// Trust an ally needs before it answers a call to arms.
+const ALLY_CALL_TRUST: f32 = 30.0;
+/// Share of a vassal's tax revenue owed to its overlord.
+const VASSAL_TRIBUTE: f32 = 0.2;
+
+/// Rough weight a nation carries at the table.
+fn power(metrics: &NationMetrics) -> f32 {
+    metrics.military + metrics.economy
+}
+
+/// Returns the treaty that should lapse between `a` and `b`, with the reason
+/// and the party walking away.
+fn treaty_to_break(
+    diplomacy: &DiplomacyMatrix,
+    (a, metrics_a): (Nation, &NationMetrics),
+    (b, metrics_b): (Nation, &NationMetrics),
+) -> Option<(TreatyKind, Nation, &'static str)> {
+    let relation = diplomacy.get(a, b)?;
+
+    if let Some(overlord) = relation.overlord {
+        let vassal = if overlord == a { b } else { a };
+        let (vassal_metrics, overlord_metrics) =
+            if overlord == a { (metrics_b, metrics_a) } else { (metrics_a, metrics_b) };
+        if power(vassal_metrics) >= power(overlord_metrics) * 0.8 {
+            return Some((TreatyKind::Vassalage, vassal, "독립 선언"));
+        }
+        if relation.opinion < -30.0 {
+            return Some((TreatyKind::Vassalage, vassal, "관계 악화"));
+        }
+    }
+    // The side less invested in the relationship is the one to walk away.
+    let walker = if metrics_a.diplomacy <= metrics_b.diplomacy { a } else { b };
+    if relation.has(TreatyKind::Alliance) && relation.opinion < 0.0 {
+        return Some((TreatyKind::Alliance, walker, "관계 악화"));
+    }
+    if relation.has(TreatyKind::TradePact) && relation.opinion < -20.0 {
+        return Some((TreatyKind::TradePact, walker, "관계 악화"));
+    }
+    if relation.has(TreatyKind::NonAggression) && relation.opinion < -40.0 {
+        return Some((TreatyKind::NonAggression, walker, "관계 악화"));
+    }
+    None
+}
+
+/// Returns a treaty `a` and `b` are now willing to sign, and the overlord for
+/// vassalage.
+fn treaty_to_sign(
+    diplomacy: &DiplomacyMatrix,
+    (a, metrics_a): (Nation, &NationMetrics),
+    (b, metrics_b): (Nation, &NationMetrics),
+    rivals: &[Nation],
+) -> Option<(TreatyKind, Option<Nation>)> {
+    let relation = diplomacy.get(a, b).cloned().unwrap_or_default();
+    let (opinion, trust) = (relation.opinion, relation.trust);
+
+    if !relation.has(TreatyKind::NonAggression) && opinion >= 5.0 && trust >= 5.0 {
+        return Some((TreatyKind::NonAggression, None));
+    }
+    if !relation.has(TreatyKind::TradePact)
+        && opinion >= 12.0
+        && metrics_a.economy >= 40.0
+        && metrics_b.economy >= 40.0
+    {
+        return Some((TreatyKind::TradePact, None));
+    }
+    // An alliance needs a common rival to stand against.
+    let common_rival = rivals.iter().any(|rival| {
+        diplomacy.opinion(a, *rival) <= -5.0 && diplomacy.opinion(b, *rival) <= -5.0
+    });
+    if !relation.has(TreatyKind::Alliance) && opinion >= 20.0 && trust >= 20.0 && common_rival {
+        return Some((TreatyKind::Alliance, None));
+    }
+    // A nation dwarfed by a friendly neighbour bends the knee.
+    if relation.overlord.is_none() && opinion >= -10.0 {
+        let (weak, strong) = if power(metrics_a) < power(metrics_b) {
+            ((a, metrics_a), (b, metrics_b))
+        } else {
+            ((b, metrics_b), (a, metrics_a))
+        };
+        if power(weak.1) < power(strong.1) * 0.4 && weak.1.territory < 20.0 {
+            return Some((TreatyKind::Vassalage, Some(strong.0)));
+        }
+    }
+    None
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn diplomacy_system(
+    mut commands: Commands,
+    mut diplomacy: ResMut<DiplomacyMatrix>,
+    mut all_metrics: ResMut<AllNationMetrics>,
+    mut treasuries: ResMut<NationTreasuries>,
+    mut ledger: ResMut<WarLedger>,
+    wars: Query<&War>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+    let mut at_war: HashSet<(Nation, Nation)> = wars
+        .iter()
+        .map(|war| pair_key(war.aggressor, war.defender))
+        .collect();
+
+    // 1. Opinion and trust drift: war sours relations, shared enemies and
+    //    treaties warm them, and everything slowly fades back to neutral.
+    for (i, a) in nations.iter().enumerate() {
+        for b in &nations[i + 1..] {
+            let (metrics_a, metrics_b) = (&all_metrics.0[a], &all_metrics.0[b]);
+            let shared_enemy = nations.iter().any(|c| {
+                c != a && c != b && at_war.contains(&pair_key(*a, *c)) && at_war.contains(&pair_key(*b, *c))
+            });
+            let aggrieved = ledger.has_grievance(*a, *b) || ledger.has_grievance(*b, *a);
+            let statecraft = (metrics_a.diplomacy + metrics_b.diplomacy) / 200.0;
+            let faith_gap = (metrics_a.religion - metrics_b.religion).abs() / 100.0;
+
+            let relation = diplomacy.relation_mut(*a, *b);
+            let (mut opinion, mut trust) = if at_war.contains(&pair_key(*a, *b)) {
+                (-0.5, -0.2)
+            } else {
+                (statecraft * 0.15 - faith_gap * 0.1, 0.05)
+            };
+            if shared_enemy {
+                opinion += 0.3;
+            }
+            if aggrieved {
+                opinion -= 0.1;
+            }
+            // Faith that differs hex by hex along the border breeds friction.
+            opinion -= relation.religious_tension * 0.005;
+            if relation.has(TreatyKind::NonAggression) {
+                opinion += 0.05;
+                trust += 0.02;
+            }
+            if relation.has(TreatyKind::TradePact) {
+                opinion += 0.1;
+                trust += 0.02;
+            }
+            if relation.has(TreatyKind::Alliance) {
+                opinion += 0.1;
+                trust += 0.02;
+            }
+            opinion -= relation.opinion * OPINION_DECAY;
+            relation.adjust(opinion, trust);
+
+            let trade_pact = relation.has(TreatyKind::TradePact);
+            let overlord = relation.overlord;
+            if trade_pact {
+                for nation in [a, b] {
+                    if let Some(metrics) = all_metrics.0.get_mut(nation) {
+                        metrics.economy = (metrics.economy + 0.05).min(100.0);
+                    }
+                }
+            }
+            if let Some(overlord) = overlord {
+                // A vassal hands a share of its tax take to its overlord.
+                let vassal = if overlord == *a { *b } else { *a };
+                let revenue = treasuries.0.get(&vassal).map_or(0.0, |treasury| treasury.revenue);
+                treasuries.transfer(vassal, overlord, revenue * VASSAL_TRIBUTE);
+            }
+        }
+    }
+
+    // 2. A peace leaves scars in proportion to how one-sided it was;
+    //    declarations break pacts between the belligerents, and the
+    //    defender's allies are called to arms.
+    let settlements: Vec<(Nation, Nation, bool)> = event_log
+        .events_at(time.tick)
+        .filter_map(|event| match &event.kind {
+            WorldEventKind::WarEnded { aggressor, defender, victor, .. } => {
+                Some((*aggressor, *defender, victor.is_some()))
+            }
+            _ => None,
+        })
+        .collect();
+    for (aggressor, defender, decisive) in settlements {
+        let bitterness = if decisive { -15.0 } else { -5.0 };
+        diplomacy.relation_mut(aggressor, defender).adjust(bitterness, 0.0);
+    }
+
+    let mut declarations: Vec<(Nation, Nation, CasusBelli)> = event_log
+        .events_at(time.tick)
+        .filter_map(|event| match &event.kind {
+            WorldEventKind::WarDeclared { aggressor, defender, casus_belli, .. } => {
+                Some((*aggressor, *defender, *casus_belli))
+            }
+            _ => None,
+        })
+        .collect();
+    declarations.reverse();
+
+    for (aggressor, defender, casus_belli) in declarations {
+        let mut broke_pact = false;
+        for kind in [TreatyKind::NonAggression, TreatyKind::TradePact] {
+            if diplomacy.relation_mut(aggressor, defender).treaties.remove(&kind).is_some() {
+                broke_pact = true;
+                event_log.push(WorldEvent::treaty_broken(
+                    time.tick, epoch, season, aggressor, defender, kind, "선전포고",
+                ));
+            }
+        }
+        if casus_belli == CasusBelli::AllyCall {
+            continue;
+        }
+        // Tearing up a pact to attack costs trust with everyone watching.
+        if broke_pact {
+            for nation in &nations {
+                if *nation != aggressor {
+                    diplomacy.relation_mut(aggressor, *nation).adjust(-5.0, -10.0);
+                }
+            }
+        }
+
+        // Allies bound to both sides sit the war out.
+        let aggressor_allies = diplomacy.allies_of(aggressor);
+        for ally in diplomacy.allies_of(defender) {
+            let destroyed = all_metrics.0.get(&ally).is_none_or(|m| m.is_destroyed);
+            if ally == aggressor
+                || destroyed
+                || aggressor_allies.contains(&ally)
+                || at_war.contains(&pair_key(ally, aggressor))
+            {
+                continue;
+            }
+            let relation = diplomacy.relation_mut(ally, defender);
+            let bound = relation.overlord.is_some();
+            if !bound && relation.trust < ALLY_CALL_TRUST {
+                relation.treaties.remove(&TreatyKind::Alliance);
+                relation.adjust(-20.0, -30.0);
+                event_log.push(WorldEvent::treaty_broken(
+                    time.tick, epoch, season, ally, defender, TreatyKind::Alliance, "동맹 의무 거부",
+                ));
+                continue;
+            }
+
+            let id = ledger.allocate_id();
+            let goal = war_goal(CasusBelli::AllyCall);
+            commands.spawn(War {
+                id,
+                aggressor: ally,
+                defender: aggressor,
+                casus_belli: CasusBelli::AllyCall,
+                goal,
+                declared_at: time.tick,
+                score: 0.0,
+                aggressor_exhaustion: 0.0,
+                defender_exhaustion: 0.0,
+                battles: 0,
+                front: Vec::new(),
+            });
+            at_war.insert(pair_key(ally, aggressor));
+            event_log.push(WorldEvent::war_declared(
+                time.tick,
+                epoch,
+                season,
+                id,
+                ally,
+                aggressor,
+                CasusBelli::AllyCall,
+                goal,
+            ));
+        }
+    }
+
+    // 3. Periodic review: each pair at peace may drop a treaty that no
+    //    longer serves it, or sign a new one.
+    if !time.tick.is_multiple_of(TREATY_REVIEW_INTERVAL) {
+        return;
+    }
+    for (i, a) in nations.iter().enumerate() {
+        for b in &nations[i + 1..] {
+            let (metrics_a, metrics_b) = (&all_metrics.0[a], &all_metrics.0[b]);
+
+            if metrics_a.is_destroyed || metrics_b.is_destroyed {
+                let relation = diplomacy.relation_mut(*a, *b);
+                let lapsed: Vec<TreatyKind> = TreatyKind::ALL
+                    .into_iter()
+                    .filter(|kind| relation.treaties.remove(kind).is_some())
+                    .collect();
+                relation.overlord = None;
+                let survivor = if metrics_a.is_destroyed { *b } else { *a };
+                for kind in lapsed {
+                    event_log.push(WorldEvent::treaty_broken(
+                        time.tick, epoch, season, survivor, other_of(*a, *b, survivor), kind, "국가 멸망",
+                    ));
+                }
+                continue;
+            }
+            if at_war.contains(&pair_key(*a, *b)) {
+                continue;
+            }
+
+            if let Some((kind, walker, reason)) =
+                treaty_to_break(&diplomacy, (*a, metrics_a), (*b, metrics_b))
+            {
+                let relation = diplomacy.relation_mut(*a, *b);
+                relation.treaties.remove(&kind);
+                if kind == TreatyKind::Vassalage {
+                    relation.overlord = None;
+                }
+                relation.adjust(-10.0, -15.0);
+                let other = other_of(*a, *b, walker);
+                event_log.push(WorldEvent::treaty_broken(
+                    time.tick, epoch, season, walker, other, kind, reason,
+                ));
+                continue;
+            }
+
+            let rivals: Vec<Nation> =
+                nations.iter().filter(|c| *c != a && *c != b).copied().collect();
+            if let Some((kind, overlord)) =
+                treaty_to_sign(&diplomacy, (*a, metrics_a), (*b, metrics_b), &rivals)
+            {
+                let relation = diplomacy.relation_mut(*a, *b);
+                relation.treaties.insert(kind, time.tick);
+                relation.adjust(0.0, 5.0);
+                // The vassal is named first so the log reads as its submission.
+                let (party, counterparty) = match overlord {
+                    Some(overlord) => {
+                        relation.overlord = Some(overlord);
+                        (other_of(*a, *b, overlord), overlord)
+                    }
+                    None => (*a, *b),
+                };
+                event_log.push(WorldEvent::treaty_signed(
+                    time.tick, epoch, season, party, counterparty, kind,
+                ));
+            }
+        }
+    }
+}
+
+/// The member of the pair `a`, `b` that is not `nation`.
+fn other_of(a: Nation, b: Nation, nation: Nation) -> Nation {
+    if nation == a { b } else { a }
+}
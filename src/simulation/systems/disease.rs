@@ -0,0 +1,237 @@
+//! Sickness: a susceptible-infected-recovered contagion that passes between
+//! NPCs sharing a hex, lingers in crowded hexes, rides along with traders
+//! and gatherings, and is eased by the Temple of Suns' healers.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    ActiveShocks, Attributes, AxialCoord, Behavior, BehaviorState, Biome, Contagion, Epidemics, Episode, Faction,
+    HarmCause, Hex, HexGrid, Identity, Immunity, Infection, InfectionSample, Injury, Memory, Nation, Outbreak,
+    Position, RegionalWeather, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Chance per tick of catching it from a hex where everyone is sick.
+const TRANSMISSION: f32 = 0.3;
+/// Chance per tick of catching it from a hex fully laden with sickness.
+const MIASMA_EXPOSURE: f32 = 0.04;
+/// Load each sick NPC leaves in the hex it stands on each tick.
+const SHEDDING: f32 = 3.0;
+/// Share of the gap to its neighbours' average a hex's load closes each tick.
+const CONTAGION_DIFFUSION: f32 = 0.05;
+const CONTAGION_DECAY: f32 = 0.9;
+/// Chance per tick a susceptible NPC in a Village falls sick from its wells.
+const VILLAGE_SPILLOVER: f32 = 0.0004;
+
+/// Chance per tick the sick shake it off unaided.
+const RECOVERY: f32 = 0.05;
+/// Added recovery chance for each temple healer on the patient's hex.
+const HEALER_BONUS: f32 = 0.04;
+const MAX_HEALERS: usize = 3;
+const IMMUNITY_TICKS: u64 = 150;
+
+/// Health and stamina the sick lose each tick.
+const SICKNESS_TOLL: f32 = 0.5;
+const SICKNESS_FATIGUE: f32 = 1.5;
+
+/// An outbreak is declared once this many are sick and they make up at
+/// least `OUTBREAK_SHARE` of the nation.
+const OUTBREAK_CASES: u32 = 3;
+const OUTBREAK_SHARE: f32 = 0.1;
+/// Infection curves take a sample once per this many ticks.
+const SAMPLE_INTERVAL: u64 = 5;
+
+/// How much of the sickness around it an NPC takes in, by what it is doing.
+fn exposure(state: BehaviorState) -> f32 {
+    match state {
+        BehaviorState::Trade => 1.5,
+        BehaviorState::Craft | BehaviorState::Pray | BehaviorState::Idle => 1.0,
+        BehaviorState::Rest => 0.6,
+        _ => 0.8,
+    }
+}
+
+#[derive(Default)]
+struct Tally {
+    susceptible: u32,
+    infected: u32,
+    recovered: u32,
+    cases: u32,
+    deaths: u32,
+}
+
+/// Spreads sickness between NPCs and hexes, heals or kills the sick, and
+/// keeps each nation's infection curve and outbreak up to date.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn disease_system(
+    mut commands: Commands,
+    mut npcs: Query<(
+        Entity,
+        &Identity,
+        &Position,
+        &Behavior,
+        &Memory,
+        &mut Attributes,
+        Option<&Infection>,
+        Option<&Immunity>,
+    )>,
+    mut hexes: Query<(&Hex, &AxialCoord, &mut Contagion)>,
+    mut epidemics: ResMut<Epidemics>,
+    shocks: Res<ActiveShocks>,
+    weather: Res<RegionalWeather>,
+    grid: Res<HexGrid>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+
+    // 1. Who stands where: the crowd, the sick and the healers on each hex.
+    let mut crowds: HashMap<AxialCoord, (u32, u32, usize)> = HashMap::new();
+    for (_, identity, position, _, _, _, infection, _) in npcs.iter() {
+        let crowd = crowds.entry(grid.coord_at(position.x, position.y)).or_insert((0, 0, 0));
+        crowd.0 += 1;
+        if infection.is_some() {
+            crowd.1 += 1;
+        } else if identity.faction == Faction::TempleOfSuns {
+            crowd.2 += 1;
+        }
+    }
+
+    // 2. The sick leave sickness behind; it seeps into neighbouring hexes
+    //    and fades.
+    let loads: HashMap<AxialCoord, f32> =
+        hexes.iter().map(|(_, coord, contagion)| (*coord, contagion.load)).collect();
+    for (_, coord, mut contagion) in hexes.iter_mut() {
+        let neighbors: Vec<f32> = coord.neighbors().iter().filter_map(|n| loads.get(n).copied()).collect();
+        let local = loads[coord];
+        let mut next = local;
+        if !neighbors.is_empty() {
+            let average = neighbors.iter().sum::<f32>() / neighbors.len() as f32;
+            next += (average - local) * CONTAGION_DIFFUSION;
+        }
+        next += crowds.get(coord).map_or(0.0, |crowd| crowd.1 as f32 * SHEDDING);
+        contagion.load = (next * CONTAGION_DECAY).clamp(0.0, 100.0);
+    }
+
+    // 3. The healthy catch it, the sick recover or succumb.
+    let mut tallies: HashMap<Nation, Tally> = HashMap::new();
+    for (entity, identity, position, behavior, memory, mut attributes, infection, immunity) in &mut npcs {
+        let mut rng = SmallRng::seed_from_u64(
+            time.tick
+                .wrapping_mul(1009)
+                .wrapping_add(identity.id * 13)
+                .wrapping_mul(73),
+        );
+        let tally = tallies.entry(identity.nation).or_default();
+        let coord = grid.coord_at(position.x, position.y);
+        let (people, sick, healers) = crowds.get(&coord).copied().unwrap_or((1, 0, 0));
+        let contagion = weather.weather(coord).contagion();
+
+        if infection.is_some() {
+            attributes.health -= SICKNESS_TOLL;
+            attributes.stamina = (attributes.stamina - SICKNESS_FATIGUE).max(0.0);
+            if attributes.health <= 0.0 {
+                commands.entity(entity).insert(Injury { cause: HarmCause::Disease });
+                tally.deaths += 1;
+                continue;
+            }
+            let care = RECOVERY + HEALER_BONUS * healers.min(MAX_HEALERS) as f32;
+            if rng.gen_bool(care.clamp(0.0, 1.0) as f64) {
+                commands
+                    .entity(entity)
+                    .remove::<Infection>()
+                    .insert(Immunity { until: time.tick + IMMUNITY_TICKS });
+                tally.recovered += 1;
+            } else {
+                tally.infected += 1;
+            }
+            continue;
+        }
+
+        if let Some(immunity) = immunity {
+            if immunity.until > time.tick {
+                tally.recovered += 1;
+                continue;
+            }
+            commands.entity(entity).remove::<Immunity>();
+        }
+
+        // Guests at yesterday's gatherings mixed with everyone there.
+        let gathered = memory.episodes.iter().rev().any(|episode| {
+            matches!(episode, Episode::Gathering { tick, .. } if *tick + 1 >= time.tick)
+        });
+        let load = loads.get(&coord).copied().unwrap_or(0.0);
+        let mut chance = TRANSMISSION * sick as f32 / people.max(1) as f32 + MIASMA_EXPOSURE * load / 100.0;
+        if position.biome == Biome::Village {
+            chance += VILLAGE_SPILLOVER;
+        }
+        // Plagues and the like seed sickness wherever they reach.
+        chance += shocks.contagion(identity.nation, position.biome, &world_meta);
+        chance *= exposure(behavior.state) * contagion * if gathered { 2.0 } else { 1.0 };
+        if rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+            commands.entity(entity).insert(Infection { contracted_at: time.tick });
+            tally.infected += 1;
+            tally.cases += 1;
+        } else {
+            tally.susceptible += 1;
+        }
+    }
+
+    // 4. Outbreaks are declared and run their course; the curves record it.
+    let mut nations: Vec<Nation> = tallies.keys().chain(epidemics.0.keys()).copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+    nations.dedup();
+    for nation in nations {
+        let tally = tallies.remove(&nation).unwrap_or_default();
+        let population = tally.susceptible + tally.infected + tally.recovered;
+        let curve = epidemics.curve_mut(nation);
+        curve.total_cases += tally.cases;
+
+        match curve.outbreak.as_mut() {
+            Some(outbreak) => {
+                outbreak.cases += tally.cases;
+                outbreak.deaths += tally.deaths;
+                outbreak.peak = outbreak.peak.max(tally.infected);
+                if tally.infected == 0 {
+                    event_log.push(WorldEvent::epidemic_ended(time.tick, epoch, season, nation, outbreak));
+                    curve.outbreak = None;
+                }
+            }
+            None if tally.infected >= OUTBREAK_CASES
+                && tally.infected as f32 >= population as f32 * OUTBREAK_SHARE =>
+            {
+                event_log.push(WorldEvent::epidemic(
+                    time.tick,
+                    epoch,
+                    season,
+                    nation,
+                    tally.infected,
+                    population,
+                ));
+                curve.outbreak = Some(Outbreak {
+                    started_at: time.tick,
+                    peak: tally.infected,
+                    cases: tally.infected,
+                    deaths: tally.deaths,
+                });
+            }
+            None => {}
+        }
+
+        if time.tick.is_multiple_of(SAMPLE_INTERVAL) {
+            epidemics.record(
+                nation,
+                InfectionSample {
+                    tick: time.tick,
+                    susceptible: tally.susceptible,
+                    infected: tally.infected,
+                    recovered: tally.recovered,
+                },
+            );
+        }
+    }
+}
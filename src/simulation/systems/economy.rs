@@ -1,13 +1,51 @@
-//! Trade and economy placeholder system.
+//! The economy: NPCs gather goods and sell them to whichever nation holds
+//! the hex they work in, paying tax on the sale and buying their provisions
+//! there, and nations spend their revenue on goods, upkeep and investment
+//! that flows back to their people as sales and wages. No coin is made or
+//! lost along the way; only the mint adds to it.
+
+use std::collections::HashMap;
 
 use bevy_ecs::prelude::*;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 
 use crate::simulation::{
-    Behavior, BehaviorState, Identity, Inventory, Position, AllNationMetrics, WorldMetadata, WorldTime,
+    ActiveShocks, Attributes, AxialCoord, Behavior, BehaviorState, Episode, Hex, HexGrid, Identity, Inventory,
+    ItemKind, Memory, Nation, NationTreasuries, CREDIT_LIMIT, Position, AllNationMetrics, BudgetLine,
+    Infection, NationResearch, NationStrategies, RegionalWeather, RelationshipGraph, Reputation, WorldMetadata, WorldTime, standing_trade_modifier,
 };
 
+/// Share of every NPC's earnings taken in tax.
+const TAX_RATE: f32 = 0.15;
+/// Share of the tax owed as market dues to the nation holding the hex the
+/// NPC works in; the rest goes to the NPC's own nation.
+const MARKET_DUES: f32 = 0.5;
+/// Coin one point of metric upkeep or investment costs.
+const COIN_PER_POINT: f32 = 4.0;
+/// Coin a nation keeps in its coffers before investing the surplus.
+const TREASURY_RESERVE: f32 = 150.0;
+/// Share of the surplus spent each tick.
+const INVESTMENT_RATE: f32 = 0.1;
+/// Metric points bought per point's worth of coin spent.
+const INVESTMENT_EFFICIENCY: f32 = 0.25;
+/// Interest charged on debt each tick.
+const DEBT_INTEREST: f32 = 0.002;
+/// The economy closes this share of the gap to its fiscal health each tick.
+const ECONOMY_DRIFT: f32 = 0.05;
+
+/// Gathering stops yielding goods once an NPC carries this many units.
+pub(crate) const GATHER_CARRY_LIMIT: u32 = 20;
+/// Units of carried goods a trader sells each tick.
+const TRADE_LOT: u32 = 3;
+/// Units of goods a gatherer brings in each tick at base yields.
+const GATHER_YIELD: f32 = 1.0;
+/// Coin one unit of goods fetches at base prices; crafted goods fetch more.
+const RESOURCE_VALUE: f32 = 2.0;
+const CRAFTED_VALUE: f32 = 6.0;
+/// Share of a healthy NPC's earnings the sick still bring in.
+const SICK_PRODUCTIVITY: f32 = 0.5;
+
 fn season_trade_modifier(season: &str) -> f32 {
     match season {
         "꽃피움 계절" => 1.1,
@@ -34,78 +72,109 @@ fn segment_trade_modifier(segment: &str) -> f32 {
     }
 }
 
-fn upkeep_penalty(base: f32, upkeep: f32) -> f32 {
-    base * upkeep.max(0.5)
+fn goods_value(item: &ItemKind) -> f32 {
+    match item {
+        ItemKind::Resource(_) => RESOURCE_VALUE,
+        ItemKind::Equipment(_) | ItemKind::Artifact(_) => CRAFTED_VALUE,
+    }
+}
+
+/// Coin an NPC spends on food and lodging each tick at base prices, scaled
+/// by its faction's upkeep burden.
+const PROVISIONS: f32 = 0.8;
+/// Share of its savings an NPC spends at the market each tick on top of
+/// its provisions.
+const SPENDING_RATE: f32 = 0.01;
+
+/// Splits `tax` between the nation holding the hex and the worker's own;
+/// dues owed to a foreign host are changed into its coin.
+fn levy(treasuries: &mut NationTreasuries, tax: f32, host: Nation, nation: Nation) {
+    let dues = treasuries.convert(tax * MARKET_DUES, nation, host);
+    treasuries.treasury_mut(host).collect(dues);
+    treasuries.treasury_mut(nation).collect(tax * (1.0 - MARKET_DUES));
+}
+
+/// How healthy a nation's finances are, 0..100: the share of its upkeep its
+/// revenue covers, less a drag for debt.
+fn fiscal_health(revenue: f32, upkeep: f32, debt: f32) -> f32 {
+    let coverage = revenue / (revenue + upkeep).max(f32::EPSILON);
+    (coverage * 100.0 - debt / CREDIT_LIMIT * 30.0).clamp(0.0, 100.0)
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn economy_system(
-    mut query: Query<(&Identity, &Position, &Behavior, &mut Inventory)>,
+    mut query: Query<(
+        &Identity,
+        &Position,
+        &Behavior,
+        &Attributes,
+        &Reputation,
+        &mut Inventory,
+        &mut Memory,
+        Option<&Infection>,
+    )>,
     mut all_metrics: ResMut<AllNationMetrics>,
+    mut treasuries: ResMut<NationTreasuries>,
+    strategies: Res<NationStrategies>,
+    research: Res<NationResearch>,
+    shocks: Res<ActiveShocks>,
+    weather: Res<RegionalWeather>,
+    graph: Res<RelationshipGraph>,
+    grid: Res<HexGrid>,
+    hexes: Query<(&Hex, &AxialCoord)>,
     world_meta: Res<WorldMetadata>,
     time: Res<WorldTime>,
 ) {
     let (segment, season) = world_meta.epoch_for_tick(time.tick);
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord)| (*coord, hex.owner)).collect();
 
-    // First, handle nation-level economic updates (upkeep, investment, growth, decay)
-    for metrics in all_metrics.0.values_mut() {
-        // 1. Military Upkeep
-        let military_upkeep = metrics.military * 0.05;
-        metrics.economy -= military_upkeep;
-
-        // 2. Other Metrics Upkeep (they cost a little bit of economy)
-        let science_upkeep = metrics.science * 0.02;
-        let culture_upkeep = metrics.culture * 0.01;
-        let diplomacy_upkeep = metrics.diplomacy * 0.02;
-        metrics.economy -= science_upkeep + culture_upkeep + diplomacy_upkeep;
-
-        // If economy is negative after upkeep, it hurts the military
-        if metrics.economy < 0.0 {
-            metrics.military += metrics.economy * 0.2; // Negative economy reduces military
-            metrics.economy = 0.0;
-        }
-
-        // 3. Investment and Growth based on Economy
-        if metrics.economy > 70.0 { // High Economy Boosts
-            metrics.military += 0.5;
-            metrics.science += 0.4;
-            metrics.diplomacy += 0.3;
-            metrics.economy -= 5.0; // Investment cost
-        } else if metrics.economy > 40.0 { // Medium Economy
-            metrics.military += 0.1;
-            metrics.science += 0.15;
-            metrics.culture += 0.2;
-            metrics.religion += 0.1;
-            metrics.diplomacy += 0.1;
-            metrics.economy -= 1.0;
+    // Last tick's spending is paid out in equal wages to each nation's people.
+    let mut headcount: HashMap<Nation, u32> = HashMap::new();
+    for (identity, ..) in query.iter() {
+        *headcount.entry(identity.nation).or_insert(0) += 1;
+    }
+    let mut wages: HashMap<Nation, f32> = HashMap::new();
+    for (nation, treasury) in treasuries.0.iter_mut() {
+        if let Some(count) = headcount.get(nation) {
+            wages.insert(*nation, treasury.payroll / *count as f32);
+            treasury.payroll = 0.0;
         }
-
-        // 4. General Decay
-        metrics.science *= 0.999;
-        metrics.culture *= 0.998;
-        metrics.diplomacy *= 0.999;
-        metrics.religion *= 0.9995;
+        treasury.revenue = 0.0;
+        treasury.expenses = 0.0;
     }
 
-    // Second, handle individual NPC actions contributing to economy
-    for (identity, position, behavior, mut inventory) in &mut query {
+    // NPCs draw their wages, earn, and pay tax where they work.
+    for (identity, position, behavior, attributes, reputation, mut inventory, mut memory, infection) in
+        &mut query
+    {
         let nation = identity.nation;
+        inventory.currency += wages.get(&nation).copied().unwrap_or(0.0);
+        let here = grid.coord_at(position.x, position.y);
+        let host = owners.get(&here).copied().unwrap_or(nation);
         let metrics = all_metrics.0.get_mut(&nation).unwrap();
+        // Earnings are reckoned in the NPC's own coin at its going prices.
+        let prices = treasuries.price_level(nation);
 
         let biome = position.biome;
         let faction = identity.faction;
 
-        let base_trade_yield = 6.0;
-        let base_gather_value = 3.0;
         let trade_multiplier = world_meta.biome_trade_opportunity(biome)
             * world_meta.faction_trade_yield(faction)
             * season_trade_modifier(season)
-            * segment_trade_modifier(segment);
-        let resource_multiplier =
-            world_meta.biome_resource_abundance(biome) * season_gather_modifier(season);
+            * segment_trade_modifier(segment)
+            * standing_trade_modifier(reputation, attributes.fame, biome, &world_meta)
+            * graph.partner_terms(identity.id)
+            * shocks.trade_multiplier(nation, biome, &world_meta);
+        let resource_multiplier = world_meta.biome_resource_abundance(biome)
+            * season_gather_modifier(season)
+            * shocks.gather_multiplier(nation, biome, &world_meta)
+            * weather.weather(here).yield_modifier();
         let risk_factor =
             world_meta.biome_risk_factor(biome) / world_meta.faction_volatility_resistance(faction);
 
         let upkeep = world_meta.faction_upkeep_burden(faction);
+        let vigor = if infection.is_some() { SICK_PRODUCTIVITY } else { 1.0 };
 
         let mut rng = SmallRng::seed_from_u64(
             time.tick
@@ -114,25 +183,112 @@ pub fn economy_system(
                 .wrapping_mul(59),
         );
 
-        if matches!(behavior.state, BehaviorState::Trade) {
+        // Everyone buys provisions, and a little more, from the host's stores.
+        let provisions =
+            (PROVISIONS * upkeep * prices + inventory.currency * SPENDING_RATE).min(inventory.currency);
+        inventory.currency -= provisions;
+        let stores = treasuries.convert(provisions, nation, host);
+        treasuries.treasury_mut(host).collect(stores);
+
+        // Traders sell their goods to the host but keep their working gear;
+        // with nothing spare, or a host whose credit has run dry, there is
+        // nothing to sell.
+        let lot = if behavior.state == BehaviorState::Trade { inventory.take_for_sale(TRADE_LOT) } else { Vec::new() };
+        if !lot.is_empty() {
+            let worth: f32 = lot.iter().map(|stack| stack.quantity as f32 * goods_value(&stack.item)).sum();
             let volatility: f32 = rng.gen_range(-2.0..2.0) * risk_factor;
-            let trade_gain =
-                base_trade_yield * trade_multiplier - upkeep_penalty(0.75, upkeep) + volatility;
-            inventory.currency =
-                (inventory.currency + trade_gain.max(-inventory.currency)).max(0.0);
+            let asking = ((worth * trade_multiplier * vigor + volatility) * prices).max(0.0);
+            let offered = treasuries.convert(asking, nation, host);
+            let paid = treasuries.treasury_mut(host).purchase(offered);
+            if paid > 0.0 {
+                let trade_gain = treasuries.convert(paid, host, nation);
+                let tax = trade_gain * TAX_RATE;
+                inventory.currency += trade_gain - tax;
+                levy(&mut treasuries, tax, host, nation);
+                memory.remember(Episode::Trade {
+                    tick: time.tick,
+                    biome,
+                    profit: trade_gain,
+                });
+            } else {
+                for stack in lot {
+                    inventory.add(stack.item, stack.quantity);
+                }
+            }
+        }
 
-            // NPC actions now contribute less directly, but still add to the economy
-            metrics.economy += trade_gain * 0.05;
+        // Gatherers bring in the local resources, as much as they can carry.
+        if matches!(behavior.state, BehaviorState::Gather)
+            && let Some(profile) = world_meta.biomes.get(&biome)
+            && !profile.resource_profile.is_empty()
+        {
+            let harvest = GATHER_YIELD
+                * resource_multiplier
+                * research.gather_multiplier(nation, &world_meta)
+                * vigor
+                + rng.gen_range(0.0..1.0);
+            let room = GATHER_CARRY_LIMIT.saturating_sub(inventory.total_quantity());
+            for _ in 0..(harvest as u32).min(room) {
+                let resource =
+                    profile.resource_profile[rng.gen_range(0..profile.resource_profile.len())];
+                inventory.add(ItemKind::Resource(resource.to_string()), 1);
+            }
         }
 
-        if matches!(behavior.state, BehaviorState::Gather) {
-            let gather_gain = base_gather_value * resource_multiplier
-                - upkeep_penalty(0.35, upkeep)
-                + rng.gen_range(0.0..2.0);
-            inventory.currency += gather_gain.max(0.0);
+        // Prayer and patrols are unpaid service to the nation.
+        match behavior.state {
+            BehaviorState::Pray => metrics.religion += 0.01,
+            BehaviorState::Patrol => metrics.military += 0.01,
+            _ => {}
+        }
+    }
 
-            metrics.economy += gather_gain * 0.03;
+    // The state pays its upkeep, services its debt and invests its surplus;
+    // the economy follows how well revenue covers the bills.
+    for (nation, metrics) in all_metrics.0.iter_mut() {
+        if metrics.is_destroyed {
+            continue;
         }
+        let treasury = treasuries.treasury_mut(*nation);
+        let revenue = treasury.revenue;
+        let prices = treasury.coinage.price_level;
+
+        // Known technologies trim every upkeep line.
+        let relief = 1.0 - research.upkeep_relief(*nation, &world_meta);
+        let upkeep = (metrics.military * 0.05
+            + metrics.science * 0.02
+            + metrics.culture * 0.01
+            + metrics.diplomacy * 0.02)
+            * relief
+            * COIN_PER_POINT
+            * prices;
+        let interest = treasury.debt() * DEBT_INTEREST;
+        // Once creditors stop lending, the mint strikes whatever the bills
+        // still need.
+        treasury.pay(upkeep + interest);
+
+        // The surplus is spent along the budget the nation's strategy
+        // settled on; the economy share stays in the coffers.
+        if let Some(strategy) = strategies.0.get(nation) {
+            let surplus = (treasury.balance - TREASURY_RESERVE).max(0.0);
+            let outlay = surplus * INVESTMENT_RATE * (1.0 - strategy.share(BudgetLine::Economy));
+            treasury.spend(outlay);
+            for line in BudgetLine::ALL {
+                if line != BudgetLine::Economy {
+                    *metrics.budget_line_mut(line) += outlay / COIN_PER_POINT / prices
+                        * INVESTMENT_EFFICIENCY
+                        * strategy.share(line);
+                }
+            }
+        }
+
+        let health = fiscal_health(revenue, upkeep, treasury.debt() / prices);
+        metrics.economy += (health - metrics.economy) * ECONOMY_DRIFT;
+
+        metrics.science *= 0.999;
+        metrics.culture *= 0.998;
+        metrics.diplomacy *= 0.999;
+        metrics.religion *= 0.9995;
     }
 
     // Finally, clamp all metrics to a 0-100 range
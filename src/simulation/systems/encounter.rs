@@ -0,0 +1,248 @@
+//! Skirmishes between co-located NPCs: raids, and hunts that turn on rivals.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    Attributes, AxialCoord, Behavior, BehaviorState, Biome, CombatOutcome, EventActor, Faction, HarmCause,
+    HexGrid, Identity, Injury, Inventory, ItemKind, Nation, NationTreasuries, Personality, Position, RegionalWeather,
+    RelationshipGraph, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Chance a hunting bandit turns on travellers instead of game.
+const RAID_CHANCE: f64 = 0.25;
+/// Chance, scaled by aggression, that any other hunter attacks a hostile outsider.
+const HOSTILE_HUNT_CHANCE: f64 = 0.3;
+/// Faction attitude at or below which outsiders count as hostile.
+const HOSTILITY_THRESHOLD: f32 = -5.0;
+/// Personal rivalry that makes an outsider fair game regardless of faction.
+const RIVALRY_THRESHOLD: f32 = 25.0;
+/// Too winded to start a fight below this stamina.
+const MIN_STAMINA: f32 = 10.0;
+const FIGHT_STAMINA_COST: f32 = 5.0;
+/// Share of the loser's purse the winner takes.
+const LOOT_SHARE: f32 = 0.3;
+/// Units taken from the loser's largest stack.
+const LOOT_ITEMS: u32 = 3;
+/// Relative margin under which neither side gives ground.
+const STANDOFF_MARGIN: f32 = 0.1;
+
+struct Fighter {
+    entity: Entity,
+    id: u64,
+    faction: Faction,
+    biome: Biome,
+    coord: AxialCoord,
+    state: BehaviorState,
+    aggressive: f32,
+    cautious: f32,
+    power: f32,
+}
+
+/// Tools and weapons count most; relics lend a little nerve.
+fn gear_bonus(inventory: &Inventory) -> f32 {
+    let mut equipment = 0;
+    let mut artifacts = 0;
+    for stack in &inventory.items {
+        match stack.item {
+            ItemKind::Equipment(_) => equipment += stack.quantity,
+            ItemKind::Artifact(_) => artifacts += stack.quantity,
+            ItemKind::Resource(_) => {}
+        }
+    }
+    (equipment as f32 * 6.0).min(30.0) + (artifacts as f32 * 3.0).min(9.0)
+}
+
+fn combat_power(attributes: &Attributes, personality: &Personality, inventory: &Inventory) -> f32 {
+    attributes.health * 0.5
+        + attributes.stamina * 0.3
+        + personality.aggressive * 15.0
+        + gear_bonus(inventory)
+}
+
+/// How likely this NPC is to start a fight this tick, if at all.
+fn initiative(fighter: &Fighter) -> f64 {
+    match fighter.state {
+        BehaviorState::Raid => 1.0,
+        BehaviorState::Hunt if fighter.faction == Faction::BanditClans => RAID_CHANCE,
+        BehaviorState::Hunt => HOSTILE_HUNT_CHANCE * fighter.aggressive as f64,
+        _ => 0.0,
+    }
+}
+
+/// Takes a share of the loser's purse, changed into the winner's coin, and a
+/// few units of their largest stack. The coin is reported in the loser's money.
+fn loot(
+    treasuries: &NationTreasuries,
+    (winner, winner_nation): (&mut Inventory, Nation),
+    (loser, loser_nation): (&mut Inventory, Nation),
+) -> (f32, Option<String>) {
+    let currency = loser.currency * LOOT_SHARE;
+    loser.currency -= currency;
+    winner.currency += treasuries.convert(currency, loser_nation, winner_nation);
+
+    let Some(stack) = loser.items.iter().max_by_key(|stack| stack.quantity).cloned() else {
+        return (currency, None);
+    };
+    let quantity = stack.quantity.min(LOOT_ITEMS);
+    if !loser.remove(stack.item.name(), quantity) {
+        return (currency, None);
+    }
+    winner.add(stack.item.clone(), quantity);
+    (currency, Some(format!("{} x{}", stack.item.name(), quantity)))
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn npc_combat_system(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &Identity,
+        &Position,
+        &Personality,
+        &Behavior,
+        &mut Attributes,
+        &mut Inventory,
+    )>,
+    graph: Res<RelationshipGraph>,
+    treasuries: Res<NationTreasuries>,
+    weather: Res<RegionalWeather>,
+    grid: Res<HexGrid>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(811).wrapping_add(43));
+
+    let mut fighters: Vec<Fighter> = query
+        .iter()
+        .map(
+            |(entity, identity, position, personality, behavior, attributes, inventory)| Fighter {
+                entity,
+                id: identity.id,
+                faction: identity.faction,
+                biome: position.biome,
+                coord: grid.coord_at(position.x, position.y),
+                state: behavior.state,
+                aggressive: personality.aggressive,
+                cautious: personality.cautious,
+                power: if attributes.stamina >= MIN_STAMINA {
+                    combat_power(attributes, personality, inventory)
+                } else {
+                    0.0
+                },
+            },
+        )
+        .collect();
+    fighters.sort_by_key(|fighter| fighter.id);
+
+    let mut by_biome: HashMap<Biome, Vec<&Fighter>> = HashMap::new();
+    for fighter in &fighters {
+        by_biome.entry(fighter.biome).or_default().push(fighter);
+    }
+
+    let mut engaged: HashSet<u64> = HashSet::new();
+    for attacker in &fighters {
+        if engaged.contains(&attacker.id) || attacker.power <= 0.0 {
+            continue;
+        }
+        let chance = initiative(attacker);
+        if chance <= 0.0 || !rng.gen_bool(chance.clamp(0.0, 1.0)) {
+            continue;
+        }
+
+        // Raiders take whoever is there; other hunters only turn on those they hate.
+        let indiscriminate = matches!(attacker.state, BehaviorState::Raid)
+            || attacker.faction == Faction::BanditClans;
+        let targets: Vec<(&Fighter, f32)> = by_biome[&attacker.biome]
+            .iter()
+            .filter(|target| target.faction != attacker.faction && !engaged.contains(&target.id))
+            .filter_map(|target| {
+                let attitude = world_meta.faction_attitude(attacker.faction, target.faction);
+                let grudge = graph.get(attacker.id, target.id).map_or(0.0, |r| r.rivalry);
+                let hostile = attitude <= HOSTILITY_THRESHOLD || grudge >= RIVALRY_THRESHOLD;
+                (indiscriminate || hostile)
+                    .then_some((*target, 1.0 + grudge / 10.0 + (-attitude).max(0.0) / 10.0))
+            })
+            .collect();
+        let Ok((defender, _)) = targets.choose_weighted(&mut rng, |(_, weight)| *weight) else {
+            continue;
+        };
+
+        // Rain, storms and sand blunt an attack more than a stand.
+        let attack = attacker.power * weather.weather(attacker.coord).assault_modifier() * rng.gen_range(0.75..1.25);
+        let defense = (defender.power + defender.cautious * 10.0) * rng.gen_range(0.75..1.25);
+        let margin = (attack - defense) / attack.max(defense).max(1.0);
+        let outcome = if margin.abs() < STANDOFF_MARGIN {
+            CombatOutcome::Standoff
+        } else if margin > 0.0 {
+            CombatOutcome::AttackerVictory
+        } else {
+            CombatOutcome::DefenderVictory
+        };
+
+        let (attacker_damage, defender_damage) = match outcome {
+            CombatOutcome::Standoff => (rng.gen_range(2.0..6.0), rng.gen_range(2.0..6.0)),
+            CombatOutcome::AttackerVictory => (rng.gen_range(1.0..5.0), rng.gen_range(8.0..20.0)),
+            CombatOutcome::DefenderVictory => (rng.gen_range(8.0..20.0), rng.gen_range(1.0..5.0)),
+        };
+
+        let Ok(
+            [
+                (_, a_identity, a_position, _, a_behavior, mut a_attributes, mut a_inventory),
+                (_, d_identity, d_position, _, d_behavior, mut d_attributes, mut d_inventory),
+            ],
+        ) = query.get_many_mut([attacker.entity, defender.entity])
+        else {
+            continue;
+        };
+
+        a_attributes.health -= attacker_damage;
+        d_attributes.health -= defender_damage;
+        a_attributes.stamina = (a_attributes.stamina - FIGHT_STAMINA_COST).max(0.0);
+        d_attributes.stamina = (d_attributes.stamina - FIGHT_STAMINA_COST).max(0.0);
+        for entity in [attacker.entity, defender.entity] {
+            commands.entity(entity).insert(Injury {
+                cause: HarmCause::Combat,
+            });
+        }
+
+        let (loot_currency, loot_items) = match outcome {
+            CombatOutcome::AttackerVictory => loot(
+                &treasuries,
+                (&mut a_inventory, a_identity.nation),
+                (&mut d_inventory, d_identity.nation),
+            ),
+            CombatOutcome::DefenderVictory => loot(
+                &treasuries,
+                (&mut d_inventory, d_identity.nation),
+                (&mut a_inventory, a_identity.nation),
+            ),
+            CombatOutcome::Standoff => (0.0, None),
+        };
+        // Both sides bleed, so a winner or either side of a standoff can die too.
+        let attacker_slain = a_attributes.health <= 0.0;
+        let defender_slain = d_attributes.health <= 0.0;
+
+        event_log.push(WorldEvent::combat(
+            time.tick,
+            epoch,
+            season,
+            EventActor::new(a_identity, a_position, a_behavior.state, &world_meta),
+            EventActor::new(d_identity, d_position, d_behavior.state, &world_meta),
+            outcome,
+            loot_currency,
+            loot_items,
+            attacker_slain,
+            defender_slain,
+        ));
+
+        engaged.insert(attacker.id);
+        engaged.insert(defender.id);
+    }
+}
@@ -6,7 +6,7 @@ use rand::{Rng, SeedableRng};
 
 use crate::simulation::{
     Attributes, Behavior, BehaviorState, EventActor, Inventory, Position, WorldEvent,
-    WorldEventLog, WorldMetadata, WorldTime, behavior_label, faction_label,
+    WorldEventLog, WorldMetadata, WorldTime,
 };
 
 pub fn event_generation_system(
@@ -74,19 +74,7 @@ pub fn event_generation_system(
             })
             .unwrap_or_else(|| "지역 수요 지표".to_string());
 
-        let actor = EventActor {
-            id: identity.id,
-            name: identity.name.clone(),
-            nation: identity.nation,
-            faction: identity.faction,
-            faction_label: faction_label(identity.faction).to_string(),
-            biome: position.biome,
-            biome_label: biome_profile
-                .map(|meta| meta.label.to_string())
-                .unwrap_or_else(|| format!("{:?}", position.biome)),
-            behavior_hint: behavior_state,
-            behavior_hint_label: behavior_label(behavior_state).to_string(),
-        };
+        let actor = EventActor::new(&identity, &position, behavior_state, &world_meta);
 
         let market_label = biome_profile
             .map(|meta| meta.label)
@@ -153,19 +141,7 @@ pub fn event_generation_system(
         }
         .to_string();
 
-        let actor = EventActor {
-            id: identity.id,
-            name: identity.name.clone(),
-            nation: identity.nation,
-            faction: identity.faction,
-            faction_label: faction_label(identity.faction).to_string(),
-            biome: position.biome,
-            biome_label: biome_profile
-                .map(|meta| meta.label.to_string())
-                .unwrap_or_else(|| format!("{:?}", position.biome)),
-            behavior_hint: behavior_state,
-            behavior_hint_label: behavior_label(behavior_state).to_string(),
-        };
+        let actor = EventActor::new(&identity, &position, behavior_state, &world_meta);
 
         event_log.push(WorldEvent::social(
             tick,
@@ -176,26 +152,4 @@ pub fn event_generation_system(
             cohesion_level,
         ));
     }
-
-    // Macro shock event (pulse each tick)
-    let stressors = &world_meta.economy.stressors;
-    let catalysts = &world_meta.economy.catalysts;
-    let circulation = &world_meta.economy.circulation_cycle;
-
-    if !(stressors.is_empty() || catalysts.is_empty() || circulation.is_empty()) {
-        let stressor = stressors[(tick as usize) % stressors.len()].to_string();
-        let catalyst =
-            catalysts[((tick as usize) + circulation.len()) % catalysts.len()].to_string();
-        let circulation_stage =
-            circulation[(tick as usize + catalysts.len()) % circulation.len()].to_string();
-
-        let impact = format!(
-            "{} 위기가 {}을(를) 압박하며 {} 단계가 진행 중입니다",
-            stressor, catalyst, circulation_stage
-        );
-
-        event_log.push(WorldEvent::macro_shock(
-            tick, epoch, season, stressor, catalyst, impact,
-        ));
-    }
-}
\ No newline at end of file
+}
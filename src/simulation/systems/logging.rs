@@ -5,8 +5,8 @@ use colored::{Color, Colorize};
 use tracing::info;
 
 use crate::simulation::{
-    Behavior, Identity, Position, Sentiment, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
-    behavior_color, behavior_label, faction_color, faction_label, sentiment_color, sentiment_label,
+    ActiveShocks, Behavior, Identity, Position, Sentiment, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+    behavior_color, behavior_label, casualties, faction_color, faction_label, sentiment_color, sentiment_label,
 };
 
 fn badge(label: &str, color: Color) -> String {
@@ -18,7 +18,28 @@ fn category_color(category: &str) -> Color {
         "무역" => Color::BrightCyan,
         "사회" => Color::BrightMagenta,
         "거시충격" => Color::BrightRed,
+        "충격 해소" => Color::BrightGreen,
         "전쟁" => Color::Red,
+        "선전포고" => Color::BrightRed,
+        "강화" => Color::BrightGreen,
+        "야전" => Color::Red,
+        "조약" => Color::BrightCyan,
+        "정책" => Color::BrightBlue,
+        "파기" => Color::Magenta,
+        "내전" => Color::BrightRed,
+        "부활" => Color::BrightYellow,
+        "정변" => Color::Yellow,
+        "멸망" => Color::Red,
+        "동화" => Color::BrightMagenta,
+        "문화 편입" => Color::Magenta,
+        "화폐 절하" => Color::Yellow,
+        "역병 유행" => Color::BrightRed,
+        "역병 종식" => Color::BrightGreen,
+        "기술" => Color::Cyan,
+        "제작" => Color::BrightYellow,
+        "사망" => Color::BrightBlack,
+        "인구" => Color::BrightGreen,
+        "전투" => Color::BrightRed,
         _ => Color::White,
     }
 }
@@ -46,7 +67,7 @@ fn format_event_line(event: &WorldEvent) -> String {
                 behavior_color(actor.behavior_hint),
             );
             let actor_name = actor
-                .name
+                .hangul_name
                 .color(faction_color(actor.faction))
                 .bold()
                 .to_string();
@@ -78,7 +99,7 @@ fn format_event_line(event: &WorldEvent) -> String {
                 behavior_color(convener.behavior_hint),
             );
             let convener_name = convener
-                .name
+                .hangul_name
                 .color(faction_color(convener.faction))
                 .bold()
                 .to_string();
@@ -102,44 +123,542 @@ fn format_event_line(event: &WorldEvent) -> String {
             )
         }
         crate::simulation::WorldEventKind::MacroShock {
-            stressor,
-            catalyst,
-            projected_impact,
+            shock,
+            nations,
+            biomes,
+            effects,
+            duration,
+            boon,
+            ended,
         } => {
-            let stress = stressor.color(Color::BrightRed).bold().to_string();
-            let catalyst = catalyst.color(Color::Yellow).to_string();
-            let impact = projected_impact.color(Color::White).to_string();
+            let shock_color = if *boon { Color::BrightGreen } else { Color::BrightRed };
+            let shock_label = shock.color(shock_color).bold().to_string();
+            let nation_badges = nations
+                .iter()
+                .map(|nation| badge(nation.name(), nation.logging_color()))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            if *ended {
+                format!(
+                    "{} {} {} {} {} {} {} {}",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    nation_badges,
+                    shock_label,
+                    "물러갔습니다".color(Color::White)
+                )
+            } else {
+                let reach = if biomes.is_empty() { "전역".to_string() } else { biomes.join(", ") };
+                format!(
+                    "{} {} {} {} {} {} {} 발생 | 지역: {} | {}틱 | 효과: {}",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    nation_badges,
+                    shock_label,
+                    reach,
+                    duration,
+                    effects.join(", ").color(Color::White)
+                )
+            }
+        }
+        crate::simulation::WorldEventKind::Warfare {
+            winner,
+            loser,
+            contested,
+            captured,
+            war_score,
+            ..
+        } => {
+            let winner_badge = badge(winner.name(), winner.logging_color());
+            let loser_badge = badge(loser.name(), loser.logging_color());
 
             format!(
-                "{} {} {} {} {} {} | 촉발: {} | 영향: {}",
+                "{} {} {} {} {} {}가 {}와의 전투에서 승리했습니다 | ({}, {}) {} | 전쟁 점수 {}",
                 category_badge,
                 sentiment_badge,
                 tick_badge,
                 epoch_badge,
                 season_badge,
-                stress,
-                catalyst,
-                impact
+                winner_badge,
+                loser_badge,
+                contested.q,
+                contested.r,
+                if *captured { "점령" } else { "방어" },
+                format!("{:+.0}", war_score).color(Color::BrightYellow)
             )
         }
-        crate::simulation::WorldEventKind::Warfare {
+        crate::simulation::WorldEventKind::WarDeclared {
+            aggressor,
+            defender,
+            casus_belli_label,
+            goal_label,
+            ..
+        } => {
+            let aggressor_badge = badge(aggressor.name(), aggressor.logging_color());
+            let defender_badge = badge(defender.name(), defender.logging_color());
+
+            format!(
+                "{} {} {} {} {} {}가 {}에 선전포고했습니다 | 명분: {} | 목표: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                aggressor_badge,
+                defender_badge,
+                casus_belli_label.color(Color::BrightRed),
+                goal_label.color(Color::BrightYellow)
+            )
+        }
+        crate::simulation::WorldEventKind::WarEnded {
+            aggressor,
+            defender,
+            victor,
+            terms_label,
+            duration,
+            ..
+        } => {
+            let aggressor_badge = badge(aggressor.name(), aggressor.logging_color());
+            let defender_badge = badge(defender.name(), defender.logging_color());
+            let victor = match victor {
+                Some(nation) => nation.name().color(nation.logging_color()).bold().to_string(),
+                None => "없음".color(Color::BrightBlack).to_string(),
+            };
+
+            format!(
+                "{} {} {} {} {} {}–{} 전쟁이 {}(으)로 끝났습니다 | 승자: {} | {}틱 지속",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                aggressor_badge,
+                defender_badge,
+                terms_label.color(Color::BrightGreen),
+                victor,
+                duration
+            )
+        }
+        crate::simulation::WorldEventKind::PolicyShift {
+            nation,
+            doctrine_label,
+            focus_label,
+            reason,
+            ..
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            format!(
+                "{} {} {} {} {} {}가 국정 중점을 {}(으)로 옮겼습니다 | 교리: {} | 사유: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                focus_label.color(Color::BrightBlue).bold(),
+                doctrine_label,
+                reason
+            )
+        }
+        crate::simulation::WorldEventKind::Treaty {
+            party,
+            counterparty,
+            treaty_label,
+            signed,
+            reason,
+            ..
+        } => {
+            let party_badge = badge(party.name(), party.logging_color());
+            let counterparty_badge = badge(counterparty.name(), counterparty.logging_color());
+
+            if *signed {
+                format!(
+                    "{} {} {} {} {} {}와 {}가 {}을 맺었습니다",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    party_badge,
+                    counterparty_badge,
+                    treaty_label.color(Color::BrightCyan)
+                )
+            } else {
+                format!(
+                    "{} {} {} {} {} {}가 {}와의 {}을 파기했습니다 | 사유: {}",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    party_badge,
+                    counterparty_badge,
+                    treaty_label.color(Color::Magenta),
+                    reason.as_deref().unwrap_or("불명")
+                )
+            }
+        }
+        crate::simulation::WorldEventKind::ArmyClash {
             winner,
             loser,
-            territory_change,
+            position,
+            winner_losses,
+            loser_losses,
+            routed,
         } => {
             let winner_badge = badge(winner.name(), winner.logging_color());
             let loser_badge = badge(loser.name(), loser.logging_color());
+            let result = if *routed {
+                "궤멸시켰습니다".color(Color::BrightRed).bold()
+            } else {
+                "격퇴했습니다".color(Color::BrightYellow)
+            };
 
             format!(
-                "{} {} {} {} {} {}가 {}와의 전쟁에서 승리하여 영토 {:.2}를 획득했습니다.",
+                "{} {} {} {} {} {} 군이 ({}, {})에서 {} 군을 {} | 손실 {:.0} 대 {:.0}",
                 category_badge,
                 sentiment_badge,
                 tick_badge,
                 epoch_badge,
                 season_badge,
                 winner_badge,
+                position.q,
+                position.r,
                 loser_badge,
-                territory_change
+                result,
+                winner_losses,
+                loser_losses
+            )
+        }
+        crate::simulation::WorldEventKind::Rebellion {
+            nation,
+            rebels,
+            reborn,
+            seat,
+            hexes,
+            defectors,
+            ..
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+            let rebels_badge = badge(rebels.name(), rebels.logging_color());
+            let outcome = if *reborn {
+                "다시 일어섰습니다".color(Color::BrightYellow).bold()
+            } else {
+                "독립을 선포했습니다".color(Color::BrightRed).bold()
+            };
+
+            format!(
+                "{} {} {} {} {} {}에서 반란이 일어나 {}가 {} | 거점 ({}, {}) · {}개 지역 · 이탈 주민 {}명",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                rebels_badge,
+                outcome,
+                seat.q,
+                seat.r,
+                hexes,
+                defectors
+            )
+        }
+        crate::simulation::WorldEventKind::Succession {
+            nation,
+            stability,
+            reason,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            format!(
+                "{} {} {} {} {} {}의 정권이 무너지고 새 지도부가 들어섰습니다 | 안정도 {} | 사유: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                format!("{:.0}", stability).color(Color::Yellow),
+                reason
+            )
+        }
+        crate::simulation::WorldEventKind::Collapse {
+            nation,
+            conqueror,
+            absorbed,
+            migrated,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+            let conqueror_badge = match conqueror {
+                Some(conqueror) => badge(conqueror.name(), conqueror.logging_color()),
+                None => "없음".to_string(),
+            };
+
+            format!(
+                "{} {} {} {} {} {}가 {} | 정복자: {} | 흡수 {}명 · 이주 {}명",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                "멸망했습니다".color(Color::Red).bold(),
+                conqueror_badge,
+                absorbed,
+                migrated
+            )
+        }
+        crate::simulation::WorldEventKind::CulturalShift {
+            nation,
+            from,
+            hexes,
+            defected,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+            let from_badge = badge(from.name(), from.logging_color());
+
+            if *defected {
+                format!(
+                    "{} {} {} {} {} {}의 헥스 {}곳이 {} {}",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    from_badge,
+                    hexes,
+                    nation_badge,
+                    "편으로 평화롭게 넘어갔습니다".color(Color::Magenta).bold()
+                )
+            } else {
+                format!(
+                    "{} {} {} {} {} {} 문화가 {} 문화의 헥스 {}곳을 {}",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    nation_badge,
+                    from_badge,
+                    hexes,
+                    "동화했습니다".color(Color::BrightMagenta).bold()
+                )
+            }
+        }
+        crate::simulation::WorldEventKind::Debasement {
+            nation,
+            silver,
+            minted,
+            price_level,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            format!(
+                "{} {} {} {} {} {}가 {} | 은 함량 {:.0}% · 주조 {:.0}닢 · 물가 {:.2}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                "화폐를 절하했습니다".color(Color::Yellow).bold(),
+                silver * 100.0,
+                minted,
+                price_level
+            )
+        }
+        crate::simulation::WorldEventKind::Epidemic {
+            nation,
+            infected,
+            population,
+            cases,
+            deaths,
+            ended,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            if *ended {
+                format!(
+                    "{} {} {} {} {} {}의 {} | 누적 감염 {}명 · 최다 {}명 · 사망 {}명",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    nation_badge,
+                    "역병이 잦아들었습니다".color(Color::BrightGreen).bold(),
+                    cases,
+                    infected,
+                    deaths
+                )
+            } else {
+                format!(
+                    "{} {} {} {} {} {}에 {} | 환자 {}명 / 인구 {}명",
+                    category_badge,
+                    sentiment_badge,
+                    tick_badge,
+                    epoch_badge,
+                    season_badge,
+                    nation_badge,
+                    "역병이 번졌습니다".color(Color::BrightRed).bold(),
+                    infected,
+                    population
+                )
+            }
+        }
+        crate::simulation::WorldEventKind::Breakthrough {
+            nation,
+            tech,
+            line_label,
+            effects,
+            ..
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            format!(
+                "{} {} {} {} {} {}가 {}을(를) 완성했습니다 | 분야: {} | 효과: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                tech.color(Color::Cyan).bold(),
+                line_label,
+                effects.join(", ")
+            )
+        }
+        crate::simulation::WorldEventKind::Crafting {
+            artisan,
+            recipe,
+            output,
+            quantity,
+        } => {
+            let faction_badge = badge(&artisan.faction_label, faction_color(artisan.faction));
+            let artisan_name = artisan
+                .hangul_name
+                .color(faction_color(artisan.faction))
+                .bold()
+                .to_string();
+            let recipe = recipe.color(Color::BrightYellow).to_string();
+            let output = output.color(Color::BrightCyan).to_string();
+
+            format!(
+                "{} {} {} {} {} {} {} 님이 {} 공정으로 {} {}개를 완성했습니다",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                faction_badge,
+                artisan_name,
+                recipe,
+                output,
+                quantity
+            )
+        }
+        crate::simulation::WorldEventKind::Death {
+            npc,
+            cause_label,
+            heir,
+            ..
+        } => {
+            let faction_badge = badge(&npc.faction_label, faction_color(npc.faction));
+            let npc_name = npc.hangul_name.color(faction_color(npc.faction)).bold().to_string();
+            let cause = cause_label.color(Color::BrightRed).to_string();
+            let heir = heir
+                .as_deref()
+                .unwrap_or("소실")
+                .color(Color::BrightBlack)
+                .to_string();
+
+            format!(
+                "{} {} {} {} {} {} {} 님이 {}(으)로 사망했습니다 | 유산: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                faction_badge,
+                npc_name,
+                cause,
+                heir
+            )
+        }
+        crate::simulation::WorldEventKind::Demographics {
+            nation,
+            births,
+            immigrants,
+            emigrants,
+            population,
+        } => {
+            let nation_badge = badge(nation.name(), nation.logging_color());
+
+            format!(
+                "{} {} {} {} {} {} 출생 {} · 이주 유입 {} · 이주 유출 {} | 총 {}명",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                nation_badge,
+                births.to_string().color(Color::BrightGreen),
+                immigrants.to_string().color(Color::BrightCyan),
+                emigrants.to_string().color(Color::BrightRed),
+                population
+            )
+        }
+        crate::simulation::WorldEventKind::Combat {
+            attacker,
+            defender,
+            outcome_label,
+            loot_currency,
+            loot_items,
+            attacker_slain,
+            defender_slain,
+            ..
+        } => {
+            let attacker_name = attacker
+                .hangul_name
+                .color(faction_color(attacker.faction))
+                .bold()
+                .to_string();
+            let defender_name = defender
+                .hangul_name
+                .color(faction_color(defender.faction))
+                .bold()
+                .to_string();
+            let outcome = if *attacker_slain || *defender_slain {
+                format!("{}{}", outcome_label, casualties(attacker, defender, *attacker_slain, *defender_slain))
+                    .color(Color::BrightRed)
+                    .to_string()
+            } else {
+                outcome_label.color(Color::BrightYellow).to_string()
+            };
+            let spoils = match loot_items {
+                Some(items) => format!("{:.1} · {}", loot_currency, items),
+                None => format!("{:.1}", loot_currency),
+            };
+
+            format!(
+                "{} {} {} {} {} {} 님이 {} 님을 습격했습니다 | 결과: {} | 전리품: {}",
+                category_badge,
+                sentiment_badge,
+                tick_badge,
+                epoch_badge,
+                season_badge,
+                attacker_name,
+                defender_name,
+                outcome,
+                spoils
             )
         }
     }
@@ -215,23 +734,16 @@ pub fn logging_system(
     time: Res<WorldTime>,
     world_meta: Res<WorldMetadata>,
     events: Res<WorldEventLog>,
+    shocks: Res<ActiveShocks>,
     query: Query<(&Identity, &Behavior, &Position)>,
 ) {
     let (epoch, season) = world_meta.epoch_for_tick(time.tick);
-    let catalyst_index = (time.tick as usize) % world_meta.economy.catalysts.len();
-    let catalyst = world_meta.economy.catalysts[catalyst_index];
     let circulation_stage = world_meta
         .economy
         .circulation_cycle
-        .get(catalyst_index % world_meta.economy.circulation_cycle.len())
+        .get((time.tick as usize) % world_meta.economy.circulation_cycle.len().max(1))
         .copied()
         .unwrap_or("균형 거래");
-    let stressor = world_meta
-        .economy
-        .stressors
-        .get(catalyst_index % world_meta.economy.stressors.len())
-        .copied()
-        .unwrap_or("안정 국면");
 
     let header_line = format!(
         "{} {} {} {} {} {}",
@@ -243,12 +755,20 @@ pub fn logging_system(
         badge(circulation_stage, Color::BrightGreen),
     );
 
-    let stress_line = format!(
-        "{} {} {}",
-        badge("촉매", Color::Yellow),
-        badge(catalyst, Color::Yellow),
-        badge(stressor, Color::BrightRed),
-    );
+    let stress_line = if shocks.0.is_empty() {
+        format!("{} {}", badge("충격", Color::Yellow), badge("안정 국면", Color::BrightGreen))
+    } else {
+        let running = shocks
+            .0
+            .iter()
+            .map(|active| {
+                let nations = active.nations.iter().map(|nation| nation.name()).collect::<Vec<_>>().join("·");
+                badge(&format!("{} {}", active.label, nations), Color::BrightRed)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("{} {}", badge("충격", Color::Yellow), running)
+    };
 
     let mut lines = vec![header_line, stress_line];
 
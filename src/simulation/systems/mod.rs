@@ -1,13 +1,53 @@
 pub mod ai;
+pub mod analytics;
+pub mod army;
+pub mod crafting;
+pub mod currency;
+pub mod culture;
+pub mod diplomacy;
+pub mod disease;
 pub mod economy;
+pub mod encounter;
 pub mod events;
 pub mod logging;
+pub mod movement;
+pub mod population;
+pub mod rebellion;
+pub mod religion;
+pub mod reputation;
+pub mod research;
+pub mod shocks;
+pub mod social;
+pub mod strategy;
 pub mod territory;
+pub mod utility;
+pub mod vitals;
 pub mod warfare;
+pub mod weather;
 
 pub use ai::*;
+pub use analytics::*;
+pub use army::*;
+pub use crafting::*;
+pub use currency::*;
+pub use culture::*;
+pub use diplomacy::*;
+pub use disease::*;
 pub use economy::*;
+pub use encounter::*;
 pub use events::*;
 pub use logging::*;
+pub use movement::*;
+pub use population::*;
+pub use rebellion::*;
+pub use religion::*;
+pub use reputation::*;
+pub use research::*;
+pub use shocks::*;
+pub use social::*;
+pub use strategy::*;
 pub use territory::*;
+pub use utility::*;
+pub use vitals::*;
 pub use warfare::*;
+pub use weather::*;
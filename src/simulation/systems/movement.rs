@@ -1,17 +1,107 @@
-//! Movement and combat placeholder system.
+//! Travel between biomes, steered by faction strongholds and NPC memory.
 
 use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
-use crate::simulation::{Behavior, BehaviorState, Position};
+use crate::simulation::{
+    Behavior, BehaviorState, Biome, Episode, HexGrid, Identity, Memory, Position, RegionalWeather, WorldMetadata,
+    WorldTime,
+};
 
-pub fn movement_and_combat_system(mut query: Query<(&Behavior, &mut Position)>) {
-    for (behavior, mut position) in &mut query {
-        if matches!(
-            behavior.state,
-            BehaviorState::Explore | BehaviorState::Gather | BehaviorState::Hunt
-        ) {
-            position.x += 1.0;
-            position.y += 1.0;
+/// Explorers wander; this is the chance they set out for another biome each tick.
+const WANDER_CHANCE: f64 = 0.3;
+/// Traders who remember a better market than the current one head back to it.
+const RETURN_CHANCE: f64 = 0.2;
+/// Migrants are on the road; most ticks carry them to a new biome.
+const MIGRATE_CHANCE: f64 = 0.6;
+
+fn destination_weight(
+    biome: Biome,
+    identity: &Identity,
+    memory: &Memory,
+    world_meta: &WorldMetadata,
+) -> f32 {
+    let mut weight = 1.0;
+
+    if world_meta
+        .faction_profile(identity.faction)
+        .is_some_and(|meta| meta.strongholds.contains(&biome))
+    {
+        weight *= 1.5;
+    }
+
+    // Places where the NPC was ambushed are avoided; profitable ones are revisited.
+    weight *= 0.4_f32.powi(memory.ambushes_in(biome) as i32);
+    if let Some(profit) = memory.trade_profit_in(biome) {
+        weight *= (1.0 + profit / 10.0).clamp(0.5, 2.0);
+    }
+
+    weight
+}
+
+fn choose_destination(
+    current: Biome,
+    identity: &Identity,
+    memory: &Memory,
+    world_meta: &WorldMetadata,
+    rng: &mut SmallRng,
+) -> Option<Biome> {
+    let options: Vec<(Biome, f32)> = Biome::ALL
+        .iter()
+        .filter(|biome| **biome != current)
+        .map(|biome| (*biome, destination_weight(*biome, identity, memory, world_meta)))
+        .collect();
+    options
+        .choose_weighted(rng, |(_, weight)| *weight)
+        .ok()
+        .map(|(biome, _)| *biome)
+}
+
+pub fn travel_system(
+    mut query: Query<(&Identity, &Behavior, &mut Position, &mut Memory)>,
+    weather: Res<RegionalWeather>,
+    grid: Res<HexGrid>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+) {
+    for (identity, behavior, mut position, mut memory) in &mut query {
+        let mut rng = SmallRng::seed_from_u64(
+            time.tick
+                .wrapping_mul(149)
+                .wrapping_add(identity.id * 17)
+                .wrapping_mul(61),
+        );
+
+        // Foul weather keeps travellers waiting for the roads to clear.
+        let mobility = weather.weather(grid.coord_at(position.x, position.y)).mobility() as f64;
+        let destination = match behavior.state {
+            BehaviorState::Explore if rng.gen_bool(WANDER_CHANCE * mobility) => {
+                choose_destination(position.biome, identity, &memory, &world_meta, &mut rng)
+            }
+            BehaviorState::Migrate if rng.gen_bool(MIGRATE_CHANCE * mobility) => {
+                choose_destination(position.biome, identity, &memory, &world_meta, &mut rng)
+            }
+            BehaviorState::Trade if rng.gen_bool(RETURN_CHANCE * mobility) => {
+                let here = memory.trade_profit_in(position.biome).unwrap_or(0.0);
+                Biome::ALL
+                    .iter()
+                    .filter(|biome| **biome != position.biome)
+                    .filter_map(|biome| memory.trade_profit_in(*biome).map(|profit| (*biome, profit)))
+                    .filter(|(biome, profit)| *profit > here && memory.ambushes_in(*biome) == 0)
+                    .max_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(biome, _)| biome)
+            }
+            _ => None,
+        };
+
+        if let Some(biome) = destination {
+            *position = world_meta.anchor_position(biome);
+            memory.remember(Episode::Visited {
+                tick: time.tick,
+                biome,
+            });
         }
     }
 }
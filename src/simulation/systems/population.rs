@@ -0,0 +1,223 @@
+//! Procedural NPC generation plus births, immigration and emigration.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    AllNationMetrics, Attributes, Behavior, BehaviorState, Biome, Faction, Identity, Inventory,
+    Memory, NameRegistry, Nation, NationMetrics, Personality, PopulationLedger, Position,
+    Reputation, WorldEvent, WorldEventLog, WorldMetadata, WorldTime, weighted_choice,
+};
+
+/// Share of a parent's savings a newborn starts out with.
+const BIRTH_PORTION: f32 = 0.3;
+
+#[derive(Bundle)]
+pub struct NpcBundle {
+    pub identity: Identity,
+    pub position: Position,
+    pub inventory: Inventory,
+    pub attributes: Attributes,
+    pub personality: Personality,
+    pub behavior: Behavior,
+    pub reputation: Reputation,
+    pub memory: Memory,
+}
+
+pub fn draw_nation(rng: &mut SmallRng, world_meta: &WorldMetadata) -> Nation {
+    weighted_choice(&world_meta.population.nation_shares, rng).unwrap_or(Nation::Tera)
+}
+
+pub fn draw_faction(rng: &mut SmallRng, world_meta: &WorldMetadata) -> Faction {
+    let mut shares: Vec<(Faction, f32)> = world_meta
+        .factions
+        .iter()
+        .map(|(faction, meta)| (*faction, meta.population_share))
+        .collect();
+    // HashMap order is not stable across runs; keep draws reproducible.
+    shares.sort_by_key(|(faction, _)| *faction as u8);
+    weighted_choice(&shares, rng).unwrap_or(Faction::Neutral)
+}
+
+/// Rolls a fresh NPC of the given nation. The faction is drawn from the
+/// configured shares unless one is supplied (e.g. inherited from a parent).
+pub fn generate_npc(
+    rng: &mut SmallRng,
+    names: &mut NameRegistry,
+    id: u64,
+    nation: Nation,
+    faction: Option<Faction>,
+    world_meta: &WorldMetadata,
+) -> NpcBundle {
+    let faction = faction.unwrap_or_else(|| draw_faction(rng, world_meta));
+    let profile = world_meta.faction_profile(faction);
+
+    let name = names.generate(nation, faction, world_meta);
+
+    let biome = profile
+        .filter(|meta| !meta.strongholds.is_empty())
+        .map(|meta| meta.strongholds[rng.gen_range(0..meta.strongholds.len())])
+        .unwrap_or(Biome::Market);
+
+    let spread = world_meta.population.temperament_spread.max(f32::EPSILON);
+    let mut jitter = |base: f32| (base + rng.gen_range(-spread..spread)).clamp(0.0, 1.0);
+    let personality = match profile {
+        Some(meta) => Personality {
+            aggressive: jitter(meta.temperament.aggressive),
+            cautious: jitter(meta.temperament.cautious),
+            social: jitter(meta.temperament.social),
+            curious: jitter(meta.temperament.curious),
+        },
+        None => Personality {
+            aggressive: jitter(0.3),
+            cautious: jitter(0.4),
+            social: jitter(0.5),
+            curious: jitter(0.5),
+        },
+    };
+
+    NpcBundle {
+        identity: Identity {
+            id,
+            name: name.roman,
+            hangul_name: name.hangul,
+            faction,
+            nation,
+        },
+        position: world_meta.anchor_position(biome),
+        inventory: Inventory {
+            items: vec![],
+            currency: rng.gen_range(20.0..80.0),
+        },
+        attributes: Attributes {
+            health: rng.gen_range(80.0..100.0),
+            stamina: rng.gen_range(70.0..100.0),
+            wealth: rng.gen_range(30.0..120.0),
+            fame: rng.gen_range(0.0..20.0),
+        },
+        personality,
+        behavior: Behavior {
+            state: BehaviorState::Idle,
+        },
+        reputation: Reputation::default(),
+        memory: Memory::default(),
+    }
+}
+
+/// How many NPCs a nation's economy, culture and land can support.
+fn carrying_capacity(metrics: &NationMetrics) -> f32 {
+    15.0 + metrics.economy * 0.3 + metrics.culture * 0.2 + metrics.territory * 0.4
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn population_system(
+    mut commands: Commands,
+    mut npcs: Query<(Entity, &Identity, &mut Inventory)>,
+    all_metrics: Res<AllNationMetrics>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut ledger: ResMut<PopulationLedger>,
+    mut names: ResMut<NameRegistry>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let rates = &world_meta.population;
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(389).wrapping_add(23));
+
+    let mut residents: HashMap<Nation, Vec<(Entity, Faction)>> = HashMap::new();
+    for (entity, identity, _) in &npcs {
+        residents
+            .entry(identity.nation)
+            .or_default()
+            .push((entity, identity.faction));
+    }
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+
+    for nation in nations {
+        let metrics = &all_metrics.0[&nation];
+        if metrics.is_destroyed {
+            continue;
+        }
+
+        let locals = residents.get(&nation).map(Vec::as_slice).unwrap_or(&[]);
+        let population = locals.len();
+        let occupancy = population as f32 / carrying_capacity(metrics);
+        let (mut births, mut immigrants, mut emigrants) = (0u32, 0u32, 0u32);
+
+        if occupancy < 1.0 {
+            let room = 1.0 - occupancy;
+
+            // Prosperity drives births; newborns follow a parent's faction
+            // and start out with a share of the parent's savings.
+            let birth_chance = rates.birth_rate * (metrics.economy / 50.0) * room;
+            if !locals.is_empty() && rng.gen_bool(birth_chance.clamp(0.0, 1.0) as f64) {
+                let (parent, parent_faction) = locals[rng.gen_range(0..locals.len())];
+                let id = ledger.allocate_id();
+                let mut newborn = generate_npc(
+                    &mut rng,
+                    &mut names,
+                    id,
+                    nation,
+                    Some(parent_faction),
+                    &world_meta,
+                );
+                newborn.inventory.currency = match npcs.get_mut(parent) {
+                    Ok((_, _, mut purse)) => {
+                        let portion = purse.currency * BIRTH_PORTION;
+                        purse.currency -= portion;
+                        portion
+                    }
+                    Err(_) => 0.0,
+                };
+                commands.spawn(newborn);
+                births += 1;
+            }
+
+            // Cultural pull attracts newcomers from beyond TERA.
+            let immigration_chance = rates.immigration_rate * (metrics.culture / 30.0) * room;
+            if rng.gen_bool(immigration_chance.clamp(0.0, 1.0) as f64) {
+                let id = ledger.allocate_id();
+                let immigrant = generate_npc(&mut rng, &mut names, id, nation, None, &world_meta);
+                ledger.coin_arrived += immigrant.inventory.currency;
+                commands.spawn(immigrant);
+                immigrants += 1;
+            }
+        } else if !locals.is_empty() {
+            // Overcrowded, struggling nations lose people abroad.
+            let hardship = (1.0 - metrics.economy / 40.0).max(0.0);
+            let emigration_chance = rates.emigration_rate * ((occupancy - 1.0) * 2.0 + hardship);
+            if rng.gen_bool(emigration_chance.clamp(0.0, 1.0) as f64) {
+                let (leaver, _) = locals[rng.gen_range(0..locals.len())];
+                if let Ok((_, _, purse)) = npcs.get(leaver) {
+                    ledger.coin_departed += purse.currency;
+                }
+                commands.entity(leaver).despawn();
+                emigrants += 1;
+            }
+        }
+
+        if births + immigrants + emigrants == 0 {
+            continue;
+        }
+
+        ledger.births += births as u64;
+        ledger.immigrants += immigrants as u64;
+        ledger.emigrants += emigrants as u64;
+
+        event_log.push(WorldEvent::demographics(
+            time.tick,
+            epoch,
+            season,
+            nation,
+            births,
+            immigrants,
+            emigrants,
+            (population + (births + immigrants) as usize).saturating_sub(emigrants as usize) as u32,
+        ));
+    }
+}
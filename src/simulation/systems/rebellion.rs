@@ -0,0 +1,470 @@
+//! The life cycle of nations: stability, coups, civil wars that carve a new
+//! polity out of an old one, fallen nations rising again under rebel banners,
+//! and the scattering of a fallen nation's people.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use super::culture::foreign_shares;
+use super::warfare::war_goal;
+use crate::simulation::{
+    AllNationMetrics, Army, ArmyOrder, ArmyRoster, AxialCoord, CasusBelli, DiplomacyMatrix, Heritage,
+    Hex, HexGrid, Identity, Inventory, Nation, NationMetrics, NationRelation, NationResearch, NationTreasuries,
+    PolityLedger, ResearchState, War, WarLedger, WorldEvent, WorldEventKind, WorldEventLog,
+    WorldMetadata, WorldTime, weighted_choice,
+};
+
+/// Stability closes this share of the gap to its target each tick.
+const STABILITY_DRIFT: f32 = 0.05;
+/// Territory share a nation is founded with; holding less unsettles it.
+const HOMELAND_SHARE: f32 = 100.0 / 3.0;
+/// Below this stability coups and uprisings become possible.
+const UNREST_THRESHOLD: f32 = 25.0;
+/// Chance per tick of an upheaval at zero stability, easing toward the threshold.
+const UPHEAVAL_CHANCE: f32 = 0.03;
+/// Ticks a nation is spared further upheaval after one.
+const UPHEAVAL_COOLDOWN: u64 = 150;
+/// A nation must hold this many hexes before rebels can carve a polity out of it.
+const MIN_PARTITION_HEXES: usize = 6;
+/// Most of a nation's land a rebellion can take with it.
+const MAX_REBEL_SHARE: f32 = 0.4;
+/// Soldiers the rebels muster per hex they rise in.
+const REBEL_LEVY_PER_HEX: f32 = 40.0;
+/// Stability a new regime starts from after a coup.
+const STABILITY_AFTER_COUP: f32 = 45.0;
+/// Share of a fallen nation's people who stay on under the conqueror.
+const ABSORB_SHARE: f64 = 0.6;
+
+/// The stability a nation's circumstances can sustain, and the heaviest drag on it.
+fn stability_target(metrics: &NationMetrics, exhaustion: f32, tension: f32, foreign: f32) -> (f32, &'static str) {
+    let drags = [
+        (exhaustion * 0.3, "전쟁 피로"),
+        ((tension - 10.0).max(0.0) * 0.4, "종교 갈등"),
+        (foreign * 0.3, "이민족 불만"),
+        ((50.0 - metrics.economy).max(0.0) * 0.3, "경제 파탄"),
+        ((30.0 - metrics.culture).max(0.0) * 0.25, "문화 쇠퇴"),
+        ((HOMELAND_SHARE - metrics.territory).max(0.0) * 0.6, "영토 상실"),
+    ];
+    let target = 50.0
+        + (metrics.economy - 50.0) * 0.3
+        + (metrics.culture - 30.0) * 0.25
+        + (metrics.religion - 25.0) * 0.1
+        - exhaustion * 0.3
+        - (tension - 10.0).max(0.0) * 0.4
+        - foreign * 0.3
+        - (HOMELAND_SHARE - metrics.territory).max(0.0) * 0.6;
+    let reason = drags
+        .into_iter()
+        .filter(|(drag, _)| *drag > 0.0)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or("민심 이반", |(_, reason)| reason);
+    (target.clamp(0.0, 100.0), reason)
+}
+
+/// How worn down `nation` is by every war it is fighting.
+fn war_exhaustion(wars: &Query<&War>, nation: Nation) -> f32 {
+    wars.iter()
+        .filter(|war| war.involves(nation))
+        .map(|war| if war.aggressor == nation { war.aggressor_exhaustion } else { war.defender_exhaustion })
+        .sum::<f32>()
+        .min(100.0)
+}
+
+/// Eases each nation's stability toward what its prosperity, culture, faith,
+/// land, foreign subjects and wars can sustain; defeats earlier this tick shake it at once.
+pub fn stability_system(
+    mut all_metrics: ResMut<AllNationMetrics>,
+    diplomacy: Res<DiplomacyMatrix>,
+    hexes: Query<(&Hex, &Heritage)>,
+    wars: Query<&War>,
+    time: Res<WorldTime>,
+    event_log: Res<WorldEventLog>,
+) {
+    let mut shocks: HashMap<Nation, f32> = HashMap::new();
+    for event in event_log.events_at(time.tick) {
+        match &event.kind {
+            WorldEventKind::Warfare { winner, loser, captured, .. } => {
+                *shocks.entry(*loser).or_insert(0.0) -= if *captured { 1.5 } else { 0.5 };
+                *shocks.entry(*winner).or_insert(0.0) += 0.3;
+            }
+            WorldEventKind::ArmyClash { loser, routed: true, .. } => {
+                *shocks.entry(*loser).or_insert(0.0) -= 2.0;
+            }
+            WorldEventKind::WarEnded { aggressor, defender, victor: Some(victor), .. } => {
+                let loser = if victor == aggressor { *defender } else { *aggressor };
+                *shocks.entry(loser).or_insert(0.0) -= 10.0;
+                *shocks.entry(*victor).or_insert(0.0) += 5.0;
+            }
+            _ => {}
+        }
+    }
+
+    let foreign = foreign_shares(hexes.iter());
+    for (nation, metrics) in all_metrics.0.iter_mut() {
+        if metrics.is_destroyed {
+            continue;
+        }
+        let (target, _) = stability_target(
+            metrics,
+            war_exhaustion(&wars, *nation),
+            diplomacy.religious_tension(*nation),
+            foreign.get(nation).copied().unwrap_or(0.0),
+        );
+        metrics.stability += (target - metrics.stability) * STABILITY_DRIFT;
+        metrics.stability = (metrics.stability + shocks.get(nation).copied().unwrap_or(0.0)).clamp(0.0, 100.0);
+    }
+}
+
+/// Works out which polity rebels in `nation` rise under, where, and with
+/// which land. A fallen nation whose old capital `nation` holds comes back
+/// there; otherwise the next polity yet to rise takes the hex farthest from
+/// the capital. The rebels take the contiguous land nearer their seat than
+/// the capital, up to `MAX_REBEL_SHARE` of the nation.
+fn partition(
+    nation: Nation,
+    owners: &HashMap<AxialCoord, Nation>,
+    grid: &HexGrid,
+    all_metrics: &AllNationMetrics,
+    belligerents: &HashSet<Nation>,
+) -> Option<(Nation, AxialCoord, Vec<AxialCoord>)> {
+    let capital = *grid.capitals.get(&nation)?;
+    let mut held: Vec<AxialCoord> =
+        owners.iter().filter(|(_, owner)| **owner == nation).map(|(coord, _)| *coord).collect();
+    held.sort_by_key(|coord| (coord.q, coord.r));
+    if held.len() < MIN_PARTITION_HEXES {
+        return None;
+    }
+
+    let holds_seat =
+        |candidate: &Nation| grid.capitals.get(candidate).is_some_and(|seat| owners.get(seat) == Some(&nation));
+    let mut dormant: Vec<Nation> = Nation::ALL
+        .into_iter()
+        .filter(|candidate| all_metrics.0.get(candidate).is_none_or(|metrics| metrics.is_destroyed))
+        .filter(|candidate| !belligerents.contains(candidate))
+        .collect();
+    dormant.sort_by_key(|candidate| !holds_seat(candidate));
+    let rebels = *dormant.first()?;
+
+    let seat = if holds_seat(&rebels) {
+        grid.capitals[&rebels]
+    } else {
+        *held.iter().max_by_key(|coord| (capital.distance(coord), -coord.q, -coord.r))?
+    };
+
+    let limit = ((held.len() as f32 * MAX_REBEL_SHARE) as usize).max(1);
+    let mut region = vec![seat];
+    let mut seen = HashSet::from([seat]);
+    let mut frontier = VecDeque::from([seat]);
+    while let Some(coord) = frontier.pop_front() {
+        for neighbor in coord.neighbors() {
+            if region.len() >= limit {
+                break;
+            }
+            if owners.get(&neighbor) == Some(&nation)
+                && neighbor != capital
+                && seat.distance(&neighbor) < capital.distance(&neighbor)
+                && seen.insert(neighbor)
+            {
+                region.push(neighbor);
+                frontier.push_back(neighbor);
+            }
+        }
+    }
+    Some((rebels, seat, region))
+}
+
+/// Unstable nations suffer upheaval: where there is land to take, rebels
+/// break away and fight for it; otherwise the regime falls to a coup.
+#[allow(clippy::too_many_arguments)]
+pub fn rebellion_system(
+    mut commands: Commands,
+    mut all_metrics: ResMut<AllNationMetrics>,
+    mut grid: ResMut<HexGrid>,
+    mut polities: ResMut<PolityLedger>,
+    mut war_ledger: ResMut<WarLedger>,
+    mut roster: ResMut<ArmyRoster>,
+    mut diplomacy: ResMut<DiplomacyMatrix>,
+    mut research: ResMut<NationResearch>,
+    mut treasuries: ResMut<NationTreasuries>,
+    mut hexes: Query<(&mut Hex, &AxialCoord, &Heritage)>,
+    mut armies: Query<&mut Army>,
+    mut npcs: Query<(&mut Identity, &mut Inventory)>,
+    wars: Query<&War>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(557).wrapping_add(41));
+
+    let mut owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord, _)| (*coord, hex.owner)).collect();
+    let foreign = foreign_shares(hexes.iter().map(|(hex, _, heritage)| (hex, heritage)));
+    let belligerents: HashSet<Nation> =
+        wars.iter().flat_map(|war| [war.aggressor, war.defender]).collect();
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+
+    for nation in nations {
+        let metrics = &all_metrics.0[&nation];
+        if metrics.is_destroyed
+            || metrics.stability >= UNREST_THRESHOLD
+            || polities.last_upheaval(nation).is_some_and(|at| time.tick < at + UPHEAVAL_COOLDOWN)
+        {
+            continue;
+        }
+        let chance = UPHEAVAL_CHANCE * (UNREST_THRESHOLD - metrics.stability) / UNREST_THRESHOLD;
+        if !rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+            continue;
+        }
+        polities.record_upheaval(nation, time.tick);
+
+        let Some((rebels, seat, region)) = partition(nation, &owners, &grid, &all_metrics, &belligerents) else {
+            // Too little land to split: the regime falls instead.
+            let (_, reason) = stability_target(
+                metrics,
+                war_exhaustion(&wars, nation),
+                diplomacy.religious_tension(nation),
+                foreign.get(&nation).copied().unwrap_or(0.0),
+            );
+            let metrics = all_metrics.0.get_mut(&nation).unwrap();
+            event_log.push(WorldEvent::succession(
+                time.tick,
+                epoch,
+                season,
+                nation,
+                metrics.stability,
+                reason,
+            ));
+            metrics.stability = STABILITY_AFTER_COUP;
+            metrics.military *= 0.85;
+            metrics.diplomacy = (metrics.diplomacy - 5.0).max(0.0);
+            continue;
+        };
+
+        let held = owners.values().filter(|owner| **owner == nation).count();
+        let share = region.len() as f32 / held as f32;
+        let reborn = all_metrics.0.contains_key(&rebels);
+        let breakaway: HashSet<AxialCoord> = region.iter().copied().collect();
+        for coord in &region {
+            owners.insert(*coord, rebels);
+        }
+        grid.capitals.insert(rebels, seat);
+
+        // Columns camped in the breakaway land go over, and the rebels
+        // muster a levy at their seat.
+        for mut army in armies.iter_mut() {
+            if army.nation == nation && breakaway.contains(&army.position) {
+                army.nation = rebels;
+                army.objective = Some(seat);
+            }
+        }
+        commands.spawn(Army {
+            id: roster.allocate_id(),
+            nation: rebels,
+            size: REBEL_LEVY_PER_HEX * region.len() as f32,
+            morale: 80.0,
+            supply: 100.0,
+            position: seat,
+            order: ArmyOrder::Garrison,
+            objective: Some(seat),
+        });
+
+        let mut residents: Vec<_> = npcs.iter_mut().filter(|(identity, _)| identity.nation == nation).collect();
+        residents.sort_by_key(|(identity, _)| identity.id);
+        let mut defectors = 0u32;
+        for (mut identity, mut inventory) in residents {
+            if rng.gen_bool(share.clamp(0.0, 1.0) as f64) {
+                identity.nation = rebels;
+                inventory.currency = treasuries.convert(inventory.currency, nation, rebels);
+                defectors += 1;
+            }
+        }
+
+        // The rebels seize the provincial coffers along with the land.
+        let seized = treasuries.0.get(&nation).map_or(0.0, |treasury| treasury.balance) * share;
+        treasuries.transfer(nation, rebels, seized);
+
+        let parent = all_metrics.0.get_mut(&nation).unwrap();
+        let levy = parent.military * share;
+        parent.military -= levy;
+        // The discontented have left with the rebels.
+        parent.stability = (parent.stability + 15.0).min(100.0);
+        let founding = NationMetrics {
+            economy: parent.economy * 0.7,
+            science: parent.science,
+            culture: parent.culture * 0.8,
+            diplomacy: 10.0,
+            religion: parent.religion,
+            military: levy,
+            territory: parent.territory * share,
+            stability: 55.0,
+            is_destroyed: false,
+        };
+        all_metrics.0.insert(rebels, founding);
+        // Rebels take what their old masters knew, but not their research in progress.
+        let known = research.0.get(&nation).map(|state| state.known.clone()).unwrap_or_default();
+        research.0.insert(rebels, ResearchState { known, ..Default::default() });
+        if reborn {
+            polities.record_rebirth(rebels, time.tick);
+        } else {
+            polities.record_upheaval(rebels, time.tick);
+        }
+
+        // A new polity starts with a clean slate, save for the nation it left.
+        let mut others: Vec<Nation> = all_metrics.0.keys().copied().filter(|other| *other != rebels).collect();
+        others.sort_by_key(|other| other.name());
+        for other in others {
+            *diplomacy.relation_mut(rebels, other) = NationRelation::default();
+        }
+        diplomacy.relation_mut(rebels, nation).adjust(-50.0, 0.0);
+        war_ledger.add_grievance(nation, rebels);
+
+        let war_id = war_ledger.allocate_id();
+        commands.spawn(War {
+            id: war_id,
+            aggressor: rebels,
+            defender: nation,
+            casus_belli: CasusBelli::Independence,
+            goal: war_goal(CasusBelli::Independence),
+            declared_at: time.tick,
+            score: 0.0,
+            aggressor_exhaustion: 0.0,
+            defender_exhaustion: 0.0,
+            battles: 0,
+            front: Vec::new(),
+        });
+
+        event_log.push(WorldEvent::rebellion(
+            time.tick,
+            epoch,
+            season,
+            nation,
+            rebels,
+            reborn,
+            seat,
+            region.len() as u32,
+            defectors,
+            war_id,
+        ));
+    }
+
+    for (mut hex, coord, _) in hexes.iter_mut() {
+        if hex.owner != owners[coord] {
+            hex.owner = owners[coord];
+        }
+    }
+}
+
+/// Scatters the people of nations that have just fallen: most stay on under
+/// whoever took the capital, the rest migrate to the surviving nation whose
+/// culture draws them.
+#[allow(clippy::too_many_arguments)]
+pub fn collapse_system(
+    all_metrics: Res<AllNationMetrics>,
+    grid: Res<HexGrid>,
+    hexes: Query<(&Hex, &AxialCoord)>,
+    mut npcs: Query<(&mut Identity, &mut Inventory)>,
+    mut polities: ResMut<PolityLedger>,
+    mut treasuries: ResMut<NationTreasuries>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(331).wrapping_add(59));
+
+    let mut fallen: Vec<Nation> = all_metrics
+        .0
+        .iter()
+        .filter(|(nation, metrics)| metrics.is_destroyed && !polities.has_fallen(**nation))
+        .map(|(nation, _)| *nation)
+        .collect();
+    if fallen.is_empty() {
+        return;
+    }
+    fallen.sort_by_key(|nation| nation.name());
+
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord)| (*coord, hex.owner)).collect();
+    let mut survivors: Vec<(Nation, f32)> = all_metrics
+        .0
+        .iter()
+        .filter(|(_, metrics)| !metrics.is_destroyed)
+        .map(|(nation, metrics)| (*nation, metrics.culture.max(1.0)))
+        .collect();
+    survivors.sort_by_key(|(nation, _)| nation.name());
+
+    for nation in fallen {
+        let conqueror = grid
+            .capitals
+            .get(&nation)
+            .and_then(|capital| owners.get(capital))
+            .copied()
+            .filter(|holder| survivors.iter().any(|(survivor, _)| survivor == holder));
+
+        let mut residents: Vec<_> = npcs.iter_mut().filter(|(identity, _)| identity.nation == nation).collect();
+        residents.sort_by_key(|(identity, _)| identity.id);
+
+        // Wages still owed are paid out before the state goes. With no
+        // conqueror to seize the coffers, whatever they hold is shared out
+        // among the people too.
+        let (balance, payroll) = treasuries
+            .0
+            .get(&nation)
+            .map_or((0.0, 0.0), |treasury| (treasury.balance.max(0.0), treasury.payroll));
+        let mut handout = payroll + if conqueror.is_none() { balance } else { 0.0 };
+        if !residents.is_empty() {
+            let share = handout / residents.len() as f32;
+            for (_, inventory) in residents.iter_mut() {
+                inventory.currency += share;
+            }
+            handout = 0.0;
+        }
+
+        let (mut absorbed, mut migrated) = (0u32, 0u32);
+        // Purses are changed into the coin of whichever nation takes them in.
+        for (mut identity, mut inventory) in residents {
+            if let Some(conqueror) = conqueror
+                && rng.gen_bool(ABSORB_SHARE)
+            {
+                identity.nation = conqueror;
+                inventory.currency = treasuries.convert(inventory.currency, nation, conqueror);
+                absorbed += 1;
+            } else if let Some(refuge) = weighted_choice(&survivors, &mut rng) {
+                identity.nation = refuge;
+                inventory.currency = treasuries.convert(inventory.currency, nation, refuge);
+                migrated += 1;
+            }
+        }
+        // Whatever the coffers still hold falls to the conqueror, and coin
+        // with nobody left to take it to the strongest survivor; debts die
+        // with the state that ran them up, and a state reborn later strikes
+        // fresh coin.
+        if let Some(conqueror) = conqueror {
+            treasuries.transfer(nation, conqueror, balance);
+        }
+        if handout > 0.0
+            && let Some(heir) = conqueror.or_else(|| survivors.first().map(|(survivor, _)| *survivor))
+        {
+            let received = treasuries.convert(handout, nation, heir);
+            treasuries.treasury_mut(heir).collect(received);
+        }
+        treasuries.0.remove(&nation);
+
+        polities.record_fall(nation, time.tick);
+        event_log.push(WorldEvent::collapse(
+            time.tick,
+            epoch,
+            season,
+            nation,
+            conqueror,
+            absorbed,
+            migrated,
+        ));
+    }
+}
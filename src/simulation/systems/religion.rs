@@ -0,0 +1,310 @@
+//! The faith of the Temple of Suns: adherence spreads hex by hex from temple
+//! NPCs and Village shrines, pilgrims walk to the holiest ground, and faith
+//! that differs across a border breeds tension.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use super::army::step_toward;
+use crate::simulation::{
+    ActiveShocks, AllNationMetrics, AxialCoord, Behavior, BehaviorState, Biome, DiplomacyMatrix, Faction, Faith,
+    Hex, HexGrid, Identity, Nation, Pilgrim, Pilgrimage, Position, ShockTrigger, War, WorldEvent, WorldEventLog,
+    WorldMetadata, WorldTime, pair_key,
+};
+
+/// Adherence a temple NPC adds each tick to the hex it stands on.
+const PREACHING_FERVOR: f32 = 0.3;
+/// Adherence any praying NPC adds each tick to the hex it stands on.
+const PRAYER_FERVOR: f32 = 0.15;
+/// Adherence a Village hex's shrines add each tick.
+const SHRINE_FERVOR: f32 = 0.08;
+/// Share of the gap to its neighbours' average a hex closes each tick.
+const FAITH_DIFFUSION: f32 = 0.05;
+/// Share of the gap to the owner's state religion a hex closes each tick.
+const STATE_CULT_PULL: f32 = 0.01;
+const FAITH_DECAY: f32 = 0.998;
+/// Share of the gap to its people's faith the religion metric closes each tick.
+const GRASSROOTS_PULL: f32 = 0.02;
+
+/// Bands set out at most once per this many ticks.
+const PILGRIMAGE_INTERVAL: u64 = 40;
+/// Chance a fully devout nation sends a band when the season comes.
+const PILGRIMAGE_CHANCE: f32 = 0.6;
+const MIN_PILGRIMS: usize = 2;
+const MAX_PILGRIMS: usize = 6;
+/// Ticks a band needs to cross one hex.
+const PILGRIM_MARCH_TICKS: u64 = 2;
+/// Adherence each pilgrim leaves in every hex the band walks through.
+const PILGRIM_BLESSING: f32 = 0.5;
+
+/// Spreads adherence across the map, lets it shape each nation's religion,
+/// and measures the religious tension along every shared border.
+pub fn faith_system(
+    mut all_metrics: ResMut<AllNationMetrics>,
+    mut diplomacy: ResMut<DiplomacyMatrix>,
+    grid: Res<HexGrid>,
+    mut hexes: Query<(&Hex, &AxialCoord, &mut Faith)>,
+    npcs: Query<(&Identity, &Position, &Behavior)>,
+) {
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord, _)| (*coord, hex.owner)).collect();
+    let adherence: HashMap<AxialCoord, f32> =
+        hexes.iter().map(|(_, coord, faith)| (*coord, faith.adherence)).collect();
+
+    // Temple NPCs preach and the pious pray where they stand.
+    let mut fervor: HashMap<AxialCoord, f32> = HashMap::new();
+    for (identity, position, behavior) in &npcs {
+        let mut gain = 0.0;
+        if identity.faction == Faction::TempleOfSuns {
+            gain += PREACHING_FERVOR;
+        }
+        if behavior.state == BehaviorState::Pray {
+            gain += PRAYER_FERVOR;
+        }
+        if gain > 0.0 {
+            *fervor.entry(grid.coord_at(position.x, position.y)).or_insert(0.0) += gain;
+        }
+    }
+
+    for (hex, coord, mut faith) in hexes.iter_mut() {
+        let neighbors: Vec<f32> = coord.neighbors().iter().filter_map(|n| adherence.get(n).copied()).collect();
+        let local = adherence[coord];
+        let mut next = local;
+        if !neighbors.is_empty() {
+            let average = neighbors.iter().sum::<f32>() / neighbors.len() as f32;
+            next += (average - local) * FAITH_DIFFUSION;
+        }
+        if let Some(metrics) = all_metrics.0.get(&hex.owner) {
+            next += (metrics.religion - local) * STATE_CULT_PULL;
+        }
+        if hex.biome == Biome::Village {
+            next += SHRINE_FERVOR;
+        }
+        next += fervor.get(coord).copied().unwrap_or(0.0);
+        faith.adherence = (next * FAITH_DECAY).clamp(0.0, 100.0);
+    }
+
+    // The people's faith pulls the state religion along with it.
+    let mut devotion: HashMap<Nation, (f32, usize)> = HashMap::new();
+    for (_, coord, faith) in hexes.iter() {
+        let entry = devotion.entry(owners[coord]).or_insert((0.0, 0));
+        entry.0 += faith.adherence;
+        entry.1 += 1;
+    }
+    for (nation, metrics) in all_metrics.0.iter_mut() {
+        if let Some((total, count)) = devotion.get(nation) {
+            let mean = total / *count as f32;
+            metrics.religion = (metrics.religion + (mean - metrics.religion) * GRASSROOTS_PULL).clamp(0.0, 100.0);
+        }
+    }
+
+    // Tension: the average gap in faith between neighbouring hexes across a border.
+    let mut gaps: HashMap<(Nation, Nation), (f32, usize)> = HashMap::new();
+    for (coord, owner) in &owners {
+        for neighbor in coord.neighbors() {
+            let Some(other) = owners.get(&neighbor) else {
+                continue;
+            };
+            if other == owner || owner.name() > other.name() {
+                continue;
+            }
+            let entry = gaps.entry((*owner, *other)).or_insert((0.0, 0));
+            entry.0 += (adherence[coord] - adherence[&neighbor]).abs();
+            entry.1 += 1;
+        }
+    }
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+    for (i, a) in nations.iter().enumerate() {
+        for b in &nations[i + 1..] {
+            let tension = gaps.get(&pair_key(*a, *b)).map_or(0.0, |(total, count)| total / *count as f32);
+            diplomacy.relation_mut(*a, *b).religious_tension = tension;
+        }
+    }
+}
+
+/// Sends bands of the faithful from devout nations to the holiest hex,
+/// blesses the land they cross, and brings a boon to wherever they arrive.
+/// The pilgrims themselves walk with their band, praying as they go, and
+/// take up their lives again wherever it ends.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn pilgrimage_system(
+    mut commands: Commands,
+    mut bands: Query<(Entity, &mut Pilgrimage)>,
+    mut all_metrics: ResMut<AllNationMetrics>,
+    mut diplomacy: ResMut<DiplomacyMatrix>,
+    grid: Res<HexGrid>,
+    mut hexes: Query<(&Hex, &AxialCoord, &mut Faith)>,
+    mut npcs: Query<(Entity, &Identity, &Behavior, &mut Position, Option<&Pilgrim>)>,
+    wars: Query<&War>,
+    mut shocks: ResMut<ActiveShocks>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(743).wrapping_add(47));
+
+    let owners: HashMap<AxialCoord, Nation> =
+        hexes.iter().map(|(hex, coord, _)| (*coord, hex.owner)).collect();
+    let terrain: HashMap<AxialCoord, Biome> = hexes.iter().map(|(hex, coord, _)| (*coord, hex.biome)).collect();
+    let hex_position = |coord: AxialCoord| {
+        let (x, y) = coord.to_world(grid.radius);
+        Position { x, y, biome: terrain[&coord] }
+    };
+    let walkers: HashMap<u64, Entity> = npcs.iter().map(|(entity, identity, ..)| (identity.id, entity)).collect();
+    // Where pilgrims stand after this tick, and those whose band broke up.
+    let mut marches: Vec<(u64, Position)> = Vec::new();
+    let mut released: Vec<u64> = Vec::new();
+    let hostile: HashSet<(Nation, Nation)> = wars
+        .iter()
+        .flat_map(|war| [(war.aggressor, war.defender), (war.defender, war.aggressor)])
+        .collect();
+    let mut blessings: HashMap<AxialCoord, f32> = HashMap::new();
+
+    // 1. Bands on the road walk on, or arrive and bring their boon.
+    let mut on_road: HashSet<Nation> = HashSet::new();
+    let mut columns: Vec<(Entity, Mut<Pilgrimage>)> = bands.iter_mut().collect();
+    columns.sort_by_key(|(_, band)| (band.departed_at, band.nation.name()));
+    for (entity, mut band) in columns {
+        let nation = band.nation;
+        band.pilgrims.retain(|pilgrim| walkers.contains_key(pilgrim));
+        if band.pilgrims.is_empty() || all_metrics.0.get(&nation).is_none_or(|metrics| metrics.is_destroyed) {
+            released.extend(band.pilgrims.iter().copied());
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if band.position == band.destination {
+            let host = owners[&band.destination];
+            let pilgrims = band.pilgrims.len() as f32;
+            if let Some(host_metrics) = all_metrics.0.get_mut(&host) {
+                // Pilgrims spend freely and their festival lifts spirits.
+                host_metrics.economy = (host_metrics.economy + 2.0 + pilgrims * 0.5).min(100.0);
+                host_metrics.stability = (host_metrics.stability + 3.0).min(100.0);
+            }
+            if let Some(metrics) = all_metrics.0.get_mut(&nation) {
+                metrics.religion = (metrics.religion + 3.0).min(100.0);
+                metrics.stability = (metrics.stability + 2.0).min(100.0);
+            }
+            if host != nation {
+                diplomacy.relation_mut(nation, host).adjust(3.0, 1.0);
+            }
+            for coord in std::iter::once(band.destination).chain(band.destination.neighbors()) {
+                *blessings.entry(coord).or_insert(0.0) += 5.0;
+            }
+
+            // The festival lingers as a boon over the host's markets and
+            // shrines; a second band only draws it out.
+            for shock in world_meta
+                .shocks
+                .iter()
+                .filter(|shock| shock.triggers.contains(&ShockTrigger::PilgrimArrival))
+            {
+                let duration = rng.gen_range(shock.duration.0..=shock.duration.1);
+                if shocks.strike(shock, vec![host], time.tick, duration) {
+                    event_log.push(WorldEvent::macro_shock(
+                        time.tick,
+                        epoch,
+                        season,
+                        shock,
+                        &[host],
+                        duration,
+                        &world_meta,
+                    ));
+                }
+            }
+            released.extend(band.pilgrims.iter().copied());
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        on_road.insert(nation);
+        if !time.tick.is_multiple_of(PILGRIM_MARCH_TICKS) {
+            continue;
+        }
+        let Some(next) = step_toward(&owners, band.position, band.destination) else {
+            released.extend(band.pilgrims.iter().copied());
+            commands.entity(entity).despawn();
+            continue;
+        };
+        // Pilgrims do not walk into the land of an enemy; the band scatters.
+        if hostile.contains(&(nation, owners[&next])) {
+            released.extend(band.pilgrims.iter().copied());
+            commands.entity(entity).despawn();
+            continue;
+        }
+        band.position = next;
+        marches.extend(band.pilgrims.iter().map(|pilgrim| (*pilgrim, hex_position(next))));
+        *blessings.entry(next).or_insert(0.0) += PILGRIM_BLESSING * band.pilgrims.len() as f32;
+    }
+
+    // 2. When the season comes, devout nations send a new band to the holiest hex.
+    if time.tick.is_multiple_of(PILGRIMAGE_INTERVAL)
+        && let Some((holy_site, _)) = hexes
+            .iter()
+            .map(|(_, coord, faith)| (*coord, faith.adherence))
+            .max_by(|a, b| a.1.total_cmp(&b.1).then((b.0.q, b.0.r).cmp(&(a.0.q, a.0.r))))
+    {
+        let mut faithful: HashMap<Nation, Vec<u64>> = HashMap::new();
+        for (_, identity, behavior, _, pilgrim) in &npcs {
+            if pilgrim.is_none()
+                && (identity.faction == Faction::TempleOfSuns || behavior.state == BehaviorState::Pray)
+            {
+                faithful.entry(identity.nation).or_default().push(identity.id);
+            }
+        }
+
+        let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+        nations.sort_by_key(|nation| nation.name());
+        for nation in nations {
+            let metrics = &all_metrics.0[&nation];
+            let Some(capital) = grid.capitals.get(&nation).copied() else {
+                continue;
+            };
+            if metrics.is_destroyed
+                || on_road.contains(&nation)
+                || hostile.contains(&(nation, owners[&holy_site]))
+                || !rng.gen_bool((metrics.religion / 100.0 * PILGRIMAGE_CHANCE).clamp(0.0, 1.0) as f64)
+            {
+                continue;
+            }
+            let mut pilgrims = faithful.remove(&nation).unwrap_or_default();
+            if pilgrims.len() < MIN_PILGRIMS {
+                continue;
+            }
+            pilgrims.sort_unstable();
+            pilgrims.truncate(MAX_PILGRIMS);
+            // The band musters at the capital.
+            for pilgrim in &pilgrims {
+                commands.entity(walkers[pilgrim]).insert(Pilgrim);
+                marches.push((*pilgrim, hex_position(capital)));
+            }
+            commands.spawn(Pilgrimage {
+                nation,
+                pilgrims,
+                position: capital,
+                destination: holy_site,
+                departed_at: time.tick,
+            });
+        }
+    }
+
+    for (pilgrim, position) in marches {
+        if let Ok((.., mut standing, _)) = npcs.get_mut(walkers[&pilgrim]) {
+            *standing = position;
+        }
+    }
+    for pilgrim in released {
+        commands.entity(walkers[&pilgrim]).remove::<Pilgrim>();
+    }
+
+    for (_, coord, mut faith) in hexes.iter_mut() {
+        if let Some(blessing) = blessings.get(coord) {
+            faith.adherence = (faith.adherence + blessing).min(100.0);
+        }
+    }
+}
@@ -0,0 +1,275 @@
+//! Fame, infamy and faction standing driven by what NPCs do.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use crate::simulation::{
+    Attributes, Behavior, BehaviorState, Biome, EventActor, Faction, Identity, Reputation, ReputationTier,
+    WorldEventKind, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deed {
+    Trade,
+    Gathering,
+    Raid { victim: Faction },
+    /// A hunter turning on an outsider it or its faction hates.
+    Hunt { quarry: Faction },
+    /// Standing one's ground when attacked.
+    Defense { attacker: Faction },
+    /// `provoked` when the killer was the one attacked.
+    Kill { victim: Faction, provoked: bool },
+}
+
+pub fn record_deed(
+    actor_faction: Faction,
+    attributes: &mut Attributes,
+    reputation: &mut Reputation,
+    deed: Deed,
+) {
+    match deed {
+        Deed::Trade => {
+            attributes.fame += 0.05;
+            reputation.adjust_standing(Faction::MerchantGuild, 0.1);
+        }
+        Deed::Gathering => {
+            attributes.fame += 1.0;
+            reputation.adjust_standing(actor_faction, 0.5);
+            reputation.adjust_standing(Faction::TempleOfSuns, 0.3);
+        }
+        Deed::Raid { victim } => {
+            reputation.infamy += 3.0;
+            reputation.adjust_standing(victim, -5.0);
+            reputation.adjust_standing(Faction::MerchantGuild, -1.0);
+            reputation.adjust_standing(Faction::BanditClans, 1.0);
+        }
+        Deed::Hunt { quarry } => {
+            reputation.adjust_standing(quarry, -2.0);
+            if quarry == Faction::BanditClans {
+                attributes.fame += 0.5;
+                reputation.adjust_standing(Faction::MerchantGuild, 0.5);
+            }
+        }
+        Deed::Defense { attacker } => {
+            attributes.fame += 0.3;
+            reputation.adjust_standing(attacker, -1.0);
+        }
+        Deed::Kill { victim, provoked } => {
+            // Cutting down a bandit is a public service and killing an
+            // attacker is self-defence; any other killing is murder.
+            if victim == Faction::BanditClans {
+                attributes.fame += 3.0;
+                reputation.adjust_standing(Faction::MerchantGuild, 2.0);
+                reputation.adjust_standing(Faction::SettlersUnion, 2.0);
+            } else if provoked {
+                reputation.adjust_standing(victim, -3.0);
+            } else {
+                reputation.infamy += 6.0;
+                reputation.adjust_standing(victim, -10.0);
+            }
+        }
+    }
+
+    attributes.fame = attributes.fame.clamp(0.0, 100.0);
+    reputation.infamy = reputation.infamy.clamp(0.0, 100.0);
+}
+
+/// Tier-driven nudges to behaviour choice: notorious NPCs are shunned at
+/// market and lean into predation, celebrated ones are sought out to trade and host.
+pub fn reputation_behavior_modifier(tier: ReputationTier, state: BehaviorState) -> f32 {
+    match tier {
+        ReputationTier::Infamous => match state {
+            BehaviorState::Hunt => 1.3,
+            BehaviorState::Raid => 1.4,
+            BehaviorState::Trade => 0.7,
+            _ => 1.0,
+        },
+        ReputationTier::Legendary => match state {
+            BehaviorState::Trade => 1.2,
+            BehaviorState::Idle => 1.15,
+            BehaviorState::Raid => 0.6,
+            _ => 1.0,
+        },
+        ReputationTier::Renowned => match state {
+            BehaviorState::Trade => 1.1,
+            BehaviorState::Idle => 1.05,
+            _ => 1.0,
+        },
+        ReputationTier::Noted | ReputationTier::Unknown => 1.0,
+    }
+}
+
+/// Trade terms offered by the factions that hold sway in a biome, from 0.5x to 1.5x.
+pub fn standing_trade_modifier(
+    reputation: &Reputation,
+    fame: f32,
+    biome: Biome,
+    world_meta: &WorldMetadata,
+) -> f32 {
+    let hosts: Vec<f32> = world_meta
+        .factions
+        .iter()
+        .filter(|(_, meta)| meta.strongholds.contains(&biome))
+        .map(|(faction, _)| reputation.standing_with(*faction))
+        .collect();
+    let standing = if hosts.is_empty() {
+        0.0
+    } else {
+        hosts.iter().sum::<f32>() / hosts.len() as f32
+    };
+
+    let infamy_penalty = match reputation.tier(fame) {
+        ReputationTier::Infamous => 0.15,
+        _ => 0.0,
+    };
+
+    (1.0 + standing / 200.0 - infamy_penalty).clamp(0.5, 1.5)
+}
+
+/// What a skirmish says about each side: raiders earn infamy, other hunters
+/// and their victims only the enmity of the side they fought, and whoever
+/// died marks their killer.
+pub fn combat_deeds(
+    attacker: &EventActor,
+    defender: &EventActor,
+    attacker_slain: bool,
+    defender_slain: bool,
+) -> Vec<(u64, Deed)> {
+    let raiding = attacker.behavior_hint == BehaviorState::Raid || attacker.faction == Faction::BanditClans;
+    let mut deeds = vec![
+        if raiding {
+            (attacker.id, Deed::Raid { victim: defender.faction })
+        } else {
+            (attacker.id, Deed::Hunt { quarry: defender.faction })
+        },
+        (defender.id, Deed::Defense { attacker: attacker.faction }),
+    ];
+    if defender_slain {
+        deeds.push((attacker.id, Deed::Kill { victim: defender.faction, provoked: false }));
+    }
+    if attacker_slain {
+        deeds.push((defender.id, Deed::Kill { victim: attacker.faction, provoked: true }));
+    }
+    deeds
+}
+
+pub fn reputation_system(
+    mut query: Query<(&Identity, &Behavior, &mut Attributes, &mut Reputation)>,
+    event_log: Res<WorldEventLog>,
+    time: Res<WorldTime>,
+) {
+    let mut deeds: HashMap<u64, Vec<Deed>> = HashMap::new();
+    for event in event_log.events_at(time.tick) {
+        match &event.kind {
+            WorldEventKind::Social { convener, .. } => {
+                deeds.entry(convener.id).or_default().push(Deed::Gathering);
+            }
+            WorldEventKind::Combat {
+                attacker,
+                defender,
+                attacker_slain,
+                defender_slain,
+                ..
+            } => {
+                for (npc, deed) in combat_deeds(attacker, defender, *attacker_slain, *defender_slain) {
+                    deeds.entry(npc).or_default().push(deed);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (identity, behavior, mut attributes, mut reputation) in &mut query {
+        // Renown fades unless it is renewed.
+        attributes.fame *= 0.9995;
+        reputation.infamy *= 0.999;
+        for standing in reputation.standing.values_mut() {
+            *standing *= 0.999;
+        }
+
+        if matches!(behavior.state, BehaviorState::Trade) {
+            record_deed(identity.faction, &mut attributes, &mut reputation, Deed::Trade);
+        }
+        for deed in deeds.remove(&identity.id).into_iter().flatten() {
+            record_deed(identity.faction, &mut attributes, &mut reputation, deed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::Nation;
+
+    fn actor(id: u64, faction: Faction, state: BehaviorState) -> EventActor {
+        EventActor {
+            id,
+            name: format!("npc{id}"),
+            hangul_name: format!("npc{id}"),
+            nation: Nation::Tera,
+            faction,
+            faction_label: String::new(),
+            biome: Biome::Plains,
+            biome_label: String::new(),
+            behavior_hint: state,
+            behavior_hint_label: String::new(),
+        }
+    }
+
+    /// Applies every deed `npc` earned in the skirmish to a fresh reputation.
+    fn standing_after(npc: &EventActor, deeds: &[(u64, Deed)]) -> (Attributes, Reputation) {
+        let mut attributes = Attributes { health: 100.0, stamina: 100.0, wealth: 0.0, fame: 0.0 };
+        let mut reputation = Reputation::default();
+        for (_, deed) in deeds.iter().filter(|(id, _)| *id == npc.id) {
+            record_deed(npc.faction, &mut attributes, &mut reputation, *deed);
+        }
+        (attributes, reputation)
+    }
+
+    #[test]
+    fn raiders_earn_infamy() {
+        let raider = actor(1, Faction::SettlersUnion, BehaviorState::Raid);
+        let victim = actor(2, Faction::MerchantGuild, BehaviorState::Trade);
+        let deeds = combat_deeds(&raider, &victim, false, false);
+        assert!(deeds.contains(&(1, Deed::Raid { victim: Faction::MerchantGuild })));
+
+        let (_, reputation) = standing_after(&raider, &deeds);
+        assert!(reputation.infamy > 0.0);
+        assert!(reputation.standing_with(Faction::BanditClans) > 0.0);
+    }
+
+    #[test]
+    fn hunting_a_hated_bandit_is_not_a_raid() {
+        let hunter = actor(1, Faction::MerchantGuild, BehaviorState::Hunt);
+        let bandit = actor(2, Faction::BanditClans, BehaviorState::Hunt);
+        let deeds = combat_deeds(&hunter, &bandit, false, false);
+        assert!(deeds.contains(&(1, Deed::Hunt { quarry: Faction::BanditClans })));
+
+        let (_, reputation) = standing_after(&hunter, &deeds);
+        assert_eq!(reputation.infamy, 0.0);
+        assert!(reputation.standing_with(Faction::MerchantGuild) >= 0.0);
+        assert!(reputation.standing_with(Faction::BanditClans) < 0.0);
+    }
+
+    #[test]
+    fn killing_an_attacker_is_self_defence() {
+        let raider = actor(1, Faction::ExplorersLeague, BehaviorState::Raid);
+        let defender = actor(2, Faction::SettlersUnion, BehaviorState::Gather);
+        let deeds = combat_deeds(&raider, &defender, true, false);
+        assert!(deeds.contains(&(2, Deed::Kill { victim: Faction::ExplorersLeague, provoked: true })));
+
+        let (attributes, reputation) = standing_after(&defender, &deeds);
+        assert_eq!(reputation.infamy, 0.0);
+        assert!(attributes.fame > 0.0);
+    }
+
+    #[test]
+    fn a_standoff_can_kill_both_sides() {
+        let raider = actor(1, Faction::BanditClans, BehaviorState::Hunt);
+        let defender = actor(2, Faction::MerchantGuild, BehaviorState::Trade);
+        let deeds = combat_deeds(&raider, &defender, true, true);
+        assert!(deeds.contains(&(1, Deed::Kill { victim: Faction::MerchantGuild, provoked: false })));
+        assert!(deeds.contains(&(2, Deed::Kill { victim: Faction::BanditClans, provoked: true })));
+    }
+}
@@ -0,0 +1,89 @@
+//! Nations turn science into discoveries along the technology tree, picking
+//! what to research from the budget their strategy settled on.
+
+use bevy_ecs::prelude::*;
+
+use crate::simulation::{
+    AllNationMetrics, BudgetLine, Nation, NationResearch, NationStrategies, ResearchState,
+    WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+/// Research gained per tick for each point of science.
+const RESEARCH_RATE: f32 = 0.04;
+
+/// The open technology that best serves the nation's budget for its cost,
+/// earliest in the tree on ties.
+fn choose_research(
+    state: &ResearchState,
+    nation: Nation,
+    strategies: &NationStrategies,
+    world_meta: &WorldMetadata,
+) -> Option<&'static str> {
+    let share = |line: BudgetLine| {
+        strategies
+            .0
+            .get(&nation)
+            .map_or(1.0 / BudgetLine::ALL.len() as f32, |strategy| strategy.share(line))
+    };
+    world_meta
+        .techs
+        .iter()
+        .filter(|tech| !state.known.contains(&tech.label))
+        .filter(|tech| tech.prerequisites.iter().all(|prerequisite| state.known.contains(prerequisite)))
+        .map(|tech| (tech.label, (share(tech.line) + 0.05) / tech.cost))
+        .fold(None, |best: Option<(&'static str, f32)>, candidate| match best {
+            Some(best) if best.1 >= candidate.1 => Some(best),
+            _ => Some(candidate),
+        })
+        .map(|(label, _)| label)
+}
+
+pub fn research_system(
+    mut research: ResMut<NationResearch>,
+    all_metrics: Res<AllNationMetrics>,
+    strategies: Res<NationStrategies>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+
+    for nation in nations {
+        let metrics = &all_metrics.0[&nation];
+        if metrics.is_destroyed {
+            continue;
+        }
+        let state = research.0.entry(nation).or_default();
+        if state.current.is_none() {
+            state.current = choose_research(state, nation, &strategies, &world_meta);
+        }
+        let Some(current) = state.current else {
+            continue;
+        };
+        let Some(tech) = world_meta.tech(current) else {
+            state.current = None;
+            continue;
+        };
+
+        state.progress += metrics.science * RESEARCH_RATE;
+        if state.progress < tech.cost {
+            continue;
+        }
+
+        state.progress -= tech.cost;
+        state.known.push(tech.label);
+        state.current = None;
+        event_log.push(WorldEvent::breakthrough(
+            time.tick,
+            epoch,
+            season,
+            nation,
+            tech.label,
+            tech.line,
+            &tech.effects,
+        ));
+    }
+}
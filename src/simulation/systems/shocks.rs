@@ -0,0 +1,120 @@
+//! Macro shocks: droughts, invasions, market crashes, plagues and boons that
+//! break out where the world data's triggers hold, bend yields, prices and
+//! behaviour within their reach for a while, and then pass.
+
+use std::collections::HashSet;
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    ActiveShocks, AllNationMetrics, Nation, NationMetrics, ShockModifier, ShockScope, ShockTrigger, War,
+    WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+fn triggered(trigger: ShockTrigger, metrics: &NationMetrics, at_war: bool, season: &str) -> bool {
+    match trigger {
+        ShockTrigger::Season(wanted) => season == wanted,
+        ShockTrigger::AtWar => at_war,
+        ShockTrigger::Below(line, level) => metrics.budget_line(line) < level,
+        ShockTrigger::Above(line, level) => metrics.budget_line(line) >= level,
+        ShockTrigger::PilgrimArrival => false,
+    }
+}
+
+/// Ends shocks that have run their course, lets new ones break out where
+/// their triggers hold, and applies the running shocks' pull on the struck
+/// nations' metrics.
+pub fn macro_shock_system(
+    mut shocks: ResMut<ActiveShocks>,
+    mut all_metrics: ResMut<AllNationMetrics>,
+    wars: Query<&War>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(911).wrapping_add(67));
+
+    // 1. Shocks that have run their course pass.
+    let (ended, running): (Vec<_>, Vec<_>) =
+        std::mem::take(&mut shocks.0).into_iter().partition(|active| active.ends_at <= time.tick);
+    shocks.0 = running;
+    for active in ended {
+        if let Some(shock) = world_meta.shock(active.label) {
+            event_log.push(WorldEvent::macro_shock_ended(time.tick, epoch, season, shock, &active.nations));
+        }
+    }
+
+    // 2. New shocks break out where every trigger holds.
+    let at_war: HashSet<Nation> = wars.iter().flat_map(|war| [war.aggressor, war.defender]).collect();
+    let mut living: Vec<Nation> = all_metrics
+        .0
+        .iter()
+        .filter(|(_, metrics)| !metrics.is_destroyed)
+        .map(|(nation, _)| *nation)
+        .collect();
+    living.sort_by_key(|nation| nation.name());
+
+    for shock in world_meta.shocks.iter().filter(|shock| shock.chance > 0.0) {
+        let ripe: Vec<Nation> = living
+            .iter()
+            .copied()
+            .filter(|nation| {
+                !shocks.strikes(shock.label, *nation)
+                    && shock.triggers.iter().all(|trigger| {
+                        triggered(*trigger, &all_metrics.0[nation], at_war.contains(nation), season)
+                    })
+            })
+            .collect();
+        // A world shock rolls once, and only where some nation is ripe for it.
+        let outbreaks: Vec<Vec<Nation>> = match shock.scope {
+            ShockScope::Nation => ripe
+                .into_iter()
+                .filter(|_| rng.gen_bool(shock.chance))
+                .map(|nation| vec![nation])
+                .collect(),
+            ShockScope::World if !ripe.is_empty() && rng.gen_bool(shock.chance) => vec![living.clone()],
+            ShockScope::World => Vec::new(),
+        };
+        for nations in outbreaks {
+            let duration = rng.gen_range(shock.duration.0..=shock.duration.1);
+            if shocks.strike(shock, nations.clone(), time.tick, duration) {
+                event_log.push(WorldEvent::macro_shock(
+                    time.tick,
+                    epoch,
+                    season,
+                    shock,
+                    &nations,
+                    duration,
+                    &world_meta,
+                ));
+            }
+        }
+    }
+
+    // 3. Running shocks pull on the struck nations' metrics.
+    for active in &shocks.0 {
+        let Some(shock) = world_meta.shock(active.label) else {
+            continue;
+        };
+        for nation in &active.nations {
+            let Some(metrics) = all_metrics.0.get_mut(nation).filter(|metrics| !metrics.is_destroyed) else {
+                continue;
+            };
+            for modifier in &shock.modifiers {
+                match *modifier {
+                    ShockModifier::Metric(line, delta) => {
+                        let value = metrics.budget_line_mut(line);
+                        *value = (*value + delta).clamp(0.0, 100.0);
+                    }
+                    ShockModifier::Stability(delta) => {
+                        metrics.stability = (metrics.stability + delta).clamp(0.0, 100.0);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Pairwise NPC interactions that shape the relationship graph.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    Behavior, BehaviorState, Biome, Episode, Faction, Identity, Memory, Position,
+    RelationshipGraph, WorldEventKind, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+const GATHERING_SIZE: usize = 6;
+
+struct Resident {
+    id: u64,
+    faction: Faction,
+    biome: Biome,
+    state: BehaviorState,
+}
+
+pub fn relationship_system(
+    mut query: Query<(&Identity, &Position, &Behavior, &mut Memory)>,
+    mut graph: ResMut<RelationshipGraph>,
+    event_log: Res<WorldEventLog>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+) {
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(577).wrapping_add(31));
+
+    let mut residents: Vec<Resident> = query
+        .iter()
+        .map(|(identity, position, behavior, ..)| Resident {
+            id: identity.id,
+            faction: identity.faction,
+            biome: position.biome,
+            state: behavior.state,
+        })
+        .collect();
+    residents.sort_by_key(|resident| resident.id);
+
+    let factions: HashMap<u64, Faction> = residents
+        .iter()
+        .map(|resident| (resident.id, resident.faction))
+        .collect();
+    let baseline = |from: u64, to: u64| match (factions.get(&from), factions.get(&to)) {
+        (Some(a), Some(b)) => world_meta.faction_attitude(*a, *b),
+        _ => 0.0,
+    };
+
+    let alive: HashSet<u64> = factions.keys().copied().collect();
+    graph.decay(&alive, baseline);
+
+    let mut episodes: HashMap<u64, Vec<Episode>> = HashMap::new();
+    let mut by_biome: HashMap<Biome, Vec<&Resident>> = HashMap::new();
+    for resident in &residents {
+        by_biome.entry(resident.biome).or_default().push(resident);
+    }
+
+    // 1. Gatherings bond the convener with the idle and resting NPCs nearby.
+    for event in event_log.events_at(time.tick) {
+        let WorldEventKind::Social { convener, .. } = &event.kind else {
+            continue;
+        };
+        let mut attendees: Vec<u64> = by_biome
+            .get(&convener.biome)
+            .into_iter()
+            .flatten()
+            .filter(|resident| {
+                resident.id != convener.id
+                    && matches!(resident.state, BehaviorState::Idle | BehaviorState::Rest)
+            })
+            .map(|resident| resident.id)
+            .collect();
+        attendees.shuffle(&mut rng);
+        attendees.truncate(GATHERING_SIZE);
+
+        for (i, &guest) in attendees.iter().enumerate() {
+            episodes.entry(guest).or_default().push(Episode::Gathering {
+                tick: time.tick,
+                biome: convener.biome,
+                convener: convener.id,
+            });
+            graph
+                .edge_mut(guest, convener.id, baseline(guest, convener.id))
+                .adjust(2.0, 1.0, 0.0);
+            graph
+                .edge_mut(convener.id, guest, baseline(convener.id, guest))
+                .adjust(1.0, 0.5, 0.0);
+            for &other in &attendees[i + 1..] {
+                graph.edge_mut(guest, other, baseline(guest, other)).adjust(0.5, 0.0, 0.0);
+                graph.edge_mut(other, guest, baseline(other, guest)).adjust(0.5, 0.0, 0.0);
+            }
+        }
+    }
+
+    // 2. Traders pair up with the co-located trader they regard most, and
+    //    deal through them at the next market.
+    let mut pairs = Vec::new();
+    let mut biomes: Vec<Biome> = by_biome.keys().copied().collect();
+    biomes.sort_by_key(|biome| *biome as u8);
+    for biome in &biomes {
+        let mut traders: Vec<u64> = by_biome[biome]
+            .iter()
+            .filter(|resident| matches!(resident.state, BehaviorState::Trade))
+            .map(|resident| resident.id)
+            .collect();
+        traders.shuffle(&mut rng);
+
+        let mut paired = HashSet::new();
+        for &trader in &traders {
+            if paired.contains(&trader) {
+                continue;
+            }
+            let partner = traders
+                .iter()
+                .filter(|other| **other != trader && !paired.contains(*other))
+                .map(|other| {
+                    let regard = graph.get(trader, *other).map_or(baseline(trader, *other), |r| r.regard());
+                    (*other, regard + rng.gen_range(0.0..10.0))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(other, _)| other);
+
+            if let Some(partner) = partner {
+                paired.insert(trader);
+                paired.insert(partner);
+                graph.edge_mut(trader, partner, baseline(trader, partner)).adjust(0.5, 1.5, 0.0);
+                graph.edge_mut(partner, trader, baseline(partner, trader)).adjust(0.5, 1.5, 0.0);
+                pairs.push((trader, partner));
+            }
+        }
+    }
+    graph.set_partners(&pairs);
+
+    // 3. Fights leave grudges: the defender resents the attacker, who marks them as a rival.
+    for event in event_log.events_at(time.tick) {
+        let WorldEventKind::Combat {
+            attacker, defender, ..
+        } = &event.kind
+        else {
+            continue;
+        };
+        graph
+            .edge_mut(defender.id, attacker.id, baseline(defender.id, attacker.id))
+            .adjust(-5.0, -3.0, 5.0);
+        graph
+            .edge_mut(attacker.id, defender.id, baseline(attacker.id, defender.id))
+            .adjust(0.0, 0.0, 1.0);
+        episodes.entry(defender.id).or_default().push(Episode::Attacked {
+            tick: time.tick,
+            biome: attacker.biome,
+            attacker: attacker.id,
+        });
+    }
+
+    for (identity, _, _, mut memory) in &mut query {
+        for episode in episodes.remove(&identity.id).into_iter().flatten() {
+            memory.remember(episode);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Nation-level strategic AI: each nation weighs threats, rivals and
+//! opportunities against its doctrine and settles on a budget.
+
+use std::collections::{HashMap, HashSet};
+
+use bevy_ecs::prelude::*;
+
+use super::warfare::effective_military;
+use crate::simulation::{
+    AllNationMetrics, BudgetLine, DiplomacyMatrix, Doctrine, Nation, NationMetrics,
+    NationStrategies, NationStrategy, TreatyKind, War, WorldEvent, WorldEventLog, WorldMetadata,
+    WorldTime,
+};
+
+/// Budgets are revised once every this many ticks.
+const REVIEW_INTERVAL: u64 = 10;
+
+/// Scales the doctrine's weights by the nation's situation. Returns the
+/// weights and the pressures that moved them, strongest first.
+fn assess(
+    nation: Nation,
+    doctrine: Doctrine,
+    mut weights: HashMap<BudgetLine, f32>,
+    all_metrics: &AllNationMetrics,
+    diplomacy: &DiplomacyMatrix,
+    belligerents: &HashSet<Nation>,
+) -> (HashMap<BudgetLine, f32>, Vec<(BudgetLine, &'static str)>) {
+    let own = &all_metrics.0[&nation];
+    let mut pressures: Vec<(BudgetLine, f32, &'static str)> = Vec::new();
+    let mut scale = |line: BudgetLine, factor: f32, reason: &'static str| {
+        *weights.entry(line).or_insert(0.0) *= factor;
+        pressures.push((line, factor, reason));
+    };
+
+    if belligerents.contains(&nation) {
+        scale(BudgetLine::Military, 2.0, "전시 동원");
+    }
+    if own.economy < 30.0 {
+        scale(BudgetLine::Economy, 2.5, "재정 위기");
+    }
+
+    let mut others: Vec<(&Nation, &NationMetrics)> = all_metrics
+        .0
+        .iter()
+        .filter(|(other, metrics)| **other != nation && !metrics.is_destroyed)
+        .collect();
+    others.sort_by_key(|(other, _)| other.name());
+
+    let threatened = others.iter().any(|(other, metrics)| {
+        effective_military(metrics) > effective_military(own) * 1.2
+            && diplomacy.opinion(nation, **other) < 0.0
+    });
+    if threatened {
+        scale(BudgetLine::Military, 1.4, "위협 대응");
+    }
+
+    let prey = others.iter().any(|(other, metrics)| {
+        metrics.military < own.military * 0.6 && diplomacy.opinion(nation, **other) < 10.0
+    });
+    if prey && doctrine == Doctrine::Militarist {
+        scale(BudgetLine::Military, 1.3, "팽창 기회");
+    }
+
+    let trade_partners = others
+        .iter()
+        .filter(|(other, _)| diplomacy.has_treaty(nation, **other, TreatyKind::TradePact))
+        .count();
+    if trade_partners > 0 {
+        scale(BudgetLine::Economy, 1.0 + 0.2 * trade_partners as f32, "교역 확대");
+    }
+
+    if others.iter().any(|(_, metrics)| (metrics.religion - own.religion).abs() > 25.0) {
+        scale(BudgetLine::Religion, 1.4, "신앙 수호");
+    }
+    if others.iter().any(|(_, metrics)| metrics.science > own.science + 15.0) {
+        scale(BudgetLine::Science, 1.3, "기술 격차");
+    }
+    if own.stability < 35.0 {
+        scale(BudgetLine::Culture, 1.4, "민심 수습");
+    }
+    if own.diplomacy < 20.0 && threatened {
+        scale(BudgetLine::Diplomacy, 1.3, "고립 탈피");
+    }
+
+    pressures.sort_by(|a, b| b.1.total_cmp(&a.1));
+    (weights, pressures.into_iter().map(|(line, _, reason)| (line, reason)).collect())
+}
+
+pub fn strategy_system(
+    mut strategies: ResMut<NationStrategies>,
+    all_metrics: Res<AllNationMetrics>,
+    diplomacy: Res<DiplomacyMatrix>,
+    wars: Query<&War>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+    let belligerents: HashSet<Nation> =
+        wars.iter().flat_map(|war| [war.aggressor, war.defender]).collect();
+
+    let mut nations: Vec<Nation> = all_metrics.0.keys().copied().collect();
+    nations.sort_by_key(|nation| nation.name());
+
+    for nation in nations {
+        if all_metrics.0[&nation].is_destroyed {
+            continue;
+        }
+        let previous = strategies.0.get(&nation);
+        if previous.is_some() && !time.tick.is_multiple_of(REVIEW_INTERVAL) {
+            continue;
+        }
+
+        let doctrine = world_meta.nation_doctrine(nation);
+        let (weights, pressures) = assess(
+            nation,
+            doctrine,
+            world_meta.doctrine_budget_weights(doctrine),
+            &all_metrics,
+            &diplomacy,
+            &belligerents,
+        );
+        let total: f32 = weights.values().sum::<f32>().max(f32::EPSILON);
+        let budget: HashMap<BudgetLine, f32> = BudgetLine::ALL
+            .into_iter()
+            .map(|line| (line, weights.get(&line).copied().unwrap_or(0.0) / total))
+            .collect();
+        let focus = BudgetLine::ALL
+            .into_iter()
+            .max_by(|a, b| budget[a].total_cmp(&budget[b]))
+            .unwrap_or(BudgetLine::Economy);
+
+        if let Some(previous) = previous
+            && previous.focus != focus
+        {
+            // Credit the pressure that pushed the new focus, if any did.
+            let reason = pressures
+                .iter()
+                .find(|(line, _)| *line == focus)
+                .map_or("교리 회귀", |(_, reason)| *reason);
+            event_log.push(WorldEvent::policy_shift(
+                time.tick,
+                epoch,
+                season,
+                nation,
+                doctrine,
+                focus,
+                reason,
+            ));
+        }
+
+        strategies.0.insert(
+            nation,
+            NationStrategy {
+                doctrine,
+                budget,
+                focus,
+                revised_at: time.tick,
+            },
+        );
+    }
+}
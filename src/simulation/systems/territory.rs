@@ -1,40 +1,93 @@
 use bevy_ecs::prelude::*;
-use crate::simulation::{AllNationMetrics, Nation, Hex, AxialCoord};
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::simulation::{AllNationMetrics, Nation, Hex, HexGrid, AxialCoord};
 
-fn distance(q1: i32, r1: i32, q2: i32, r2: i32) -> i32 {
-    ((q1 - q2).abs() + (q1 + r1 - q2 - r2).abs() + (r1 - r2).abs()) / 2
+/// Hexes reachable from `nation`'s capital without leaving its territory.
+fn supplied_from(owners: &HashMap<AxialCoord, Nation>, nation: Nation, capital: AxialCoord) -> HashSet<AxialCoord> {
+    let mut supplied = HashSet::new();
+    if owners.get(&capital) != Some(&nation) {
+        return supplied;
+    }
+
+    let mut frontier = VecDeque::from([capital]);
+    supplied.insert(capital);
+    while let Some(coord) = frontier.pop_front() {
+        for neighbor in coord.neighbors() {
+            if owners.get(&neighbor) == Some(&nation) && supplied.insert(neighbor) {
+                frontier.push_back(neighbor);
+            }
+        }
+    }
+    supplied
 }
 
+/// Keeps every nation's land contiguous with its capital, then derives
+/// `NationMetrics.territory` from the hexes actually held.
 pub fn territory_system(
-    metrics: Res<AllNationMetrics>,
+    mut metrics: ResMut<AllNationMetrics>,
+    grid: Res<HexGrid>,
     mut query: Query<(&mut Hex, &AxialCoord)>,
 ) {
-    let capitals = [
-        (Nation::Tera, 0, 0),
-        (Nation::Sora, -4, 0),
-        (Nation::Aqua, 4, 0),
-    ];
+    let mut owners: HashMap<AxialCoord, Nation> =
+        query.iter().map(|(hex, coord)| (*coord, hex.owner)).collect();
 
-    for (mut hex, coord) in query.iter_mut() {
-        let mut max_influence = -1.0;
-        let mut new_owner = hex.owner;
-
-        for (nation, cap_q, cap_r) in &capitals {
-            let territory = metrics.0.get(nation).map_or(0.0, |m| m.territory);
-            let dist = distance(coord.q, coord.r, *cap_q, *cap_r);
-
-            // Avoid division by zero and handle distance 0 case
-            let influence = if dist == 0 {
-                f32::MAX
-            } else {
-                territory / (dist as f32).powi(2)
-            };
-
-            if influence > max_influence {
-                max_influence = influence;
-                new_owner = *nation;
+    let mut capitals: Vec<(Nation, AxialCoord)> =
+        grid.capitals.iter().map(|(nation, coord)| (*nation, *coord)).collect();
+    capitals.sort_by_key(|(nation, _)| nation.name());
+
+    let mut supplied = HashSet::new();
+    for (nation, capital) in &capitals {
+        supplied.extend(supplied_from(&owners, *nation, *capital));
+    }
+
+    // Pockets cut off from their capital go over to whoever surrounds them,
+    // working inwards from the pocket's edge.
+    let mut cut_off: Vec<AxialCoord> =
+        owners.keys().filter(|coord| !supplied.contains(coord)).copied().collect();
+    cut_off.sort_by_key(|coord| (coord.q, coord.r));
+    loop {
+        let mut absorbed = Vec::new();
+        for coord in &cut_off {
+            let mut pressure: HashMap<Nation, u32> = HashMap::new();
+            for neighbor in coord.neighbors() {
+                if supplied.contains(&neighbor) {
+                    *pressure.entry(owners[&neighbor]).or_insert(0) += 1;
+                }
             }
+            if let Some((nation, _)) = pressure
+                .into_iter()
+                .max_by_key(|(nation, count)| (*count, std::cmp::Reverse(nation.name())))
+            {
+                absorbed.push((*coord, nation));
+            }
+        }
+        if absorbed.is_empty() {
+            break;
+        }
+        for (coord, nation) in absorbed {
+            owners.insert(coord, nation);
+            supplied.insert(coord);
+        }
+        cut_off.retain(|coord| !supplied.contains(coord));
+    }
+
+    for (mut hex, coord) in query.iter_mut() {
+        if hex.owner != owners[coord] {
+            hex.owner = owners[coord];
         }
-        hex.owner = new_owner;
     }
-}
\ No newline at end of file
+
+    let total = owners.len().max(1) as f32;
+    let mut held: HashMap<Nation, usize> = HashMap::new();
+    for owner in owners.values() {
+        *held.entry(*owner).or_insert(0) += 1;
+    }
+    for (nation, nation_metrics) in metrics.0.iter_mut() {
+        let count = held.get(nation).copied().unwrap_or(0);
+        nation_metrics.territory = count as f32 / total * 100.0;
+        let has_capital = grid.capitals.get(nation).is_some_and(|capital| owners.get(capital) == Some(nation));
+        if count == 0 || !has_capital {
+            nation_metrics.is_destroyed = true;
+        }
+    }
+}
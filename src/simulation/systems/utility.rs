@@ -0,0 +1,104 @@
+//! Need-driven utility scoring, an alternative to the transition tables.
+
+use rand::rngs::SmallRng;
+use rand::Rng;
+
+use crate::simulation::{BehaviorState, DecisionContext, DecisionStrategy, weighted_choice};
+
+use super::economy::GATHER_CARRY_LIMIT;
+
+/// Purse size at which an NPC stops feeling poor.
+const COMFORTABLE_CURRENCY: f32 = 120.0;
+/// Scores are squared before the draw so the dominant need usually wins.
+const SCORE_SHARPNESS: i32 = 2;
+/// Random jitter so NPCs with identical needs do not move in lockstep.
+const SCORE_NOISE: f32 = 0.1;
+
+/// Needs on a 0..1 scale, 1 being the most pressing.
+struct Needs {
+    fatigue: f32,
+    injury: f32,
+    poverty: f32,
+    cargo: f32,
+}
+
+impl Needs {
+    fn from_context(context: &DecisionContext) -> Self {
+        Self {
+            fatigue: (1.0 - context.attributes.stamina / 100.0).clamp(0.0, 1.0),
+            injury: (1.0 - context.attributes.health / 100.0).clamp(0.0, 1.0),
+            poverty: (1.0 - context.inventory.currency / context.price_level / COMFORTABLE_CURRENCY)
+                .clamp(0.0, 1.0),
+            cargo: (context.inventory.total_quantity() as f32 / GATHER_CARRY_LIMIT as f32)
+                .clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Scores every behaviour against stamina, health, purse and pack, then lets
+/// personality and the situational modifiers tilt the result. A poor trader
+/// turns to hunting and a wounded hunter goes home to rest.
+pub struct UtilityStrategy;
+
+impl UtilityStrategy {
+    fn score(context: &DecisionContext, needs: &Needs, state: BehaviorState) -> f32 {
+        let personality = context.personality;
+        let vigour = 1.0 - needs.fatigue.max(needs.injury);
+
+        let base = match state {
+            BehaviorState::Rest => {
+                needs.fatigue * 1.2 + needs.injury * 1.5 + personality.cautious * 0.15
+            }
+            BehaviorState::Trade => {
+                needs.cargo * 1.1 + personality.social * 0.35 + (1.0 - needs.poverty) * 0.1
+            }
+            BehaviorState::Hunt => {
+                (needs.poverty * 0.8 + personality.aggressive * 0.6) * vigour
+                    - needs.injury * 0.6
+            }
+            BehaviorState::Gather => {
+                ((1.0 - needs.cargo) * 0.5 + needs.poverty * 0.4 + personality.cautious * 0.1)
+                    * vigour
+            }
+            BehaviorState::Explore => {
+                (personality.curious * 0.7 + (1.0 - needs.cargo) * 0.15) * vigour
+            }
+            BehaviorState::Idle => 0.15 + personality.social * 0.15,
+            BehaviorState::Pray => {
+                needs.injury * 0.4 + needs.fatigue * 0.2 + personality.cautious * 0.15
+            }
+            BehaviorState::Raid => {
+                (needs.poverty * 0.6 + personality.aggressive * 0.8 - personality.cautious * 0.4)
+                    * vigour
+                    - needs.injury * 0.8
+            }
+            BehaviorState::Patrol => {
+                (personality.cautious * 0.25 + personality.aggressive * 0.2) * vigour
+            }
+            BehaviorState::Craft => {
+                (needs.cargo * 0.7 + personality.cautious * 0.15) * vigour
+            }
+            BehaviorState::Migrate => {
+                (0.05 + personality.curious * 0.15) * vigour * (1.0 - needs.cargo)
+            }
+        };
+
+        base.max(0.01) * context.situational_modifier(state)
+    }
+}
+
+impl DecisionStrategy for UtilityStrategy {
+    fn decide(&self, context: &DecisionContext, rng: &mut SmallRng) -> BehaviorState {
+        let needs = Needs::from_context(context);
+        let scored: Vec<(BehaviorState, f32)> = BehaviorState::ALL
+            .iter()
+            .map(|state| {
+                let noise = 1.0 + rng.gen_range(-SCORE_NOISE..SCORE_NOISE);
+                let score = Self::score(context, &needs, *state) * noise;
+                (*state, score.max(0.01).powi(SCORE_SHARPNESS))
+            })
+            .collect();
+
+        weighted_choice(&scored, rng).unwrap_or(context.current)
+    }
+}
@@ -0,0 +1,197 @@
+//! NPC health and stamina upkeep, injuries, and death.
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    Attributes, Behavior, BehaviorState, EventActor, HarmCause, HexGrid, Identity, Injury, Inventory,
+    ActiveShocks, NationTreasuries, Personality, Position, RegionalWeather, WorldEvent, WorldEventLog, WorldMetadata, WorldTime,
+};
+
+const MAX_HEALTH: f32 = 100.0;
+const MAX_STAMINA: f32 = 100.0;
+
+fn stamina_delta(state: BehaviorState) -> f32 {
+    match state {
+        BehaviorState::Explore => -3.0,
+        BehaviorState::Gather => -2.5,
+        BehaviorState::Hunt => -4.0,
+        BehaviorState::Trade => 0.0,
+        BehaviorState::Idle => 2.0,
+        BehaviorState::Rest => 6.0,
+        BehaviorState::Pray => 3.0,
+        BehaviorState::Raid => -4.5,
+        BehaviorState::Patrol => -2.0,
+        BehaviorState::Craft => -1.5,
+        BehaviorState::Migrate => -3.5,
+    }
+}
+
+fn health_recovery(state: BehaviorState) -> f32 {
+    match state {
+        BehaviorState::Rest => 4.0,
+        BehaviorState::Pray => 2.0,
+        BehaviorState::Idle => 1.0,
+        _ => 0.0,
+    }
+}
+
+fn recover(value: f32, amount: f32, max: f32) -> f32 {
+    if value >= max { value } else { (value + amount).min(max) }
+}
+
+pub fn vitals_system(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Identity, &Position, &Personality, &Behavior, &mut Attributes)>,
+    shocks: Res<ActiveShocks>,
+    weather: Res<RegionalWeather>,
+    grid: Res<HexGrid>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+) {
+    for (entity, identity, position, personality, behavior, mut attributes) in &mut query {
+        let mut rng = SmallRng::seed_from_u64(
+            time.tick
+                .wrapping_mul(313)
+                .wrapping_add(identity.id * 11)
+                .wrapping_mul(71),
+        );
+        let state = behavior.state;
+        let skies = weather.weather(grid.coord_at(position.x, position.y));
+        let mut damage = 0.0;
+        let mut cause = None;
+
+        // 1. Stamina drains with exertion, the faster the worse the going;
+        //    an exhausted NPC starts losing health.
+        let delta = stamina_delta(state);
+        if delta < 0.0 {
+            attributes.stamina += delta / skies.mobility();
+            if attributes.stamina < 0.0 {
+                damage += 1.0;
+                cause = Some(HarmCause::Exhaustion);
+                attributes.stamina = 0.0;
+            }
+        } else {
+            attributes.stamina = recover(attributes.stamina, delta, MAX_STAMINA);
+        }
+
+        // 2. Working the land exposes NPCs to the biome's hazards.
+        if matches!(
+            state,
+            BehaviorState::Explore
+                | BehaviorState::Gather
+                | BehaviorState::Hunt
+                | BehaviorState::Patrol
+                | BehaviorState::Migrate
+        ) {
+            let hazard_chance = 0.02 * world_meta.biome_risk_factor(position.biome);
+            if rng.gen_bool(hazard_chance.clamp(0.0, 1.0) as f64) {
+                damage += rng.gen_range(5.0..15.0);
+                cause = Some(HarmCause::Hazard);
+            }
+        }
+
+        // 3. Hunting wounds the careless.
+        if matches!(state, BehaviorState::Hunt) {
+            let wound_chance = 0.1 * (1.0 - personality.cautious * 0.5);
+            if rng.gen_bool(wound_chance.clamp(0.0, 1.0) as f64) {
+                damage += rng.gen_range(3.0..12.0);
+                cause = Some(HarmCause::Hunting);
+            }
+        }
+
+        match cause {
+            Some(cause) => {
+                attributes.health -= damage;
+                commands.entity(entity).insert(Injury { cause });
+            }
+            None => {
+                attributes.health =
+                    recover(attributes.health, health_recovery(state), MAX_HEALTH);
+            }
+        }
+
+        // 4. Raiders, harsh digs and the like wear on everyone within their
+        //    reach; sickness itself is left to the disease system.
+        let loss = shocks.health_loss(identity.nation, position.biome, &world_meta);
+        if loss > 0.0 {
+            attributes.health -= loss;
+            if attributes.health <= 0.0 {
+                commands.entity(entity).insert(Injury { cause: HarmCause::Calamity });
+            }
+        }
+    }
+}
+
+/// Despawns NPCs whose health reached zero. Their belongings pass to a living
+/// member of the same faction (preferring the same nation); otherwise their
+/// goods are lost and their coin falls to their nation's coffers.
+#[allow(clippy::type_complexity)]
+pub fn mortality_system(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &Identity,
+        &Position,
+        &Behavior,
+        &Attributes,
+        &mut Inventory,
+        Option<&Injury>,
+    )>,
+    mut treasuries: ResMut<NationTreasuries>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+    mut event_log: ResMut<WorldEventLog>,
+) {
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+
+    let fallen: Vec<Entity> = query
+        .iter()
+        .filter(|(_, _, _, _, attributes, _, _)| attributes.health <= 0.0)
+        .map(|(entity, ..)| entity)
+        .collect();
+
+    for entity in fallen {
+        let Ok((_, identity, position, behavior, _, inventory, injury)) = query.get(entity) else {
+            continue;
+        };
+        let actor = EventActor::new(identity, position, behavior.state, &world_meta);
+        let cause = injury.map_or(HarmCause::Exhaustion, |injury| injury.cause);
+        let estate = inventory.clone();
+        let (faction, nation) = (identity.faction, identity.nation);
+
+        let heir = query
+            .iter()
+            .filter(|(other, other_identity, _, _, attributes, _, _)| {
+                *other != entity
+                    && other_identity.faction == faction
+                    && attributes.health > 0.0
+            })
+            .max_by_key(|(_, other_identity, ..)| other_identity.nation == nation)
+            .map(|(other, other_identity, ..)| (other, other_identity.nation, other_identity.hangul_name.clone()));
+
+        if let Some((heir_entity, heir_nation, _)) = &heir
+            && let Ok((.., mut heir_inventory, _)) = query.get_mut(*heir_entity)
+        {
+            for stack in estate.items {
+                heir_inventory.add(stack.item, stack.quantity);
+            }
+            // An heir abroad inherits the estate changed into their own coin.
+            heir_inventory.currency += treasuries.convert(estate.currency, nation, *heir_nation);
+        } else {
+            treasuries.treasury_mut(nation).collect(estate.currency);
+        }
+
+        commands.entity(entity).despawn();
+
+        event_log.push(WorldEvent::death(
+            time.tick,
+            epoch,
+            season,
+            actor,
+            cause,
+            heir.map(|(_, _, name)| name),
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 use bevy_ecs::prelude::*;
 use crate::simulation::{
-    AllNationMetrics, Nation, WorldTime, Hex,
+    AllNationMetrics, Army, CasusBelli, DiplomacyMatrix, TreatyKind, Nation, NationMetrics, NationResearch, NationTreasuries, PeaceTerms, War, WarGoal, WarLedger, pair_key,
+    WorldEvent, WorldEventLog, WorldMetadata, WorldTime, Hex, HexGrid,
     components::{InCombat, Combatants},
     grid::AxialCoord,
 };
@@ -8,146 +9,482 @@ use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use std::collections::{HashMap, HashSet};
 
-struct BattleRequest {
-    nation_a: Nation,
-    nation_b: Nation,
+/// Chance per tick that a pair of nations at peace goes to war, before diplomacy dampens it.
+const DECLARATION_CHANCE: f32 = 0.004;
+/// An unavenged defeat makes a new war this many times likelier.
+const GRIEVANCE_MULTIPLIER: f32 = 3.0;
+/// A non-aggression pact makes a declaration this much less likely.
+const NON_AGGRESSION_DAMPING: f32 = 0.25;
+/// Chance per tick that an army standing on the front gives battle.
+const BATTLE_CHANCE: f64 = 0.35;
+/// How far from its capital a nation can hold captured hexes, before military bonus.
+const BASE_SUPPLY_RANGE: i32 = 4;
+/// Field strength is worth this much less than a point of military spending.
+const FIELD_STRENGTH_SCALE: f32 = 5.0;
+/// Exhaustion both sides accrue simply by staying at war, per tick.
+const WAR_WEARINESS: f32 = 0.4;
+const MAX_WAR_SCORE: f32 = 100.0;
+/// War score at which the losing side capitulates.
+const DECISIVE_SCORE: f32 = 60.0;
+/// Below this score a negotiated peace changes nothing.
+const WHITE_PEACE_MARGIN: f32 = 20.0;
+const MAX_EXHAUSTION: f32 = 100.0;
+/// Wars older than this end in whatever peace the score allows.
+const MAX_WAR_TICKS: u64 = 200;
+const TRUCE_TICKS: u64 = 120;
+/// Coin a nation forced to pay tribute owes per point of war score.
+const INDEMNITY_PER_SCORE: f32 = 3.0;
+const FRONT_COMBAT_TICKS: u32 = 3;
+
+// Science acts as a multiplier for military strength.
+pub(crate) fn effective_military(metrics: &NationMetrics) -> f32 {
+    metrics.military * (1.0 + metrics.science / 100.0)
 }
 
-// System to clean up finished combat encounters
-pub fn combat_cleanup_system(mut commands: Commands, mut query: Query<(Entity, &mut InCombat)>) {
-    for (entity, mut in_combat) in query.iter_mut() {
-        in_combat.ticks_remaining = in_combat.ticks_remaining.saturating_sub(1);
-        if in_combat.ticks_remaining == 0 {
-            commands.entity(entity).remove::<InCombat>();
-            commands.entity(entity).remove::<Combatants>();
+// Higher diplomacy, culture, and religion reduce the chance of war.
+fn peace_factor(a: &NationMetrics, b: &NationMetrics) -> f32 {
+    (a.diplomacy + b.diplomacy) + (a.culture + b.culture) * 0.5 + (a.religion + b.religion) * 0.5
+}
+
+pub(crate) fn supply_range(metrics: &NationMetrics) -> i32 {
+    BASE_SUPPLY_RANGE + (metrics.military / 20.0) as i32
+}
+
+/// Whether `conqueror` can take and hold `coord` from `holder`: the hex must
+/// border the conqueror's land, lie within its supply range, and not be a
+/// hex the holder still has an army standing on.
+fn can_capture(
+    owners: &HashMap<AxialCoord, Nation>,
+    grid: &HexGrid,
+    all_metrics: &AllNationMetrics,
+    garrisons: &HashSet<(Nation, AxialCoord)>,
+    conqueror: Nation,
+    holder: Nation,
+    coord: AxialCoord,
+) -> bool {
+    if owners.get(&coord) != Some(&holder) {
+        return false;
+    }
+    if !coord.neighbors().iter().any(|neighbor| owners.get(neighbor) == Some(&conqueror)) {
+        return false;
+    }
+    let Some(conqueror_metrics) = all_metrics.0.get(&conqueror) else {
+        return false;
+    };
+    let in_supply = grid
+        .capitals
+        .get(&conqueror)
+        .is_some_and(|capital| capital.distance(&coord) <= supply_range(conqueror_metrics));
+    in_supply && !garrisons.contains(&(holder, coord))
+}
+
+/// Hands up to `count` of the loser's front hexes to the victor, nearest to
+/// the victor's capital first. Returns how many changed hands.
+fn cede_hexes(
+    owners: &mut HashMap<AxialCoord, Nation>,
+    grid: &HexGrid,
+    all_metrics: &AllNationMetrics,
+    garrisons: &HashSet<(Nation, AxialCoord)>,
+    victor: Nation,
+    loser: Nation,
+    count: usize,
+) -> usize {
+    let Some(capital) = grid.capitals.get(&victor).copied() else {
+        return 0;
+    };
+    let mut ceded = 0;
+    while ceded < count {
+        let next = front_line(owners, victor, loser)
+            .into_iter()
+            .filter(|coord| can_capture(owners, grid, all_metrics, garrisons, victor, loser, *coord))
+            .min_by_key(|coord| (capital.distance(coord), coord.q, coord.r));
+        let Some(coord) = next else {
+            break;
+        };
+        owners.insert(coord, victor);
+        ceded += 1;
+    }
+    ceded
+}
+
+/// Picks the aggressor and its pretext for a war between `a` and `b`.
+fn choose_casus_belli(
+    (a, metrics_a): (Nation, &NationMetrics),
+    (b, metrics_b): (Nation, &NationMetrics),
+    ledger: &WarLedger,
+) -> (Nation, Nation, CasusBelli) {
+    if ledger.has_grievance(a, b) {
+        return (a, b, CasusBelli::Revenge);
+    }
+    if ledger.has_grievance(b, a) {
+        return (b, a, CasusBelli::Revenge);
+    }
+
+    let ((aggressor, attacker), (defender, target)) =
+        if effective_military(metrics_a) >= effective_military(metrics_b) {
+            ((a, metrics_a), (b, metrics_b))
+        } else {
+            ((b, metrics_b), (a, metrics_a))
+        };
+
+    let casus_belli = if (attacker.religion - target.religion).abs() > 25.0 {
+        CasusBelli::HolyWar
+    } else if target.economy > attacker.economy + 15.0 {
+        CasusBelli::TradeRivalry
+    } else if attacker.territory < target.territory {
+        CasusBelli::BorderDispute
+    } else {
+        CasusBelli::Expansion
+    };
+    (aggressor, defender, casus_belli)
+}
+
+pub(crate) fn war_goal(casus_belli: CasusBelli) -> WarGoal {
+    match casus_belli {
+        CasusBelli::BorderDispute | CasusBelli::Expansion | CasusBelli::Independence => WarGoal::Conquest,
+        CasusBelli::TradeRivalry => WarGoal::Tribute,
+        CasusBelli::HolyWar | CasusBelli::Revenge | CasusBelli::AllyCall => WarGoal::Humiliation,
+    }
+}
+
+/// Hexes of either side that touch the other's territory.
+fn front_line(owners: &HashMap<AxialCoord, Nation>, a: Nation, b: Nation) -> Vec<AxialCoord> {
+    let mut front: Vec<AxialCoord> = owners
+        .iter()
+        .filter(|(coord, owner)| {
+            let enemy = if **owner == a {
+                b
+            } else if **owner == b {
+                a
+            } else {
+                return false;
+            };
+            coord
+                .neighbors()
+                .iter()
+                .any(|neighbor| owners.get(neighbor) == Some(&enemy))
+        })
+        .map(|(coord, _)| *coord)
+        .collect();
+    front.sort_by_key(|coord| (coord.q, coord.r));
+    front
+}
+
+/// Whether the war is over, and if so who won and on what terms.
+fn negotiate(war: &War, tick: u64, destroyed: Option<Nation>) -> Option<(Option<Nation>, PeaceTerms)> {
+    let decisive = war.score.abs() >= DECISIVE_SCORE;
+    let exhausted = war.aggressor_exhaustion >= MAX_EXHAUSTION
+        || war.defender_exhaustion >= MAX_EXHAUSTION;
+    let stale = tick.saturating_sub(war.declared_at) >= MAX_WAR_TICKS;
+    if destroyed.is_none() && !decisive && !exhausted && !stale {
+        return None;
+    }
+
+    let victor = match destroyed {
+        Some(fallen) => war.opponent_of(fallen),
+        None if war.score.abs() < WHITE_PEACE_MARGIN => return Some((None, PeaceTerms::WhitePeace)),
+        None if war.score > 0.0 => war.aggressor,
+        None => war.defender,
+    };
+
+    // A victorious defender extracts reparations; an aggressor gets what it came for.
+    let terms = if victor == war.defender {
+        PeaceTerms::Tribute
+    } else {
+        match war.goal {
+            WarGoal::Conquest => PeaceTerms::Concession,
+            WarGoal::Tribute => PeaceTerms::Tribute,
+            WarGoal::Humiliation => PeaceTerms::Humiliation,
+        }
+    };
+    Some((Some(victor), terms))
+}
+
+fn apply_terms(
+    all_metrics: &mut AllNationMetrics,
+    treasuries: &mut NationTreasuries,
+    victor: Nation,
+    loser: Nation,
+    terms: PeaceTerms,
+    score: f32,
+) {
+    // The indemnity is paid from the loser's coffers, as far as they reach.
+    if terms == PeaceTerms::Tribute {
+        treasuries.transfer(loser, victor, score.abs() * INDEMNITY_PER_SCORE);
+    }
+
+    if terms == PeaceTerms::Humiliation {
+        if let Some(metrics) = all_metrics.0.get_mut(&loser) {
+            metrics.diplomacy = (metrics.diplomacy - 10.0).max(0.0);
+            metrics.culture = (metrics.culture - 5.0).max(0.0);
+        }
+        if let Some(metrics) = all_metrics.0.get_mut(&victor) {
+            metrics.culture = (metrics.culture + 5.0).min(100.0);
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn warfare_system(
     mut commands: Commands,
     mut all_metrics: ResMut<AllNationMetrics>,
+    mut treasuries: ResMut<NationTreasuries>,
+    mut ledger: ResMut<WarLedger>,
+    mut wars: Query<(Entity, &mut War)>,
     time: Res<WorldTime>,
-    mut event_log: ResMut<crate::simulation::WorldEventLog>,
-    world_meta: Res<crate::simulation::WorldMetadata>,
-    hex_query: Query<(Entity, &Hex, &AxialCoord)>,
+    mut event_log: ResMut<WorldEventLog>,
+    world_meta: Res<WorldMetadata>,
+    grid: Res<HexGrid>,
+    mut hex_query: Query<(Entity, &mut Hex, &AxialCoord)>,
+    mut armies: Query<&mut Army>,
+    diplomacy: Res<DiplomacyMatrix>,
+    research: Res<NationResearch>,
 ) {
     let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(257));
-    let mut battle_requests = Vec::new();
+    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
 
-    // 1. Identify potential battles
-    let nations: Vec<Nation> = all_metrics.0.keys().cloned().collect();
-    for i in 0..nations.len() {
-        for j in (i + 1)..nations.len() {
-            let nation_a_key = nations[i];
-            let nation_b_key = nations[j];
+    let mut owners: HashMap<AxialCoord, Nation> =
+        hex_query.iter().map(|(_, hex, coord)| (*coord, hex.owner)).collect();
+    let hex_entities: HashMap<AxialCoord, Entity> =
+        hex_query.iter().map(|(entity, _, coord)| (*coord, entity)).collect();
+    let garrisons: HashSet<(Nation, AxialCoord)> =
+        armies.iter().map(|army| (army.nation, army.position)).collect();
 
-            let (metrics_a, metrics_b) = (
-                all_metrics.0.get(&nation_a_key).unwrap(),
-                all_metrics.0.get(&nation_b_key).unwrap(),
-            );
+    // Field strength a nation can bring to bear on a hex from armies on or next to it.
+    let local_strength = |armies: &Query<&mut Army>, nation: Nation, coord: AxialCoord| -> f32 {
+        armies
+            .iter()
+            .filter(|army| army.nation == nation && army.position.distance(&coord) <= 1)
+            .map(|army| army.strength())
+            .sum()
+    };
 
-            if metrics_a.is_destroyed || metrics_b.is_destroyed || metrics_a.military <= 1.0 || metrics_b.military <= 1.0 {
-                continue;
-            }
+    // 1. Ongoing campaigns: the front moves, battles shift the score, and
+    //    exhausted or beaten nations sue for peace.
+    let mut at_war: HashSet<(Nation, Nation)> = HashSet::new();
+    let mut campaigns: Vec<(Entity, Mut<War>)> = wars.iter_mut().collect();
+    campaigns.sort_by_key(|(_, war)| war.id);
 
-            // --- War Prevention Logic ---
-            // Higher diplomacy, culture, and religion reduce the chance of war.
-            let peace_factor = (metrics_a.diplomacy + metrics_b.diplomacy)
-                + (metrics_a.culture + metrics_b.culture) * 0.5
-                + (metrics_a.religion + metrics_b.religion) * 0.5;
+    for (entity, mut war) in campaigns {
+        war.front = front_line(&owners, war.aggressor, war.defender);
+        war.aggressor_exhaustion += WAR_WEARINESS;
+        war.defender_exhaustion += WAR_WEARINESS;
 
-            // Base probability of war is 20%, reduced by the peace factor.
-            let war_prob = (0.2 - peace_factor * 0.001).max(0.01);
+        let destroyed = [war.aggressor, war.defender]
+            .into_iter()
+            .find(|nation| all_metrics.0.get(nation).is_none_or(|m| m.is_destroyed));
 
-            if rng.gen_bool(war_prob as f64) {
-                battle_requests.push(BattleRequest { nation_a: nation_a_key, nation_b: nation_b_key });
-            }
-        }
-    }
+        // Battles are fought where an army stands at the front: the side
+        // with momentum strikes first at an enemy hex its armies can reach.
+        let momentum = if war.score >= 0.0 { war.aggressor } else { war.defender };
+        let strike = [momentum, war.opponent_of(momentum)].into_iter().find_map(|attacker| {
+            let enemy = war.opponent_of(attacker);
+            let targets: Vec<AxialCoord> = war
+                .front
+                .iter()
+                .filter(|coord| owners.get(coord) == Some(&enemy))
+                .filter(|coord| local_strength(&armies, attacker, **coord) > 0.0)
+                .copied()
+                .collect();
+            (!targets.is_empty()).then_some((attacker, targets))
+        });
 
-    let (epoch, season) = world_meta.epoch_for_tick(time.tick);
+        if destroyed.is_none()
+            && let Some((attacker, targets)) = strike
+            && rng.gen_bool(BATTLE_CHANCE)
+        {
+            let defender = war.opponent_of(attacker);
+            let contested = targets[rng.gen_range(0..targets.len())];
+            let (winner, loser, margin) = {
+                let attack = (effective_military(&all_metrics.0[&attacker])
+                    + local_strength(&armies, attacker, contested) / FIELD_STRENGTH_SCALE)
+                    * research.army_multiplier(attacker, &world_meta);
+                let defense = (effective_military(&all_metrics.0[&defender])
+                    + local_strength(&armies, defender, contested) / FIELD_STRENGTH_SCALE)
+                    * research.army_multiplier(defender, &world_meta);
+                let roll_a = rng.gen_range(0.0..1.0) * attack;
+                let roll_d = rng.gen_range(0.0..1.0) * defense;
+                let margin = (roll_a - roll_d).abs() / roll_a.max(roll_d).max(1.0);
+                if roll_a > roll_d {
+                    (attacker, defender, margin)
+                } else {
+                    (defender, attacker, margin)
+                }
+            };
 
-    // 2. Process battles
-    for request in battle_requests {
-        let (winner, loser) = {
-            let metrics_a = all_metrics.0.get(&request.nation_a).unwrap();
-            let metrics_b = all_metrics.0.get(&request.nation_b).unwrap();
+            // The winner takes the hex if it was the loser's and can be held.
+            let captured =
+                can_capture(&owners, &grid, &all_metrics, &garrisons, winner, loser, contested);
+            if captured {
+                owners.insert(contested, winner);
+            }
 
-            // --- Battle Outcome Logic ---
-            // Science acts as a multiplier for military strength.
-            let military_a_effective = metrics_a.military * (1.0 + metrics_a.science / 100.0);
-            let military_b_effective = metrics_b.military * (1.0 + metrics_b.science / 100.0);
+            let swing = 4.0 + margin * 6.0 + if captured { 2.0 } else { 0.0 };
+            war.score = if winner == war.aggressor {
+                (war.score + swing).min(MAX_WAR_SCORE)
+            } else {
+                (war.score - swing).max(-MAX_WAR_SCORE)
+            };
+            war.battles += 1;
+            *war.exhaustion_mut(winner) += 1.0;
+            *war.exhaustion_mut(loser) += 3.0;
 
-            let roll_a = rng.gen_range(0.0..1.0) * military_a_effective;
-            let roll_b = rng.gen_range(0.0..1.0) * military_b_effective;
+            if let Some(winner_metrics) = all_metrics.0.get_mut(&winner) {
+                winner_metrics.military = (winner_metrics.military - 1.5).max(0.0);
+            }
+            if let Some(loser_metrics) = all_metrics.0.get_mut(&loser) {
+                loser_metrics.military = (loser_metrics.military - 2.5).max(0.0);
+            }
 
-            if roll_a > roll_b {
-                (request.nation_a, request.nation_b)
-            } else {
-                (request.nation_b, request.nation_a)
+            for mut army in armies.iter_mut() {
+                if army.position.distance(&contested) > 1 || !war.involves(army.nation) {
+                    continue;
+                }
+                if army.nation == winner {
+                    army.size *= 0.95;
+                    army.morale = (army.morale + 3.0).min(100.0);
+                } else {
+                    army.size *= 0.85;
+                    army.morale = (army.morale - 15.0).max(0.0);
+                }
             }
-        };
 
-        // Update metrics
-        let territory_change = 0.5;
-        let military_loss = 2.0;
+            if let Some(hex) = hex_entities.get(&contested) {
+                commands.entity(*hex).insert((
+                    InCombat { ticks_remaining: FRONT_COMBAT_TICKS },
+                    Combatants { nation_a: winner, nation_b: loser },
+                ));
+            }
 
-        if let Some(winner_metrics) = all_metrics.0.get_mut(&winner) {
-            winner_metrics.territory += territory_change;
-            winner_metrics.military -= military_loss;
-            winner_metrics.territory = winner_metrics.territory.max(0.0);
-            winner_metrics.military = winner_metrics.military.max(0.0);
+            event_log.push(WorldEvent::warfare(
+                time.tick,
+                epoch,
+                season,
+                war.id,
+                winner,
+                loser,
+                contested,
+                captured,
+                war.score_for(winner),
+            ));
         }
 
-        if let Some(loser_metrics) = all_metrics.0.get_mut(&loser) {
-            loser_metrics.territory -= territory_change;
-            loser_metrics.military -= military_loss;
-            loser_metrics.territory = loser_metrics.territory.max(0.0);
-            loser_metrics.military = loser_metrics.military.max(0.0);
+        let Some((victor, terms)) = negotiate(&war, time.tick, destroyed) else {
+            at_war.insert(pair_key(war.aggressor, war.defender));
+            continue;
+        };
 
-            if loser_metrics.territory <= 0.0 {
-                loser_metrics.is_destroyed = true;
+        if let Some(victor) = victor {
+            let loser = war.opponent_of(victor);
+            if terms == PeaceTerms::Concession {
+                let count = (war.score.abs() / 20.0).ceil() as usize;
+                cede_hexes(&mut owners, &grid, &all_metrics, &garrisons, victor, loser, count);
             }
+            apply_terms(&mut all_metrics, &mut treasuries, victor, loser, terms, war.score);
+            ledger.add_grievance(loser, victor);
+            ledger.settle_grievance(victor, loser);
         }
+        ledger.sign_truce(war.aggressor, war.defender, time.tick + TRUCE_TICKS);
+        commands.entity(entity).despawn();
 
-        // Log the event
-        event_log.push(crate::simulation::WorldEvent::warfare(
+        event_log.push(WorldEvent::war_ended(
             time.tick,
             epoch,
             season,
-            winner,
-            loser,
-            territory_change,
+            war.id,
+            war.aggressor,
+            war.defender,
+            victor,
+            terms,
+            time.tick.saturating_sub(war.declared_at),
         ));
+    }
 
-        // 3. Find border hexes and mark them as in combat
-        let mut border_hex_entities = HashSet::new();
-        let nation_hexes: HashMap<Nation, HashSet<AxialCoord>> = {
-            let mut map: HashMap<Nation, HashSet<AxialCoord>> = HashMap::new();
-            for (_, hex, coord) in hex_query.iter() {
-                map.entry(hex.owner).or_default().insert(*coord);
-            }
-            map
-        };
+    // 2. Nations at peace may declare war, unless bound by a truce.
+    let mut nations: Vec<Nation> = all_metrics.0.keys().cloned().collect();
+    nations.sort_by_key(|nation| nation.name());
+    for i in 0..nations.len() {
+        for j in (i + 1)..nations.len() {
+            let (a, b) = (nations[i], nations[j]);
+            let (metrics_a, metrics_b) = (&all_metrics.0[&a], &all_metrics.0[&b]);
 
-        let loser_hexes = nation_hexes.get(&loser).cloned().unwrap_or_default();
+            if metrics_a.is_destroyed || metrics_b.is_destroyed || metrics_a.military <= 1.0 || metrics_b.military <= 1.0 {
+                continue;
+            }
+            if at_war.contains(&pair_key(a, b)) || ledger.in_truce(a, b, time.tick) {
+                continue;
+            }
+            // Allies and a vassal with its overlord do not fight each other.
+            if diplomacy.has_treaty(a, b, TreatyKind::Alliance) || diplomacy.has_treaty(a, b, TreatyKind::Vassalage) {
+                continue;
+            }
 
-        for (entity, hex, coord) in hex_query.iter() {
-            if hex.owner == winner {
-                for neighbor_coord in coord.neighbors() {
-                    if loser_hexes.contains(&neighbor_coord) {
-                        border_hex_entities.insert(entity);
-                    }
-                }
+            let mut chance =
+                DECLARATION_CHANCE * (1.0 - peace_factor(metrics_a, metrics_b) / 600.0).max(0.1);
+            if ledger.has_grievance(a, b) || ledger.has_grievance(b, a) {
+                chance *= GRIEVANCE_MULTIPLIER;
+            }
+            if diplomacy.has_treaty(a, b, TreatyKind::NonAggression) {
+                chance *= NON_AGGRESSION_DAMPING;
+            }
+            chance *= 1.0 - diplomacy.opinion(a, b) / 150.0;
+            if rng.gen_range(0.0..1.0) >= chance {
+                continue;
+            }
+            // Nations that share no border have nothing to fight over.
+            let front = front_line(&owners, a, b);
+            if front.is_empty() {
+                continue;
             }
-        }
 
-        for entity in border_hex_entities {
-            commands.entity(entity).insert((
-                InCombat { ticks_remaining: 5 },
-                Combatants { nation_a: winner, nation_b: loser },
+            let (aggressor, defender, casus_belli) =
+                choose_casus_belli((a, metrics_a), (b, metrics_b), &ledger);
+            let goal = war_goal(casus_belli);
+            let id = ledger.allocate_id();
+            commands.spawn(War {
+                id,
+                aggressor,
+                defender,
+                casus_belli,
+                goal,
+                declared_at: time.tick,
+                score: 0.0,
+                aggressor_exhaustion: 0.0,
+                defender_exhaustion: 0.0,
+                battles: 0,
+                front,
+            });
+            at_war.insert(pair_key(a, b));
+
+            event_log.push(WorldEvent::war_declared(
+                time.tick,
+                epoch,
+                season,
+                id,
+                aggressor,
+                defender,
+                casus_belli,
+                goal,
             ));
         }
     }
+
+    for (_, mut hex, coord) in hex_query.iter_mut() {
+        if let Some(owner) = owners.get(coord)
+            && hex.owner != *owner
+        {
+            hex.owner = *owner;
+        }
+    }
+}
+
+// System to clean up finished combat encounters
+pub fn combat_cleanup_system(mut commands: Commands, mut query: Query<(Entity, &mut InCombat)>) {
+    for (entity, mut in_combat) in query.iter_mut() {
+        in_combat.ticks_remaining = in_combat.ticks_remaining.saturating_sub(1);
+        if in_combat.ticks_remaining == 0 {
+            commands.entity(entity).remove::<InCombat>();
+            commands.entity(entity).remove::<Combatants>();
+        }
+    }
 }
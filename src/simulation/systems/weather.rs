@@ -0,0 +1,62 @@
+//! Weather over each region: when a spell runs out, the next is drawn from
+//! the climate of the biome most of the region's hexes share, as the
+//! season bends it.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::simulation::{
+    AxialCoord, Biome, Hex, RegionalWeather, Weather, WeatherSpell, WorldMetadata, WorldTime, weather_region,
+};
+
+/// Shortest and longest spell of weather, in ticks.
+const SPELL_TICKS: (u64, u64) = (12, 36);
+
+pub fn weather_system(
+    mut weather: ResMut<RegionalWeather>,
+    hexes: Query<(&Hex, &AxialCoord)>,
+    world_meta: Res<WorldMetadata>,
+    time: Res<WorldTime>,
+) {
+    let (_, season) = world_meta.epoch_for_tick(time.tick);
+    let mut rng = SmallRng::seed_from_u64(time.tick.wrapping_mul(977).wrapping_add(71));
+
+    let mut terrain: HashMap<AxialCoord, HashMap<Biome, u32>> = HashMap::new();
+    for (hex, coord) in &hexes {
+        *terrain.entry(weather_region(*coord)).or_default().entry(hex.biome).or_default() += 1;
+    }
+    let mut regions: Vec<(AxialCoord, Biome)> = terrain
+        .into_iter()
+        .filter_map(|(region, biomes)| {
+            Biome::ALL
+                .into_iter()
+                .max_by_key(|biome| biomes.get(biome).copied().unwrap_or(0))
+                .map(|climate| (region, climate))
+        })
+        .collect();
+    regions.sort_by_key(|(region, _)| (region.q, region.r));
+
+    for (region, climate) in regions {
+        if weather.0.get(&region).is_some_and(|spell| spell.ends_at > time.tick) {
+            continue;
+        }
+        let next = Weather::ALL
+            .choose_weighted(&mut rng, |kind| world_meta.weather_weight(climate, season, *kind))
+            .copied()
+            .unwrap_or(Weather::Clear);
+        weather.0.insert(
+            region,
+            WeatherSpell {
+                region,
+                climate,
+                weather: next,
+                started_at: time.tick,
+                ends_at: time.tick + rng.gen_range(SPELL_TICKS.0..=SPELL_TICKS.1),
+            },
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! State finances: each nation's coffers, the taxes that fill them, and the
+//! upkeep, wages and debts that pay coin back out to its people.
+//!
+//! Coin is conserved between purses and coffers: taxes and provisions move
+//! it from NPCs to the state, and every coin the state spends is paid back
+//! out as wages or for the goods it buys. Only migrants carry coin into or
+//! out of the world. Borrowing lets the state spend coin it does not have,
+//! up to its credit limit, which leaves its balance negative by exactly what
+//! it put into circulation. Past that limit the state mints new coin instead, which
+//! waters down its currency: each nation strikes its own coin, whose price
+//! level and exchange rate follow how much of it is in circulation.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::Resource;
+use serde::Serialize;
+
+use crate::simulation::Nation;
+
+/// Coin each founding nation's coffers hold at the start of the world.
+const FOUNDING_TREASURY: f32 = 200.0;
+/// Debt beyond which creditors stop lending.
+pub const CREDIT_LIMIT: f32 = 400.0;
+
+/// Silver value of a coin falls by this share of it for every credit limit's
+/// worth of debt the state owes.
+const DEBT_DISCOUNT: f32 = 0.2;
+
+/// A nation's currency: what its coin is made of, how much of it the state
+/// has struck without revenue behind it, and what it buys.
+#[derive(Debug, Clone, Serialize)]
+pub struct Coinage {
+    /// Silver in one coin, 1.0 at founding; debasement cuts it.
+    pub silver: f32,
+    /// Coin the state minted to pay what it could neither collect nor
+    /// borrow, still in circulation.
+    pub printed: f32,
+    /// Coin held by the nation's people and in its coffers.
+    pub money_supply: f32,
+    /// Prices in this coin, 1.0 at founding.
+    pub price_level: f32,
+    /// Change in the price level over the last tick, in percent.
+    pub inflation: f32,
+}
+
+impl Default for Coinage {
+    fn default() -> Self {
+        Self {
+            silver: 1.0,
+            printed: 0.0,
+            money_supply: 0.0,
+            price_level: 1.0,
+            inflation: 0.0,
+        }
+    }
+}
+
+/// One nation's currency as quoted at the Market.
+#[derive(Debug, Clone, Serialize)]
+pub struct CurrencyQuote {
+    pub nation: Nation,
+    pub silver: f32,
+    pub price_level: f32,
+    pub inflation: f32,
+    pub money_supply: f32,
+    pub printed: f32,
+    /// Silver one coin fetches at the Market.
+    pub exchange_rate: f32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Treasury {
+    /// Coin in the coffers; negative while the state is in debt.
+    pub balance: f32,
+    /// Coin spent but not yet paid out; the economy pass hands it to the
+    /// nation's people as wages.
+    pub payroll: f32,
+    /// Coin taken in since the last economy pass.
+    pub revenue: f32,
+    /// Coin spent since the last economy pass.
+    pub expenses: f32,
+    pub coinage: Coinage,
+}
+
+impl Treasury {
+    pub fn collect(&mut self, amount: f32) {
+        self.balance += amount;
+        self.revenue += amount;
+    }
+
+    /// Spends up to `amount`, borrowing whatever the coffers cannot cover
+    /// while creditors still lend. The coin is owed out as wages. Returns
+    /// what was actually paid.
+    pub fn spend(&mut self, amount: f32) -> f32 {
+        let paid = amount.min(self.balance + CREDIT_LIMIT).max(0.0);
+        self.balance -= paid;
+        self.payroll += paid;
+        self.expenses += paid;
+        paid
+    }
+
+    /// Buys goods worth up to `amount` straight from the people who bring
+    /// them, borrowing while creditors still lend. Returns what was paid.
+    pub fn purchase(&mut self, amount: f32) -> f32 {
+        let paid = amount.min(self.balance + CREDIT_LIMIT).max(0.0);
+        self.balance -= paid;
+        self.expenses += paid;
+        paid
+    }
+
+    /// Pays `amount` in full: what the coffers and creditors cannot cover is
+    /// minted. Returns the coin minted.
+    pub fn pay(&mut self, amount: f32) -> f32 {
+        let minted = amount - self.spend(amount);
+        if minted > 0.0 {
+            self.payroll += minted;
+            self.expenses += minted;
+            self.coinage.printed += minted;
+        }
+        minted
+    }
+
+    pub fn debt(&self) -> f32 {
+        (-self.balance).max(0.0)
+    }
+
+    /// Silver one coin fetches at the Market: what it buys at home, marked
+    /// down for the chance the state never repays what it owes.
+    pub fn exchange_rate(&self) -> f32 {
+        (1.0 - DEBT_DISCOUNT * self.debt() / CREDIT_LIMIT).max(0.1) / self.coinage.price_level
+    }
+}
+
+#[derive(Debug, Clone, Resource, Serialize)]
+pub struct NationTreasuries(pub HashMap<Nation, Treasury>);
+
+impl Default for NationTreasuries {
+    fn default() -> Self {
+        let founding = Treasury {
+            balance: FOUNDING_TREASURY,
+            ..Treasury::default()
+        };
+        Self(
+            [Nation::Tera, Nation::Sora, Nation::Aqua]
+                .into_iter()
+                .map(|nation| (nation, founding.clone()))
+                .collect(),
+        )
+    }
+}
+
+impl NationTreasuries {
+    pub fn treasury_mut(&mut self, nation: Nation) -> &mut Treasury {
+        self.0.entry(nation).or_default()
+    }
+
+    pub fn debt(&self, nation: Nation) -> f32 {
+        self.0.get(&nation).map_or(0.0, Treasury::debt)
+    }
+
+    pub fn price_level(&self, nation: Nation) -> f32 {
+        self.0.get(&nation).map_or(1.0, |treasury| treasury.coinage.price_level)
+    }
+
+    pub fn exchange_rate(&self, nation: Nation) -> f32 {
+        self.0.get(&nation).map_or(1.0, Treasury::exchange_rate)
+    }
+
+    /// Changes `amount` of `from`'s coin into `to`'s at the Market rate.
+    pub fn convert(&self, amount: f32, from: Nation, to: Nation) -> f32 {
+        if from == to {
+            return amount;
+        }
+        amount * self.exchange_rate(from) / self.exchange_rate(to)
+    }
+
+    /// Moves coin from one state's coffers to another's, as tribute or
+    /// indemnity, changed into the receiver's coin. Returns what was actually
+    /// paid in the payer's coin: a state pays only what its coffers hold.
+    pub fn transfer(&mut self, from: Nation, to: Nation, amount: f32) -> f32 {
+        let payer = self.treasury_mut(from);
+        let paid = amount.min(payer.balance).max(0.0);
+        payer.balance -= paid;
+        payer.expenses += paid;
+        let received = self.convert(paid, from, to);
+        self.treasury_mut(to).collect(received);
+        paid
+    }
+
+    /// Every nation's currency as quoted at the Market, by name.
+    pub fn quotes(&self) -> Vec<CurrencyQuote> {
+        let mut quotes: Vec<CurrencyQuote> = self
+            .0
+            .iter()
+            .map(|(nation, treasury)| CurrencyQuote {
+                nation: *nation,
+                silver: treasury.coinage.silver,
+                price_level: treasury.coinage.price_level,
+                inflation: treasury.coinage.inflation,
+                money_supply: treasury.coinage.money_supply,
+                printed: treasury.coinage.printed,
+                exchange_rate: treasury.exchange_rate(),
+            })
+            .collect();
+        quotes.sort_by_key(|quote| quote.nation.name());
+        quotes
+    }
+}
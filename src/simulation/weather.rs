@@ -0,0 +1,142 @@
+//! Weather over the world's regions: each cluster of neighbouring hexes
+//! lives through spells of clear skies, rain, heat, storms and sandstorms
+//! drawn from the climate of the biome most of it shares and the season,
+//! and the weather bends travel, harvests, fighting and sickness while it
+//! lasts.
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::Resource;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::simulation::{AxialCoord, Biome};
+
+/// Hexes along each side of the rhombus a weather region covers.
+const REGION_SPAN: i32 = 4;
+
+/// The region whose skies `coord` lies under, named by its corner hex.
+pub fn weather_region(coord: AxialCoord) -> AxialCoord {
+    AxialCoord::new(
+        coord.q.div_euclid(REGION_SPAN) * REGION_SPAN,
+        coord.r.div_euclid(REGION_SPAN) * REGION_SPAN,
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Heat,
+    Storm,
+    Sandstorm,
+}
+
+impl Weather {
+    pub const ALL: [Weather; 5] = [
+        Weather::Clear,
+        Weather::Rain,
+        Weather::Heat,
+        Weather::Storm,
+        Weather::Sandstorm,
+    ];
+
+    /// How readily NPCs take to the road; heavy going also wears them out
+    /// faster.
+    pub fn mobility(self) -> f32 {
+        match self {
+            Weather::Clear => 1.0,
+            Weather::Rain => 0.8,
+            Weather::Heat => 0.85,
+            Weather::Storm => 0.5,
+            Weather::Sandstorm => 0.4,
+        }
+    }
+
+    /// Multiplier on what gatherers bring in.
+    pub fn yield_modifier(self) -> f32 {
+        match self {
+            Weather::Clear => 1.0,
+            Weather::Rain => 1.15,
+            Weather::Heat => 0.8,
+            Weather::Storm => 0.7,
+            Weather::Sandstorm => 0.6,
+        }
+    }
+
+    /// Multiplier on an attacker's strength: poor footing and visibility
+    /// favour the side holding its ground.
+    pub fn assault_modifier(self) -> f32 {
+        match self {
+            Weather::Clear => 1.0,
+            Weather::Rain => 0.9,
+            Weather::Heat => 0.95,
+            Weather::Storm => 0.75,
+            Weather::Sandstorm => 0.7,
+        }
+    }
+
+    /// Multiplier on how fast sickness spreads.
+    pub fn contagion(self) -> f32 {
+        match self {
+            Weather::Clear => 1.0,
+            Weather::Rain => 1.3,
+            Weather::Heat => 1.2,
+            Weather::Storm => 1.1,
+            Weather::Sandstorm => 0.9,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct WeatherSpell {
+    pub region: AxialCoord,
+    /// The biome whose climate the region's weather is drawn from.
+    pub climate: Biome,
+    pub weather: Weather,
+    pub started_at: u64,
+    pub ends_at: u64,
+}
+
+/// The weather over each region, keyed by `weather_region`; derived by
+/// `weather_system`.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct RegionalWeather(pub HashMap<AxialCoord, WeatherSpell>);
+
+impl RegionalWeather {
+    /// The weather over the hex at `coord`.
+    pub fn weather(&self, coord: AxialCoord) -> Weather {
+        self.0.get(&weather_region(coord)).map_or(Weather::Clear, |spell| spell.weather)
+    }
+}
+
+// JSON keys must be strings, so the regions go out as a list of spells.
+impl Serialize for RegionalWeather {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut spells: Vec<&WeatherSpell> = self.0.values().collect();
+        spells.sort_by_key(|spell| (spell.region.q, spell.region.r));
+        serializer.collect_seq(spells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_cluster_neighbouring_hexes() {
+        assert_eq!(weather_region(AxialCoord::new(1, 2)), weather_region(AxialCoord::new(3, 0)));
+        assert_eq!(weather_region(AxialCoord::new(-1, -4)), AxialCoord::new(-4, -4));
+        assert_ne!(weather_region(AxialCoord::new(-1, 0)), weather_region(AxialCoord::new(0, 0)));
+    }
+
+    #[test]
+    fn distant_hexes_of_one_biome_can_see_different_skies() {
+        let spell = |region, weather| WeatherSpell { region, climate: Biome::Desert, weather, started_at: 0, ends_at: 10 };
+        let (west, east) = (AxialCoord::new(-8, 0), AxialCoord::new(8, 0));
+        let mut skies = RegionalWeather::default();
+        skies.0.insert(weather_region(west), spell(weather_region(west), Weather::Sandstorm));
+        skies.0.insert(weather_region(east), spell(weather_region(east), Weather::Clear));
+        assert_eq!(skies.weather(west), Weather::Sandstorm);
+        assert_eq!(skies.weather(east), Weather::Clear);
+    }
+}
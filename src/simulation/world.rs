@@ -4,7 +4,10 @@ use std::collections::HashMap;
 
 use bevy_ecs::prelude::Resource;
 
-use crate::simulation::{BehaviorState, Biome, Faction, Position};
+use crate::simulation::{
+    BehaviorState, Biome, BudgetLine, DecisionModel, Doctrine, Faction, ItemKind, Nation,
+    Personality, Position, Weather,
+};
 
 #[derive(Debug, Clone)]
 pub struct BiomeMetadata {
@@ -16,6 +19,9 @@ pub struct BiomeMetadata {
     pub tensions: Vec<&'static str>,
     pub behavior_bias: HashMap<BehaviorState, f32>,
     pub economic_shift: EconomicShift,
+    /// How often each kind of weather sets in over the region, before the
+    /// season has its say.
+    pub climate: HashMap<Weather, f32>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,13 +32,148 @@ pub struct FactionMetadata {
     pub strongholds: Vec<Biome>,
     pub behavior_modifiers: HashMap<BehaviorState, f32>,
     pub economy_profile: EconomyProfile,
+    pub population_share: f32,
+    pub temperament: Personality,
+    pub attitudes: HashMap<Faction, f32>,
+    pub decision_model: DecisionModel,
 }
 
 #[derive(Debug, Clone)]
 pub struct EconomyMetadata {
     pub circulation_cycle: Vec<&'static str>,
-    pub stressors: Vec<&'static str>,
-    pub catalysts: Vec<&'static str>,
+}
+
+/// Distributions used to generate NPCs and drive births, immigration and emigration.
+#[derive(Debug, Clone)]
+pub struct PopulationMetadata {
+    pub nation_shares: Vec<(Nation, f32)>,
+    pub temperament_spread: f32,
+    pub birth_rate: f32,
+    pub immigration_rate: f32,
+    pub emigration_rate: f32,
+}
+
+/// One sound in a naming table: its romanisation, its Hangul jamo index
+/// (choseong, jungseong or jongseong depending on the slot) and a draw weight.
+#[derive(Debug, Clone, Copy)]
+pub struct Phoneme {
+    pub roman: &'static str,
+    pub jamo: u32,
+    pub weight: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Phonology {
+    pub onsets: Vec<Phoneme>,
+    pub vowels: Vec<Phoneme>,
+    pub codas: Vec<Phoneme>,
+    pub coda_chance: f32,
+    pub syllables: (u32, u32),
+}
+
+#[derive(Debug, Clone)]
+pub struct NamingMetadata {
+    pub nations: HashMap<Nation, Phonology>,
+    /// Signature closing syllables (romanised, Hangul) that mark faction members.
+    pub faction_endings: HashMap<Faction, Vec<(&'static str, &'static str)>>,
+    pub ending_chance: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecipeMetadata {
+    pub label: &'static str,
+    pub workshop: Biome,
+    pub inputs: Vec<(&'static str, u32)>,
+    pub output: ItemKind,
+    pub output_quantity: u32,
+    pub work_ticks: f32,
+}
+
+/// What a researched technology does for the nation that holds it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TechEffect {
+    /// Multiplies what the nation's gatherers bring in.
+    GatherYield(f32),
+    /// Opens a workshop recipe to the nation's artisans.
+    UnlockRecipe(&'static str),
+    /// Multiplies the fighting strength of the nation's armies.
+    ArmyStrength(f32),
+    /// Cuts the economy the nation spends on upkeep by this share.
+    UpkeepRelief(f32),
+}
+
+/// A condition a nation must meet before a macro shock can strike it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShockTrigger {
+    /// Only in this season.
+    Season(&'static str),
+    /// Only while the nation is at war.
+    AtWar,
+    /// Only while the nation's line is below this.
+    Below(BudgetLine, f32),
+    /// Only while the nation's line is at least this.
+    Above(BudgetLine, f32),
+    /// Only where a pilgrim band reaches its shrine; never by chance.
+    PilgrimArrival,
+}
+
+/// What a macro shock does, within its reach, while it lasts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShockModifier {
+    /// Multiplies what gatherers bring in.
+    GatherYield(f32),
+    /// Multiplies what traders make.
+    TradeYield(f32),
+    /// Multiplies the price level the struck nations' coin settles toward.
+    Prices(f32),
+    /// Moves a line of each struck nation by this much every tick.
+    Metric(BudgetLine, f32),
+    /// Moves each struck nation's stability by this much every tick.
+    Stability(f32),
+    /// Multiplies how readily NPCs take up a behaviour.
+    Behavior(BehaviorState, f32),
+    /// Health NPCs lose every tick.
+    Health(f32),
+    /// Chance per tick that a susceptible NPC falls sick.
+    Contagion(f32),
+}
+
+/// Who a macro shock strikes once it breaks out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShockScope {
+    /// Only the nation whose conditions set it off.
+    Nation,
+    /// Every nation still standing.
+    World,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShockMetadata {
+    pub label: &'static str,
+    /// The shock helps rather than harms.
+    pub boon: bool,
+    /// Chance per tick that the shock strikes a nation meeting every
+    /// trigger; zero for shocks only other systems set off.
+    pub chance: f64,
+    pub triggers: Vec<ShockTrigger>,
+    /// Shortest and longest run, in ticks.
+    pub duration: (u64, u64),
+    /// Biomes the shock reaches; empty for everywhere.
+    pub biomes: Vec<Biome>,
+    pub scope: ShockScope,
+    pub modifiers: Vec<ShockModifier>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TechMetadata {
+    pub label: &'static str,
+    /// Budget line the technology serves; nations research what they spend on.
+    pub line: BudgetLine,
+    /// Technologies that must be known first; each is listed earlier in the tree.
+    pub prerequisites: Vec<&'static str>,
+    /// Research needed for the breakthrough.
+    pub cost: f32,
+    pub effects: Vec<TechEffect>,
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +202,7 @@ pub struct EconomyProfile {
     pub trade_yield: f32,
     pub volatility_resistance: f32,
     pub upkeep_burden: f32,
+    pub craft_yield: f32,
 }
 
 impl EconomyProfile {
@@ -75,12 +217,126 @@ impl EconomyProfile {
     pub fn upkeep_burden(&self) -> f32 {
         self.upkeep_burden
     }
+
+    pub fn craft_yield(&self) -> f32 {
+        self.craft_yield
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct EpochCadence {
     pub day_segments: Vec<&'static str>,
     pub seasons: Vec<&'static str>,
+    /// How each season bends the odds of each kind of weather.
+    pub season_weather: HashMap<&'static str, HashMap<Weather, f32>>,
+}
+
+/// The NPC life cycle: which states follow which, and how temperament and
+/// the clock bend those odds.
+#[derive(Debug, Clone)]
+pub struct BehaviorMetadata {
+    /// Base weights of every state reachable from a given state.
+    pub transitions: HashMap<BehaviorState, Vec<(BehaviorState, f32)>>,
+    /// Per-trait pull toward a state; the modifier is one plus the dot
+    /// product with the NPC's personality.
+    pub personality_weights: HashMap<BehaviorState, Personality>,
+    /// Multipliers keyed by day segment label.
+    pub epoch_modifiers: HashMap<&'static str, HashMap<BehaviorState, f32>>,
+    /// Multipliers keyed by season label.
+    pub season_modifiers: HashMap<&'static str, HashMap<BehaviorState, f32>>,
+}
+
+impl BehaviorMetadata {
+    /// Rejects negative or non-finite weights, unknown epoch labels, and any
+    /// state an NPC starting out idle could never reach.
+    pub fn validate(&self, epochs: &EpochCadence) -> anyhow::Result<()> {
+        for state in BehaviorState::ALL {
+            let Some(options) = self.transitions.get(&state) else {
+                anyhow::bail!("behaviour {state:?} has no transition table");
+            };
+            anyhow::ensure!(
+                options.iter().any(|(_, weight)| *weight > 0.0),
+                "behaviour {state:?} has no positive transition weight"
+            );
+            for (next, weight) in options {
+                anyhow::ensure!(
+                    weight.is_finite() && *weight >= 0.0,
+                    "transition {state:?} -> {next:?} has invalid weight {weight}"
+                );
+            }
+        }
+
+        for (label, modifiers, known) in self
+            .epoch_modifiers
+            .iter()
+            .map(|(label, modifiers)| (label, modifiers, &epochs.day_segments))
+            .chain(
+                self.season_modifiers
+                    .iter()
+                    .map(|(label, modifiers)| (label, modifiers, &epochs.seasons)),
+            )
+        {
+            anyhow::ensure!(known.contains(label), "unknown epoch label {label}");
+            for (state, weight) in modifiers {
+                anyhow::ensure!(
+                    weight.is_finite() && *weight >= 0.0,
+                    "{label} modifier for {state:?} has invalid weight {weight}"
+                );
+            }
+        }
+
+        let mut reached = vec![BehaviorState::Idle];
+        let mut frontier = vec![BehaviorState::Idle];
+        while let Some(state) = frontier.pop() {
+            for (next, weight) in self.transitions.get(&state).into_iter().flatten() {
+                if *weight > 0.0 && !reached.contains(next) {
+                    reached.push(*next);
+                    frontier.push(*next);
+                }
+            }
+        }
+        let unreachable: Vec<BehaviorState> = BehaviorState::ALL
+            .into_iter()
+            .filter(|state| !reached.contains(state))
+            .collect();
+        anyhow::ensure!(
+            unreachable.is_empty(),
+            "behaviours unreachable from Idle: {unreachable:?}"
+        );
+
+        Ok(())
+    }
+}
+
+/// Doctrines and which nation follows which.
+#[derive(Debug, Clone)]
+pub struct StrategyMetadata {
+    /// Baseline budget weight per line, before threats and opportunities.
+    pub doctrines: HashMap<Doctrine, HashMap<BudgetLine, f32>>,
+    pub nation_doctrines: HashMap<Nation, Doctrine>,
+}
+
+impl StrategyMetadata {
+    /// Every nation needs a doctrine, and every doctrine a spendable budget.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for nation in Nation::ALL {
+            let doctrine = self
+                .nation_doctrines
+                .get(&nation)
+                .ok_or_else(|| anyhow::anyhow!("{} has no doctrine", nation.name()))?;
+            let weights = self
+                .doctrines
+                .get(doctrine)
+                .ok_or_else(|| anyhow::anyhow!("doctrine {:?} has no budget weights", doctrine))?;
+            if weights.values().any(|weight| !weight.is_finite() || *weight < 0.0) {
+                anyhow::bail!("doctrine {:?} has a negative or non-finite weight", doctrine);
+            }
+            if weights.values().sum::<f32>() <= 0.0 {
+                anyhow::bail!("doctrine {:?} allocates nothing", doctrine);
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Resource)]
@@ -88,10 +344,225 @@ pub struct WorldMetadata {
     pub biomes: HashMap<Biome, BiomeMetadata>,
     pub factions: HashMap<Faction, FactionMetadata>,
     pub economy: EconomyMetadata,
+    pub population: PopulationMetadata,
+    pub naming: NamingMetadata,
+    pub recipes: Vec<RecipeMetadata>,
     pub epochs: EpochCadence,
+    pub behaviors: BehaviorMetadata,
+    pub strategy: StrategyMetadata,
+    /// The technology tree, roots first.
+    pub techs: Vec<TechMetadata>,
+    /// Droughts, invasions, crashes and boons that can strike a nation.
+    pub shocks: Vec<ShockMetadata>,
 }
 
 impl WorldMetadata {
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.behaviors.validate(&self.epochs)?;
+        self.strategy.validate()?;
+        self.validate_techs()?;
+        self.validate_shocks()?;
+        self.validate_climates()
+    }
+
+    /// Every biome needs some weather to draw from, and season modifiers may
+    /// only name known seasons.
+    fn validate_climates(&self) -> anyhow::Result<()> {
+        for (biome, meta) in &self.biomes {
+            anyhow::ensure!(
+                meta.climate.values().all(|weight| weight.is_finite() && *weight >= 0.0)
+                    && meta.climate.values().sum::<f32>() > 0.0,
+                "biome {:?} has an unusable climate",
+                biome
+            );
+        }
+        for (season, modifiers) in &self.epochs.season_weather {
+            anyhow::ensure!(
+                self.epochs.seasons.contains(season),
+                "weather is given for unknown season {}",
+                season
+            );
+            anyhow::ensure!(
+                modifiers.values().all(|factor| factor.is_finite() && *factor >= 0.0),
+                "season {} has an invalid weather modifier",
+                season
+            );
+        }
+        Ok(())
+    }
+
+    /// Labels must be unique, chances and durations usable, seasons known
+    /// and multipliers positive.
+    fn validate_shocks(&self) -> anyhow::Result<()> {
+        let mut seen: Vec<&'static str> = Vec::new();
+        for shock in &self.shocks {
+            anyhow::ensure!(!seen.contains(&shock.label), "shock {} is defined twice", shock.label);
+            anyhow::ensure!(
+                (0.0..=1.0).contains(&shock.chance),
+                "shock {} has invalid chance {}",
+                shock.label,
+                shock.chance
+            );
+            anyhow::ensure!(
+                0 < shock.duration.0 && shock.duration.0 <= shock.duration.1,
+                "shock {} has invalid duration {:?}",
+                shock.label,
+                shock.duration
+            );
+            for trigger in &shock.triggers {
+                if let ShockTrigger::Season(season) = trigger {
+                    anyhow::ensure!(
+                        self.epochs.seasons.contains(season),
+                        "shock {} waits for unknown season {}",
+                        shock.label,
+                        season
+                    );
+                }
+            }
+            for modifier in &shock.modifiers {
+                match modifier {
+                    ShockModifier::GatherYield(factor)
+                    | ShockModifier::TradeYield(factor)
+                    | ShockModifier::Prices(factor)
+                    | ShockModifier::Behavior(_, factor) => anyhow::ensure!(
+                        factor.is_finite() && *factor > 0.0,
+                        "shock {} has invalid multiplier {}",
+                        shock.label,
+                        factor
+                    ),
+                    ShockModifier::Metric(_, delta) | ShockModifier::Stability(delta) | ShockModifier::Health(delta) => {
+                        anyhow::ensure!(delta.is_finite(), "shock {} has invalid delta {}", shock.label, delta)
+                    }
+                    ShockModifier::Contagion(chance) => anyhow::ensure!(
+                        (0.0..=1.0).contains(chance),
+                        "shock {} has invalid contagion {}",
+                        shock.label,
+                        chance
+                    ),
+                }
+            }
+            seen.push(shock.label);
+        }
+        Ok(())
+    }
+
+    /// Every prerequisite must come earlier in the tree, which also rules out
+    /// cycles; costs and effects must be usable and recipes must exist.
+    fn validate_techs(&self) -> anyhow::Result<()> {
+        let mut seen: Vec<&'static str> = Vec::new();
+        for tech in &self.techs {
+            anyhow::ensure!(!seen.contains(&tech.label), "technology {} is defined twice", tech.label);
+            for prerequisite in &tech.prerequisites {
+                anyhow::ensure!(
+                    seen.contains(prerequisite),
+                    "technology {} requires {} before it is defined",
+                    tech.label,
+                    prerequisite
+                );
+            }
+            anyhow::ensure!(
+                tech.cost.is_finite() && tech.cost > 0.0,
+                "technology {} has invalid cost {}",
+                tech.label,
+                tech.cost
+            );
+            for effect in &tech.effects {
+                match effect {
+                    TechEffect::GatherYield(factor) | TechEffect::ArmyStrength(factor) => anyhow::ensure!(
+                        factor.is_finite() && *factor > 0.0,
+                        "technology {} has invalid multiplier {}",
+                        tech.label,
+                        factor
+                    ),
+                    TechEffect::UpkeepRelief(share) => anyhow::ensure!(
+                        (0.0..1.0).contains(share),
+                        "technology {} has invalid upkeep relief {}",
+                        tech.label,
+                        share
+                    ),
+                    TechEffect::UnlockRecipe(recipe) => anyhow::ensure!(
+                        self.recipe(recipe).is_some(),
+                        "technology {} unlocks unknown recipe {}",
+                        tech.label,
+                        recipe
+                    ),
+                }
+            }
+            seen.push(tech.label);
+        }
+        Ok(())
+    }
+
+    pub fn shock(&self, label: &str) -> Option<&ShockMetadata> {
+        self.shocks.iter().find(|shock| shock.label == label)
+    }
+
+    pub fn tech(&self, label: &str) -> Option<&TechMetadata> {
+        self.techs.iter().find(|tech| tech.label == label)
+    }
+
+    /// Whether some technology has to be researched before `recipe` can be worked.
+    pub fn recipe_needs_tech(&self, recipe: &str) -> bool {
+        self.techs
+            .iter()
+            .flat_map(|tech| &tech.effects)
+            .any(|effect| matches!(effect, TechEffect::UnlockRecipe(unlocked) if *unlocked == recipe))
+    }
+
+    pub fn nation_doctrine(&self, nation: Nation) -> Doctrine {
+        self.strategy
+            .nation_doctrines
+            .get(&nation)
+            .copied()
+            .unwrap_or(Doctrine::Mercantile)
+    }
+
+    pub fn doctrine_budget_weights(&self, doctrine: Doctrine) -> HashMap<BudgetLine, f32> {
+        self.strategy.doctrines.get(&doctrine).cloned().unwrap_or_default()
+    }
+
+    pub fn behavior_transitions(&self, state: BehaviorState) -> &[(BehaviorState, f32)] {
+        self.behaviors
+            .transitions
+            .get(&state)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    pub fn personality_behavior_modifier(
+        &self,
+        personality: &Personality,
+        state: BehaviorState,
+    ) -> f32 {
+        let Some(weights) = self.behaviors.personality_weights.get(&state) else {
+            return 1.0;
+        };
+        let pull = personality.aggressive * weights.aggressive
+            + personality.cautious * weights.cautious
+            + personality.social * weights.social
+            + personality.curious * weights.curious;
+
+        (1.0 + pull).clamp(0.1, 2.5)
+    }
+
+    pub fn epoch_behavior_modifier(&self, segment: &str, state: BehaviorState) -> f32 {
+        self.behaviors
+            .epoch_modifiers
+            .get(segment)
+            .and_then(|modifiers| modifiers.get(&state))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    pub fn season_behavior_modifier(&self, season: &str, state: BehaviorState) -> f32 {
+        self.behaviors
+            .season_modifiers
+            .get(season)
+            .and_then(|modifiers| modifiers.get(&state))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
     pub fn anchor_position(&self, biome: Biome) -> Position {
         if let Some(metadata) = self.biomes.get(&biome) {
             Position {
@@ -108,10 +579,40 @@ impl WorldMetadata {
         }
     }
 
+    /// The biome whose anchor lies nearest a world-space position.
+    pub fn nearest_biome(&self, x: f32, y: f32) -> Biome {
+        Biome::ALL
+            .into_iter()
+            .filter_map(|biome| {
+                let anchor = self.biomes.get(&biome)?.anchor;
+                Some((biome, (anchor.0 - x).powi(2) + (anchor.1 - y).powi(2)))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(Biome::Plains, |(biome, _)| biome)
+    }
+
     pub fn faction_profile(&self, faction: Faction) -> Option<&FactionMetadata> {
         self.factions.get(&faction)
     }
 
+    /// Odds of `weather` setting in over `biome`'s region in `season`.
+    pub fn weather_weight(&self, biome: Biome, season: &str, weather: Weather) -> f32 {
+        let climate = self
+            .biomes
+            .get(&biome)
+            .and_then(|meta| meta.climate.get(&weather))
+            .copied()
+            .unwrap_or(0.0);
+        let seasonal = self
+            .epochs
+            .season_weather
+            .get(season)
+            .and_then(|modifiers| modifiers.get(&weather))
+            .copied()
+            .unwrap_or(1.0);
+        climate * seasonal
+    }
+
     pub fn biome_behavior_bias(&self, biome: Biome, state: BehaviorState) -> f32 {
         self.biomes
             .get(&biome)
@@ -120,6 +621,13 @@ impl WorldMetadata {
             .unwrap_or(1.0)
     }
 
+    pub fn faction_decision_model(&self, faction: Faction) -> DecisionModel {
+        self.factions
+            .get(&faction)
+            .map(|meta| meta.decision_model)
+            .unwrap_or(DecisionModel::TransitionTable)
+    }
+
     pub fn faction_behavior_modifier(&self, faction: Faction, state: BehaviorState) -> f32 {
         self.factions
             .get(&faction)
@@ -170,6 +678,29 @@ impl WorldMetadata {
             .unwrap_or(1.0)
     }
 
+    pub fn faction_craft_yield(&self, faction: Faction) -> f32 {
+        self.factions
+            .get(&faction)
+            .map(|meta| meta.economy_profile.craft_yield())
+            .unwrap_or(1.0)
+    }
+
+    /// Baseline affinity a member of `from` holds toward a member of `to`.
+    pub fn faction_attitude(&self, from: Faction, to: Faction) -> f32 {
+        if from == to {
+            return 10.0;
+        }
+        self.factions
+            .get(&from)
+            .and_then(|meta| meta.attitudes.get(&to))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn recipe(&self, label: &str) -> Option<&RecipeMetadata> {
+        self.recipes.iter().find(|recipe| recipe.label == label)
+    }
+
     pub fn epoch_for_tick(&self, tick: u64) -> (&'static str, &'static str) {
         let day_segments = &self.epochs.day_segments;
         let seasons = &self.epochs.seasons;
@@ -201,12 +732,19 @@ impl Default for WorldMetadata {
                         (Gather, 1.2),
                         (Hunt, 1.15),
                         (Rest, 0.95),
+                        (Raid, 1.2),
                     ]),
                     economic_shift: EconomicShift {
                         trade_opportunity: 0.9,
                         resource_abundance: 1.2,
                         risk_factor: 1.1,
                     },
+                    climate: HashMap::from([
+                        (Weather::Clear, 3.0),
+                        (Weather::Rain, 3.0),
+                        (Weather::Heat, 0.5),
+                        (Weather::Storm, 1.0),
+                    ]),
                 },
             ),
             (
@@ -230,6 +768,12 @@ impl Default for WorldMetadata {
                         resource_abundance: 1.05,
                         risk_factor: 0.9,
                     },
+                    climate: HashMap::from([
+                        (Weather::Clear, 4.0),
+                        (Weather::Rain, 2.0),
+                        (Weather::Heat, 1.5),
+                        (Weather::Storm, 1.0),
+                    ]),
                 },
             ),
             (
@@ -253,6 +797,13 @@ impl Default for WorldMetadata {
                         resource_abundance: 0.8,
                         risk_factor: 1.35,
                     },
+                    climate: HashMap::from([
+                        (Weather::Clear, 3.0),
+                        (Weather::Rain, 0.2),
+                        (Weather::Heat, 3.0),
+                        (Weather::Storm, 0.3),
+                        (Weather::Sandstorm, 2.0),
+                    ]),
                 },
             ),
             (
@@ -270,12 +821,20 @@ impl Default for WorldMetadata {
                         (Rest, 1.2),
                         (Idle, 1.05),
                         (Gather, 1.0),
+                        (Pray, 1.3),
+                        (Craft, 1.2),
                     ]),
                     economic_shift: EconomicShift {
                         trade_opportunity: 1.05,
                         resource_abundance: 1.1,
                         risk_factor: 0.85,
                     },
+                    climate: HashMap::from([
+                        (Weather::Clear, 4.0),
+                        (Weather::Rain, 2.0),
+                        (Weather::Heat, 1.0),
+                        (Weather::Storm, 0.5),
+                    ]),
                 },
             ),
             (
@@ -299,6 +858,13 @@ impl Default for WorldMetadata {
                         resource_abundance: 0.9,
                         risk_factor: 1.05,
                     },
+                    climate: HashMap::from([
+                        (Weather::Clear, 4.0),
+                        (Weather::Rain, 1.5),
+                        (Weather::Heat, 1.5),
+                        (Weather::Storm, 0.5),
+                        (Weather::Sandstorm, 0.5),
+                    ]),
                 },
             ),
         ]
@@ -317,12 +883,27 @@ impl Default for WorldMetadata {
                         (BehaviorState::Trade, 1.4),
                         (BehaviorState::Idle, 0.9),
                         (BehaviorState::Explore, 0.95),
+                        (BehaviorState::Raid, 0.4),
                     ]),
                     economy_profile: EconomyProfile {
                         trade_yield: 1.35,
                         volatility_resistance: 1.1,
                         upkeep_burden: 1.0,
+                        craft_yield: 1.0,
                     },
+                    population_share: 0.22,
+                    temperament: Personality {
+                        aggressive: 0.15,
+                        cautious: 0.4,
+                        social: 0.65,
+                        curious: 0.45,
+                    },
+                    attitudes: HashMap::from([
+                        (Faction::BanditClans, -20.0),
+                        (Faction::SettlersUnion, 5.0),
+                        (Faction::TempleOfSuns, 5.0),
+                    ]),
+                    decision_model: DecisionModel::Utility,
                 },
             ),
             (
@@ -337,12 +918,29 @@ impl Default for WorldMetadata {
                         (BehaviorState::Explore, 1.1),
                         (BehaviorState::Trade, 0.7),
                         (BehaviorState::Rest, 0.85),
+                        (BehaviorState::Raid, 1.6),
+                        (BehaviorState::Patrol, 0.5),
+                        (BehaviorState::Pray, 0.6),
                     ]),
                     economy_profile: EconomyProfile {
                         trade_yield: 0.85,
                         volatility_resistance: 0.9,
                         upkeep_burden: 0.8,
+                        craft_yield: 0.7,
+                    },
+                    population_share: 0.15,
+                    temperament: Personality {
+                        aggressive: 0.6,
+                        cautious: 0.2,
+                        social: 0.3,
+                        curious: 0.4,
                     },
+                    attitudes: HashMap::from([
+                        (Faction::MerchantGuild, -15.0),
+                        (Faction::SettlersUnion, -10.0),
+                        (Faction::TempleOfSuns, -5.0),
+                    ]),
+                    decision_model: DecisionModel::TransitionTable,
                 },
             ),
             (
@@ -357,12 +955,26 @@ impl Default for WorldMetadata {
                         (BehaviorState::Gather, 1.25),
                         (BehaviorState::Trade, 0.9),
                         (BehaviorState::Rest, 0.95),
+                        (BehaviorState::Migrate, 1.3),
                     ]),
                     economy_profile: EconomyProfile {
                         trade_yield: 1.05,
                         volatility_resistance: 0.95,
                         upkeep_burden: 1.1,
+                        craft_yield: 0.9,
+                    },
+                    population_share: 0.15,
+                    temperament: Personality {
+                        aggressive: 0.2,
+                        cautious: 0.3,
+                        social: 0.45,
+                        curious: 0.75,
                     },
+                    attitudes: HashMap::from([
+                        (Faction::BanditClans, -5.0),
+                        (Faction::MerchantGuild, 5.0),
+                    ]),
+                    decision_model: DecisionModel::TransitionTable,
                 },
             ),
             (
@@ -377,12 +989,29 @@ impl Default for WorldMetadata {
                         (BehaviorState::Trade, 1.1),
                         (BehaviorState::Idle, 1.05),
                         (BehaviorState::Hunt, 0.85),
+                        (BehaviorState::Craft, 1.35),
+                        (BehaviorState::Patrol, 1.1),
+                        (BehaviorState::Raid, 0.5),
                     ]),
                     economy_profile: EconomyProfile {
                         trade_yield: 1.15,
                         volatility_resistance: 1.05,
                         upkeep_burden: 1.2,
+                        craft_yield: 1.4,
                     },
+                    population_share: 0.3,
+                    temperament: Personality {
+                        aggressive: 0.15,
+                        cautious: 0.45,
+                        social: 0.55,
+                        curious: 0.35,
+                    },
+                    attitudes: HashMap::from([
+                        (Faction::BanditClans, -20.0),
+                        (Faction::TempleOfSuns, 10.0),
+                        (Faction::MerchantGuild, 5.0),
+                    ]),
+                    decision_model: DecisionModel::Utility,
                 },
             ),
             (
@@ -397,12 +1026,28 @@ impl Default for WorldMetadata {
                         (BehaviorState::Trade, 1.05),
                         (BehaviorState::Explore, 0.9),
                         (BehaviorState::Hunt, 0.7),
+                        (BehaviorState::Pray, 1.7),
+                        (BehaviorState::Raid, 0.3),
                     ]),
                     economy_profile: EconomyProfile {
                         trade_yield: 1.0,
                         volatility_resistance: 1.25,
                         upkeep_burden: 1.05,
+                        craft_yield: 1.05,
+                    },
+                    population_share: 0.18,
+                    temperament: Personality {
+                        aggressive: 0.1,
+                        cautious: 0.5,
+                        social: 0.7,
+                        curious: 0.5,
                     },
+                    attitudes: HashMap::from([
+                        (Faction::BanditClans, -5.0),
+                        (Faction::SettlersUnion, 10.0),
+                        (Faction::MerchantGuild, 5.0),
+                    ]),
+                    decision_model: DecisionModel::TransitionTable,
                 },
             ),
         ]
@@ -417,20 +1062,697 @@ impl Default for WorldMetadata {
                 "사막 원정",
                 "시장 환류",
             ],
-            stressors: vec!["가뭄 압박", "산적 급습", "화폐 절하", "유물 부족"],
-            catalysts: vec!["사원 축제", "탐험가 돌파구", "길드 관세 인하", "연합 풍년"],
         };
 
+        let shocks = vec![
+            ShockMetadata {
+                label: "가뭄",
+                boon: false,
+                chance: 0.004,
+                triggers: vec![ShockTrigger::Season("불꽃 절정")],
+                duration: (60, 150),
+                biomes: vec![Biome::Plains, Biome::Forest, Biome::Village],
+                scope: ShockScope::Nation,
+                modifiers: vec![
+                    ShockModifier::GatherYield(0.6),
+                    ShockModifier::Prices(1.1),
+                    ShockModifier::Metric(BudgetLine::Economy, -0.03),
+                    ShockModifier::Behavior(BehaviorState::Gather, 0.7),
+                ],
+            },
+            ShockMetadata {
+                label: "이민족 침입",
+                boon: false,
+                chance: 0.004,
+                triggers: vec![ShockTrigger::AtWar, ShockTrigger::Below(BudgetLine::Military, 50.0)],
+                duration: (40, 100),
+                biomes: vec![Biome::Desert, Biome::Plains],
+                scope: ShockScope::Nation,
+                modifiers: vec![
+                    ShockModifier::TradeYield(0.8),
+                    ShockModifier::Metric(BudgetLine::Military, -0.05),
+                    ShockModifier::Stability(-0.05),
+                    ShockModifier::Behavior(BehaviorState::Patrol, 1.5),
+                    ShockModifier::Health(0.05),
+                ],
+            },
+            ShockMetadata {
+                label: "시장 붕괴",
+                boon: false,
+                chance: 0.0015,
+                triggers: vec![],
+                duration: (50, 120),
+                biomes: vec![Biome::Market],
+                scope: ShockScope::World,
+                modifiers: vec![
+                    ShockModifier::TradeYield(0.6),
+                    ShockModifier::Prices(1.2),
+                    ShockModifier::Metric(BudgetLine::Economy, -0.05),
+                    ShockModifier::Behavior(BehaviorState::Trade, 0.6),
+                ],
+            },
+            ShockMetadata {
+                label: "순례 축복",
+                boon: true,
+                chance: 0.0,
+                triggers: vec![ShockTrigger::PilgrimArrival],
+                duration: (30, 60),
+                biomes: vec![Biome::Village, Biome::Market],
+                scope: ShockScope::Nation,
+                modifiers: vec![
+                    ShockModifier::TradeYield(1.2),
+                    ShockModifier::Metric(BudgetLine::Religion, 0.05),
+                    ShockModifier::Stability(0.03),
+                    ShockModifier::Behavior(BehaviorState::Pray, 1.4),
+                ],
+            },
+            ShockMetadata {
+                label: "유물 열풍",
+                boon: true,
+                chance: 0.002,
+                triggers: vec![ShockTrigger::Above(BudgetLine::Culture, 40.0)],
+                duration: (40, 100),
+                biomes: vec![Biome::Desert],
+                scope: ShockScope::Nation,
+                modifiers: vec![
+                    ShockModifier::GatherYield(1.5),
+                    ShockModifier::Metric(BudgetLine::Culture, 0.03),
+                    ShockModifier::Behavior(BehaviorState::Gather, 1.3),
+                    ShockModifier::Health(0.02),
+                ],
+            },
+            ShockMetadata {
+                label: "역병",
+                boon: false,
+                chance: 0.002,
+                triggers: vec![ShockTrigger::Season("잿불 내림")],
+                duration: (50, 120),
+                biomes: vec![Biome::Village, Biome::Market],
+                scope: ShockScope::Nation,
+                modifiers: vec![
+                    ShockModifier::Contagion(0.02),
+                    ShockModifier::Stability(-0.03),
+                    ShockModifier::Metric(BudgetLine::Economy, -0.02),
+                    ShockModifier::Behavior(BehaviorState::Rest, 1.5),
+                    ShockModifier::Behavior(BehaviorState::Trade, 0.7),
+                ],
+            },
+        ];
+
+        let population = PopulationMetadata {
+            nation_shares: vec![(Nation::Tera, 1.0), (Nation::Sora, 1.0), (Nation::Aqua, 1.0)],
+            temperament_spread: 0.2,
+            birth_rate: 0.08,
+            immigration_rate: 0.04,
+            emigration_rate: 0.05,
+        };
+
+        let phoneme = |roman, jamo, weight| Phoneme { roman, jamo, weight };
+        let naming = NamingMetadata {
+            nations: HashMap::from([
+                (
+                    Nation::Tera,
+                    Phonology {
+                        onsets: vec![
+                            phoneme("k", 0, 2.0),
+                            phoneme("d", 3, 2.0),
+                            phoneme("b", 7, 1.5),
+                            phoneme("t", 16, 1.0),
+                            phoneme("h", 18, 1.0),
+                            phoneme("m", 6, 1.0),
+                            phoneme("", 11, 1.0),
+                        ],
+                        vowels: vec![
+                            phoneme("a", 0, 3.0),
+                            phoneme("o", 8, 2.0),
+                            phoneme("eo", 4, 1.5),
+                            phoneme("u", 13, 1.0),
+                        ],
+                        codas: vec![
+                            phoneme("n", 4, 2.0),
+                            phoneme("ng", 21, 2.0),
+                            phoneme("m", 16, 1.0),
+                            phoneme("k", 1, 1.0),
+                        ],
+                        coda_chance: 0.5,
+                        syllables: (2, 3),
+                    },
+                ),
+                (
+                    Nation::Sora,
+                    Phonology {
+                        onsets: vec![
+                            phoneme("s", 9, 3.0),
+                            phoneme("h", 18, 2.0),
+                            phoneme("r", 5, 2.0),
+                            phoneme("", 11, 1.5),
+                            phoneme("ch", 14, 1.0),
+                            phoneme("j", 12, 1.0),
+                        ],
+                        vowels: vec![
+                            phoneme("o", 8, 2.0),
+                            phoneme("a", 0, 2.0),
+                            phoneme("i", 20, 2.0),
+                            phoneme("yo", 12, 1.0),
+                            phoneme("e", 5, 1.0),
+                        ],
+                        codas: vec![phoneme("n", 4, 1.0), phoneme("l", 8, 1.5)],
+                        coda_chance: 0.25,
+                        syllables: (2, 3),
+                    },
+                ),
+                (
+                    Nation::Aqua,
+                    Phonology {
+                        onsets: vec![
+                            phoneme("m", 6, 2.0),
+                            phoneme("n", 2, 2.0),
+                            phoneme("r", 5, 2.0),
+                            phoneme("", 11, 2.0),
+                            phoneme("p", 17, 1.0),
+                        ],
+                        vowels: vec![
+                            phoneme("u", 13, 2.0),
+                            phoneme("i", 20, 2.0),
+                            phoneme("eu", 18, 1.0),
+                            phoneme("ae", 1, 1.0),
+                            phoneme("yu", 17, 1.0),
+                            phoneme("a", 0, 1.0),
+                        ],
+                        codas: vec![
+                            phoneme("l", 8, 2.0),
+                            phoneme("m", 16, 1.0),
+                            phoneme("ng", 21, 1.0),
+                        ],
+                        coda_chance: 0.35,
+                        syllables: (2, 3),
+                    },
+                ),
+                (
+                    Nation::Ignis,
+                    Phonology {
+                        onsets: vec![
+                            phoneme("g", 0, 2.0),
+                            phoneme("d", 3, 2.0),
+                            phoneme("b", 7, 1.5),
+                            phoneme("k", 15, 1.0),
+                            phoneme("t", 16, 1.0),
+                        ],
+                        vowels: vec![
+                            phoneme("a", 0, 2.0),
+                            phoneme("eo", 4, 2.0),
+                            phoneme("u", 13, 1.0),
+                            phoneme("ae", 1, 1.0),
+                        ],
+                        codas: vec![
+                            phoneme("k", 1, 2.0),
+                            phoneme("l", 8, 1.5),
+                            phoneme("t", 19, 1.0),
+                        ],
+                        coda_chance: 0.55,
+                        syllables: (2, 2),
+                    },
+                ),
+                (
+                    Nation::Ventus,
+                    Phonology {
+                        onsets: vec![
+                            phoneme("h", 18, 2.0),
+                            phoneme("p", 17, 1.5),
+                            phoneme("s", 9, 1.5),
+                            phoneme("", 11, 2.0),
+                        ],
+                        vowels: vec![
+                            phoneme("wa", 9, 2.0),
+                            phoneme("i", 20, 2.0),
+                            phoneme("yeo", 6, 1.0),
+                            phoneme("e", 5, 1.0),
+                        ],
+                        codas: vec![phoneme("n", 4, 1.5), phoneme("p", 17, 1.0)],
+                        coda_chance: 0.2,
+                        syllables: (2, 3),
+                    },
+                ),
+            ]),
+            faction_endings: HashMap::from([
+                (Faction::MerchantGuild, vec![("ron", "론"), ("mun", "문")]),
+                (Faction::BanditClans, vec![("rak", "락"), ("gu", "구"), ("chal", "찰")]),
+                (Faction::ExplorersLeague, vec![("hae", "해"), ("ryeon", "련")]),
+                (Faction::SettlersUnion, vec![("bo", "보"), ("dol", "돌")]),
+                (Faction::TempleOfSuns, vec![("on", "온"), ("seong", "성"), ("hwi", "휘")]),
+            ]),
+            ending_chance: 0.3,
+        };
+
+        let recipes = vec![
+            RecipeMetadata {
+                label: "도구 제작",
+                workshop: Biome::Village,
+                inputs: vec![("목재", 2), ("광물", 1)],
+                output: ItemKind::Equipment("도구".into()),
+                output_quantity: 1,
+                work_ticks: 3.0,
+            },
+            RecipeMetadata {
+                label: "공방 가공",
+                workshop: Biome::Village,
+                inputs: vec![("가공품", 3)],
+                output: ItemKind::Equipment("생활 도구".into()),
+                output_quantity: 1,
+                work_ticks: 2.0,
+            },
+            RecipeMetadata {
+                label: "약재 조제",
+                workshop: Biome::Village,
+                inputs: vec![("약초", 3), ("곡물", 1)],
+                output: ItemKind::Resource("약재".into()),
+                output_quantity: 2,
+                work_ticks: 2.0,
+            },
+            RecipeMetadata {
+                label: "성물 정화",
+                workshop: Biome::Village,
+                inputs: vec![("유물", 1), ("도구", 1), ("신앙 의례", 2)],
+                output: ItemKind::Artifact("정화된 성물".into()),
+                output_quantity: 1,
+                work_ticks: 5.0,
+            },
+            // Recipes below are unlocked by research.
+            RecipeMetadata {
+                label: "강철 단조",
+                workshop: Biome::Village,
+                inputs: vec![("광물", 3), ("목재", 1)],
+                output: ItemKind::Equipment("강철 무기".into()),
+                output_quantity: 1,
+                work_ticks: 4.0,
+            },
+            RecipeMetadata {
+                label: "성상 조각",
+                workshop: Biome::Village,
+                inputs: vec![("유물", 1), ("공예 기술", 2)],
+                output: ItemKind::Artifact("태양 성상".into()),
+                output_quantity: 1,
+                work_ticks: 4.0,
+            },
+            RecipeMetadata {
+                label: "서적 제본",
+                workshop: Biome::Village,
+                inputs: vec![("섬유", 2), ("가공품", 1)],
+                output: ItemKind::Equipment("서적".into()),
+                output_quantity: 1,
+                work_ticks: 3.0,
+            },
+        ];
+
+        let techs = vec![
+            TechMetadata {
+                label: "윤작법",
+                line: BudgetLine::Economy,
+                prerequisites: vec![],
+                cost: 150.0,
+                effects: vec![TechEffect::GatherYield(1.15)],
+            },
+            TechMetadata {
+                label: "청동 주조",
+                line: BudgetLine::Military,
+                prerequisites: vec![],
+                cost: 150.0,
+                effects: vec![TechEffect::ArmyStrength(1.1)],
+            },
+            TechMetadata {
+                label: "장부 기술",
+                line: BudgetLine::Science,
+                prerequisites: vec![],
+                cost: 180.0,
+                effects: vec![TechEffect::UpkeepRelief(0.1)],
+            },
+            TechMetadata {
+                label: "성물 공예",
+                line: BudgetLine::Religion,
+                prerequisites: vec![],
+                cost: 200.0,
+                effects: vec![TechEffect::UnlockRecipe("성상 조각")],
+            },
+            TechMetadata {
+                label: "관개 수로",
+                line: BudgetLine::Economy,
+                prerequisites: vec!["윤작법"],
+                cost: 300.0,
+                effects: vec![TechEffect::GatherYield(1.2)],
+            },
+            TechMetadata {
+                label: "제련술",
+                line: BudgetLine::Military,
+                prerequisites: vec!["청동 주조"],
+                cost: 320.0,
+                effects: vec![TechEffect::UnlockRecipe("강철 단조"), TechEffect::ArmyStrength(1.1)],
+            },
+            TechMetadata {
+                label: "관료제",
+                line: BudgetLine::Diplomacy,
+                prerequisites: vec!["장부 기술"],
+                cost: 350.0,
+                effects: vec![TechEffect::UpkeepRelief(0.15)],
+            },
+            TechMetadata {
+                label: "인쇄술",
+                line: BudgetLine::Culture,
+                prerequisites: vec!["장부 기술", "윤작법"],
+                cost: 400.0,
+                effects: vec![TechEffect::UnlockRecipe("서적 제본"), TechEffect::UpkeepRelief(0.05)],
+            },
+            TechMetadata {
+                label: "기병 전술",
+                line: BudgetLine::Military,
+                prerequisites: vec!["제련술"],
+                cost: 500.0,
+                effects: vec![TechEffect::ArmyStrength(1.15)],
+            },
+            TechMetadata {
+                label: "상업 어음",
+                line: BudgetLine::Economy,
+                prerequisites: vec!["관개 수로", "관료제"],
+                cost: 600.0,
+                effects: vec![TechEffect::GatherYield(1.1), TechEffect::UpkeepRelief(0.1)],
+            },
+        ];
+
         let epochs = EpochCadence {
             day_segments: vec!["새벽", "한낮", "해질녘"],
             seasons: vec!["꽃피움 계절", "불꽃 절정", "잿불 내림"],
+            season_weather: HashMap::from([
+                (
+                    "꽃피움 계절",
+                    HashMap::from([(Weather::Rain, 1.5), (Weather::Heat, 0.5)]),
+                ),
+                (
+                    "불꽃 절정",
+                    HashMap::from([(Weather::Heat, 2.0), (Weather::Rain, 0.5), (Weather::Sandstorm, 1.5)]),
+                ),
+                (
+                    "잿불 내림",
+                    HashMap::from([(Weather::Storm, 1.8), (Weather::Rain, 1.2), (Weather::Heat, 0.3)]),
+                ),
+            ]),
+        };
+
+        let trait_weights = |aggressive, cautious, social, curious| Personality {
+            aggressive,
+            cautious,
+            social,
+            curious,
+        };
+        let behaviors = BehaviorMetadata {
+            transitions: HashMap::from([
+                (
+                    BehaviorState::Idle,
+                    vec![
+                        (BehaviorState::Idle, 0.2),
+                        (BehaviorState::Explore, 0.3),
+                        (BehaviorState::Trade, 0.25),
+                        (BehaviorState::Rest, 0.25),
+                        (BehaviorState::Pray, 0.1),
+                        (BehaviorState::Patrol, 0.08),
+                    ],
+                ),
+                (
+                    BehaviorState::Explore,
+                    vec![
+                        (BehaviorState::Explore, 0.2),
+                        (BehaviorState::Gather, 0.35),
+                        (BehaviorState::Hunt, 0.25),
+                        (BehaviorState::Trade, 0.2),
+                        (BehaviorState::Raid, 0.06),
+                        (BehaviorState::Migrate, 0.04),
+                    ],
+                ),
+                (
+                    BehaviorState::Gather,
+                    vec![
+                        (BehaviorState::Gather, 0.25),
+                        (BehaviorState::Trade, 0.4),
+                        (BehaviorState::Rest, 0.2),
+                        (BehaviorState::Explore, 0.15),
+                        (BehaviorState::Craft, 0.15),
+                    ],
+                ),
+                (
+                    BehaviorState::Trade,
+                    vec![
+                        (BehaviorState::Trade, 0.25),
+                        (BehaviorState::Rest, 0.25),
+                        (BehaviorState::Idle, 0.2),
+                        (BehaviorState::Explore, 0.3),
+                        (BehaviorState::Migrate, 0.03),
+                    ],
+                ),
+                (
+                    BehaviorState::Hunt,
+                    vec![
+                        (BehaviorState::Hunt, 0.25),
+                        (BehaviorState::Rest, 0.3),
+                        (BehaviorState::Gather, 0.2),
+                        (BehaviorState::Trade, 0.25),
+                        (BehaviorState::Raid, 0.1),
+                    ],
+                ),
+                (
+                    BehaviorState::Rest,
+                    vec![
+                        (BehaviorState::Rest, 0.3),
+                        (BehaviorState::Idle, 0.35),
+                        (BehaviorState::Explore, 0.2),
+                        (BehaviorState::Trade, 0.15),
+                        (BehaviorState::Pray, 0.1),
+                    ],
+                ),
+                (
+                    BehaviorState::Pray,
+                    vec![
+                        (BehaviorState::Pray, 0.2),
+                        (BehaviorState::Idle, 0.35),
+                        (BehaviorState::Rest, 0.25),
+                        (BehaviorState::Trade, 0.2),
+                    ],
+                ),
+                (
+                    BehaviorState::Raid,
+                    vec![
+                        (BehaviorState::Raid, 0.2),
+                        (BehaviorState::Hunt, 0.25),
+                        (BehaviorState::Rest, 0.35),
+                        (BehaviorState::Explore, 0.2),
+                    ],
+                ),
+                (
+                    BehaviorState::Patrol,
+                    vec![
+                        (BehaviorState::Patrol, 0.25),
+                        (BehaviorState::Idle, 0.3),
+                        (BehaviorState::Rest, 0.25),
+                        (BehaviorState::Hunt, 0.2),
+                    ],
+                ),
+                (
+                    BehaviorState::Craft,
+                    vec![
+                        (BehaviorState::Craft, 0.3),
+                        (BehaviorState::Trade, 0.4),
+                        (BehaviorState::Rest, 0.2),
+                        (BehaviorState::Gather, 0.1),
+                    ],
+                ),
+                (
+                    BehaviorState::Migrate,
+                    vec![
+                        (BehaviorState::Migrate, 0.1),
+                        (BehaviorState::Explore, 0.3),
+                        (BehaviorState::Rest, 0.3),
+                        (BehaviorState::Idle, 0.3),
+                    ],
+                ),
+            ]),
+            personality_weights: HashMap::from([
+                (BehaviorState::Hunt, trait_weights(0.6, -0.3, 0.0, 0.0)),
+                (BehaviorState::Trade, trait_weights(0.0, 0.0, 0.5, 0.0)),
+                (BehaviorState::Explore, trait_weights(0.0, -0.2, 0.0, 0.6)),
+                (BehaviorState::Gather, trait_weights(0.0, 0.2, 0.0, 0.2)),
+                (BehaviorState::Rest, trait_weights(-0.2, 0.4, 0.0, 0.0)),
+                (BehaviorState::Idle, trait_weights(0.0, 0.1, 0.0, -0.1)),
+                (BehaviorState::Pray, trait_weights(-0.2, 0.3, 0.3, 0.0)),
+                (BehaviorState::Raid, trait_weights(0.8, -0.4, -0.1, 0.0)),
+                (BehaviorState::Patrol, trait_weights(0.3, 0.3, 0.0, 0.0)),
+                (BehaviorState::Craft, trait_weights(0.0, 0.3, 0.0, 0.2)),
+                (BehaviorState::Migrate, trait_weights(0.0, -0.3, 0.0, 0.5)),
+            ]),
+            epoch_modifiers: HashMap::from([
+                (
+                    "새벽",
+                    HashMap::from([
+                        (BehaviorState::Explore, 1.15),
+                        (BehaviorState::Gather, 1.15),
+                        (BehaviorState::Rest, 0.85),
+                        (BehaviorState::Pray, 1.3),
+                    ]),
+                ),
+                (
+                    "한낮",
+                    HashMap::from([
+                        (BehaviorState::Trade, 1.2),
+                        (BehaviorState::Idle, 0.85),
+                        (BehaviorState::Craft, 1.15),
+                    ]),
+                ),
+                (
+                    "해질녘",
+                    HashMap::from([
+                        (BehaviorState::Hunt, 1.25),
+                        (BehaviorState::Rest, 1.1),
+                        (BehaviorState::Trade, 0.75),
+                        (BehaviorState::Raid, 1.35),
+                        (BehaviorState::Patrol, 1.15),
+                    ]),
+                ),
+            ]),
+            season_modifiers: HashMap::from([
+                (
+                    "꽃피움 계절",
+                    HashMap::from([
+                        (BehaviorState::Gather, 1.2),
+                        (BehaviorState::Trade, 1.05),
+                        (BehaviorState::Migrate, 1.15),
+                    ]),
+                ),
+                (
+                    "불꽃 절정",
+                    HashMap::from([
+                        (BehaviorState::Explore, 1.1),
+                        (BehaviorState::Hunt, 1.1),
+                        (BehaviorState::Rest, 0.95),
+                        (BehaviorState::Raid, 1.1),
+                    ]),
+                ),
+                (
+                    "잿불 내림",
+                    HashMap::from([
+                        (BehaviorState::Rest, 1.25),
+                        (BehaviorState::Trade, 0.9),
+                        (BehaviorState::Pray, 1.2),
+                        (BehaviorState::Craft, 1.15),
+                    ]),
+                ),
+            ]),
+        };
+
+        let strategy = StrategyMetadata {
+            doctrines: HashMap::from([
+                (
+                    Doctrine::Militarist,
+                    HashMap::from([
+                        (BudgetLine::Military, 0.4),
+                        (BudgetLine::Economy, 0.2),
+                        (BudgetLine::Science, 0.15),
+                        (BudgetLine::Culture, 0.05),
+                        (BudgetLine::Diplomacy, 0.1),
+                        (BudgetLine::Religion, 0.1),
+                    ]),
+                ),
+                (
+                    Doctrine::Mercantile,
+                    HashMap::from([
+                        (BudgetLine::Military, 0.15),
+                        (BudgetLine::Economy, 0.35),
+                        (BudgetLine::Science, 0.15),
+                        (BudgetLine::Culture, 0.1),
+                        (BudgetLine::Diplomacy, 0.2),
+                        (BudgetLine::Religion, 0.05),
+                    ]),
+                ),
+                (
+                    Doctrine::Theocratic,
+                    HashMap::from([
+                        (BudgetLine::Military, 0.15),
+                        (BudgetLine::Economy, 0.2),
+                        (BudgetLine::Science, 0.05),
+                        (BudgetLine::Culture, 0.2),
+                        (BudgetLine::Diplomacy, 0.1),
+                        (BudgetLine::Religion, 0.3),
+                    ]),
+                ),
+                (
+                    Doctrine::Scholarly,
+                    HashMap::from([
+                        (BudgetLine::Military, 0.15),
+                        (BudgetLine::Economy, 0.2),
+                        (BudgetLine::Science, 0.35),
+                        (BudgetLine::Culture, 0.15),
+                        (BudgetLine::Diplomacy, 0.1),
+                        (BudgetLine::Religion, 0.05),
+                    ]),
+                ),
+            ]),
+            nation_doctrines: HashMap::from([
+                (Nation::Tera, Doctrine::Militarist),
+                (Nation::Sora, Doctrine::Theocratic),
+                (Nation::Aqua, Doctrine::Mercantile),
+                (Nation::Ignis, Doctrine::Militarist),
+                (Nation::Ventus, Doctrine::Scholarly),
+            ]),
         };
 
         Self {
             biomes,
             factions,
             economy,
+            population,
+            naming,
+            recipes,
             epochs,
+            behaviors,
+            strategy,
+            techs,
+            shocks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejection(meta: &WorldMetadata) -> String {
+        meta.validate().expect_err("metadata should be rejected").to_string()
+    }
+
+    #[test]
+    fn default_metadata_is_valid() {
+        WorldMetadata::default().validate().unwrap();
+    }
+
+    #[test]
+    fn negative_transition_weights_are_rejected() {
+        let mut meta = WorldMetadata::default();
+        meta.behaviors.transitions.get_mut(&BehaviorState::Idle).unwrap()[0].1 = -1.0;
+        assert!(rejection(&meta).contains("invalid weight"));
+    }
+
+    #[test]
+    fn unreachable_states_are_rejected() {
+        let mut meta = WorldMetadata::default();
+        for options in meta.behaviors.transitions.values_mut() {
+            for (next, weight) in options.iter_mut() {
+                if *next == BehaviorState::Pray {
+                    *weight = 0.0;
+                }
+            }
         }
+        assert!(rejection(&meta).contains("unreachable from Idle: [Pray]"));
+    }
+
+    #[test]
+    fn unknown_epoch_labels_are_rejected() {
+        let mut meta = WorldMetadata::default();
+        meta.behaviors.epoch_modifiers.insert("한밤", HashMap::new());
+        assert!(rejection(&meta).contains("unknown epoch label 한밤"));
     }
 }
@@ -1,5 +1,5 @@
 use crate::simulation::events::{WorldEventKind};
-use crate::simulation::{ObserverSnapshot};
+use crate::simulation::{ObserverSnapshot, Weather, budget_line_label, doctrine_label, treaty_label, weather_label};
 use ratatui::{
     prelude::*,
     style::Stylize,
@@ -8,7 +8,7 @@ use ratatui::{
 };
 use std::time::Duration;
 
-pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Duration) {
+pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Duration, weather_overlay: bool) {
     // Main layout
     let main_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -36,10 +36,10 @@ pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Dur
         .split(content_layout[0]);
 
     // World State Panel
-    render_world_state_panel(frame, top_layout[0], snapshot, tick_duration);
+    render_world_state_panel(frame, top_layout[0], snapshot, tick_duration, weather_overlay);
 
     // Map Widget
-    let map_widget = MapWidget { snapshot };
+    let map_widget = MapWidget { snapshot, weather_overlay };
     frame.render_widget(map_widget, top_layout[1]);
 
     // Event Log Panel - Using a Table for alignment
@@ -70,13 +70,83 @@ pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Dur
                     let color = convener.nation.color();
                     (Cell::from(convener.nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Green))
                 }
-                WorldEventKind::MacroShock { .. } => {
-                    (Cell::from("System"), Style::default().fg(Color::Yellow))
+                WorldEventKind::MacroShock { nations, boon, .. } => {
+                    let style = if *boon { Color::LightGreen } else { Color::Yellow };
+                    match nations.as_slice() {
+                        [nation] => (Cell::from(nation.name()).style(Style::default().fg(nation.color())), Style::default().fg(style)),
+                        _ => (Cell::from("System"), Style::default().fg(style)),
+                    }
                 }
                 WorldEventKind::Warfare { winner, .. } => {
                     let color = winner.color();
                     (Cell::from(winner.name()).style(Style::default().fg(color)), Style::default().fg(Color::Red))
                 }
+                WorldEventKind::WarDeclared { aggressor, .. } => {
+                    let color = aggressor.color();
+                    (Cell::from(aggressor.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightRed))
+                }
+                WorldEventKind::WarEnded { aggressor, .. } => {
+                    let color = aggressor.color();
+                    (Cell::from(aggressor.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightGreen))
+                }
+                WorldEventKind::PolicyShift { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightBlue))
+                }
+                WorldEventKind::Treaty { party, signed, .. } => {
+                    let color = party.color();
+                    let style = if *signed { Color::LightCyan } else { Color::Magenta };
+                    (Cell::from(party.name()).style(Style::default().fg(color)), Style::default().fg(style))
+                }
+                WorldEventKind::ArmyClash { winner, .. } => {
+                    let color = winner.color();
+                    (Cell::from(winner.name()).style(Style::default().fg(color)), Style::default().fg(Color::Red))
+                }
+                WorldEventKind::Rebellion { rebels, .. } => {
+                    let color = rebels.color();
+                    (Cell::from(rebels.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightRed))
+                }
+                WorldEventKind::Succession { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Yellow))
+                }
+                WorldEventKind::Collapse { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Red))
+                }
+                WorldEventKind::CulturalShift { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Magenta))
+                }
+                WorldEventKind::Debasement { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Yellow))
+                }
+                WorldEventKind::Epidemic { nation, ended, .. } => {
+                    let color = nation.color();
+                    let style = if *ended { Color::Green } else { Color::LightRed };
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(style))
+                }
+                WorldEventKind::Breakthrough { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Cyan))
+                }
+                WorldEventKind::Crafting { artisan, .. } => {
+                    let color = artisan.nation.color();
+                    (Cell::from(artisan.nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::Cyan))
+                }
+                WorldEventKind::Death { npc, .. } => {
+                    let color = npc.nation.color();
+                    (Cell::from(npc.nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::DarkGray))
+                }
+                WorldEventKind::Demographics { nation, .. } => {
+                    let color = nation.color();
+                    (Cell::from(nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightGreen))
+                }
+                WorldEventKind::Combat { attacker, .. } => {
+                    let color = attacker.nation.color();
+                    (Cell::from(attacker.nation.name()).style(Style::default().fg(color)), Style::default().fg(Color::LightRed))
+                }
             };
 
             let (actor, details, impact) = match &event.kind {
@@ -90,15 +160,118 @@ pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Dur
                     gathering_theme.clone(),
                     cohesion_level.clone(),
                 ),
-                WorldEventKind::MacroShock { stressor, catalyst, projected_impact } => (
-                    stressor.clone(),
-                    catalyst.clone(),
-                    projected_impact.clone(),
+                WorldEventKind::MacroShock { shock, nations, effects, duration, ended, .. } => (
+                    shock.clone(),
+                    if *ended {
+                        "passes".to_string()
+                    } else {
+                        format!("strikes {} for {} ticks", nations.iter().map(|nation| nation.name()).collect::<Vec<_>>().join(", "), duration)
+                    },
+                    effects.join(", "),
+                ),
+                WorldEventKind::Warfare { winner, loser, contested, captured, war_score, .. } => (
+                    winner.name().to_string(),
+                    format!("vs {} · score {:+.0}", loser.name(), war_score),
+                    format!(
+                        "{} ({}, {})",
+                        if *captured { "captured" } else { "held" },
+                        contested.q,
+                        contested.r
+                    ),
+                ),
+                WorldEventKind::WarDeclared { aggressor, defender, casus_belli_label, goal_label, .. } => (
+                    aggressor.name().to_string(),
+                    format!("declares on {} · {}", defender.name(), casus_belli_label),
+                    goal_label.clone(),
+                ),
+                WorldEventKind::WarEnded { aggressor, defender, victor, terms_label, duration, .. } => (
+                    aggressor.name().to_string(),
+                    format!("peace with {} · {}", defender.name(), terms_label),
+                    format!("won by {} · {} ticks", victor.map_or("none", |nation| nation.name()), duration),
+                ),
+                WorldEventKind::PolicyShift { nation, doctrine_label, focus_label, reason, .. } => (
+                    nation.name().to_string(),
+                    format!("focus → {} · {}", focus_label, doctrine_label),
+                    reason.clone(),
+                ),
+                WorldEventKind::Treaty { party, counterparty, treaty_label, signed, reason, .. } => (
+                    party.name().to_string(),
+                    format!("{} {} · {}", if *signed { "signs with" } else { "breaks with" }, counterparty.name(), treaty_label),
+                    reason.clone().unwrap_or_default(),
                 ),
-                WorldEventKind::Warfare { winner, loser, territory_change } => (
+                WorldEventKind::ArmyClash { winner, loser, position, winner_losses, loser_losses, routed } => (
                     winner.name().to_string(),
-                    format!("vs {}", loser.name()),
-                    format!("+{:.2} territory", territory_change),
+                    format!(
+                        "{} {} at ({}, {})",
+                        if *routed { "destroys" } else { "repels" },
+                        loser.name(),
+                        position.q,
+                        position.r
+                    ),
+                    format!("losses {:.0} / {:.0}", winner_losses, loser_losses),
+                ),
+                WorldEventKind::Rebellion { nation, rebels, reborn, hexes, defectors, .. } => (
+                    rebels.name().to_string(),
+                    format!("{} from {}", if *reborn { "reborn" } else { "breaks away" }, nation.name()),
+                    format!("{} hexes · {} defectors", hexes, defectors),
+                ),
+                WorldEventKind::Succession { nation, stability, reason } => (
+                    nation.name().to_string(),
+                    format!("regime falls at stability {:.0}", stability),
+                    reason.clone(),
+                ),
+                WorldEventKind::Collapse { nation, conqueror, absorbed, migrated } => (
+                    nation.name().to_string(),
+                    format!("falls to {}", conqueror.map_or("none", |nation| nation.name())),
+                    format!("{} absorbed · {} migrated", absorbed, migrated),
+                ),
+                WorldEventKind::CulturalShift { nation, from, hexes, defected } => (
+                    nation.name().to_string(),
+                    if *defected {
+                        format!("wins over {} peacefully", from.name())
+                    } else {
+                        format!("assimilates {} culture", from.name())
+                    },
+                    format!("{} hexes", hexes),
+                ),
+                WorldEventKind::Debasement { nation, silver, minted, price_level } => (
+                    nation.name().to_string(),
+                    format!("debases coin to {:.0}% silver", silver * 100.0),
+                    format!("{:.0} coin minted · prices {:.2}", minted, price_level),
+                ),
+                WorldEventKind::Epidemic { nation, infected, population, cases, deaths, ended } => (
+                    nation.name().to_string(),
+                    if *ended { "epidemic subsides".to_string() } else { "epidemic breaks out".to_string() },
+                    if *ended {
+                        format!("{} cases · peak {} · {} dead", cases, infected, deaths)
+                    } else {
+                        format!("{} sick of {}", infected, population)
+                    },
+                ),
+                WorldEventKind::Breakthrough { nation, tech, line_label, effects, .. } => (
+                    nation.name().to_string(),
+                    format!("discovers {} · {}", tech, line_label),
+                    effects.join(", "),
+                ),
+                WorldEventKind::Crafting { artisan, recipe, output, quantity } => (
+                    artisan.name.clone(),
+                    recipe.clone(),
+                    format!("{} x{}", output, quantity),
+                ),
+                WorldEventKind::Death { npc, cause_label, heir, .. } => (
+                    npc.name.clone(),
+                    cause_label.clone(),
+                    heir.clone().unwrap_or_else(|| "소실".to_string()),
+                ),
+                WorldEventKind::Demographics { nation, births, immigrants, emigrants, population } => (
+                    nation.name().to_string(),
+                    format!("+{} born, +{} in, -{} out", births, immigrants, emigrants),
+                    format!("{} people", population),
+                ),
+                WorldEventKind::Combat { attacker, defender, outcome_label, loot_currency, .. } => (
+                    attacker.name.clone(),
+                    format!("vs {} · {}", defender.name, outcome_label),
+                    format!("loot {:.1}", loot_currency),
                 ),
             };
 
@@ -132,7 +305,13 @@ pub fn render(frame: &mut Frame, snapshot: &ObserverSnapshot, tick_duration: Dur
     frame.render_widget(table, content_layout[1]);
 }
 
-fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSnapshot, tick_duration: Duration) {
+fn render_world_state_panel(
+    frame: &mut Frame,
+    area: Rect,
+    snapshot: &ObserverSnapshot,
+    tick_duration: Duration,
+    weather_overlay: bool,
+) {
     let outer_block = Block::default().title("World State").borders(Borders::ALL);
     frame.render_widget(outer_block, area);
 
@@ -142,7 +321,7 @@ fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSn
         .constraints([
             Constraint::Length(2),
             Constraint::Min(0),
-            Constraint::Length(3),
+            Constraint::Length(4),
         ])
         .split(area);
 
@@ -161,15 +340,11 @@ fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSn
 
     let nations_layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-            Constraint::Ratio(1, 3),
-        ])
+        .constraints(vec![Constraint::Ratio(1, nations.len().max(1) as u32); nations.len().max(1)])
         .split(panel_layout[1]);
 
     for (i, &nation) in nations.iter().enumerate() {
-        if i >= nations_layout.len() { break; } // Avoid panic if more than 3 nations
+        if i >= nations_layout.len() { break; }
 
         if let Some(metrics) = snapshot.all_metrics.0.get(&nation) {
             let nation_color = nation.color();
@@ -191,6 +366,18 @@ fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSn
                 nation_lines.push(create_bar(metrics.science, 100.0, 10, nation_color));
                 nation_lines.push(Line::from(Span::styled("  문화 (Culture)", Style::default())));
                 nation_lines.push(create_bar(metrics.culture, 100.0, 10, nation_color));
+                let (held, foreign, kin) = snapshot.grid.hexes.values().fold((0, 0, 0), |(held, foreign, kin), hex| {
+                    (
+                        held + usize::from(hex.owner == nation),
+                        foreign + usize::from(hex.owner == nation && hex.culture != nation),
+                        kin + usize::from(hex.culture == nation),
+                    )
+                });
+                nation_lines.push(Line::from(format!(
+                    "  문화권 {} · 이민족 {:.0}%",
+                    kin,
+                    foreign as f32 / held.max(1) as f32 * 100.0
+                )));
                 nation_lines.push(Line::from(Span::styled("  외교 (Diplomacy)", Style::default())));
                 nation_lines.push(create_bar(metrics.diplomacy, 100.0, 10, nation_color));
                 nation_lines.push(Line::from(Span::styled("  종교 (Religion)", Style::default())));
@@ -199,6 +386,99 @@ fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSn
                 nation_lines.push(create_bar(metrics.military, 100.0, 10, nation_color));
                 nation_lines.push(Line::from(Span::styled("  영토 (Territory)", Style::default())));
                 nation_lines.push(create_bar(metrics.territory, 100.0, 10, nation_color));
+                nation_lines.push(Line::from(Span::styled("  안정 (Stability)", Style::default())));
+                nation_lines.push(create_bar(metrics.stability, 100.0, 10, nation_color));
+                if let Some(strategy) = snapshot.strategies.0.get(&nation) {
+                    nation_lines.push(Line::from(format!(
+                        "  {} · 중점 {} {:.0}%",
+                        doctrine_label(strategy.doctrine),
+                        budget_line_label(strategy.focus),
+                        strategy.share(strategy.focus) * 100.0
+                    )));
+                }
+                if let Some(treasury) = snapshot.treasuries.0.get(&nation) {
+                    let style = if treasury.balance < 0.0 { Style::default().fg(Color::Red) } else { Style::default() };
+                    nation_lines.push(Line::from(Span::styled(
+                        format!(
+                            "  국고 {:.0} · 세입 {:.1} · 세출 {:.1}",
+                            treasury.balance, treasury.revenue, treasury.expenses
+                        ),
+                        style,
+                    )));
+                    let coinage = &treasury.coinage;
+                    let style = if coinage.inflation > 0.1 { Style::default().fg(Color::Yellow) } else { Style::default() };
+                    nation_lines.push(Line::from(Span::styled(
+                        format!(
+                            "  물가 {:.2} · 은 {:.0}% · 환율 {:.2}",
+                            coinage.price_level,
+                            coinage.silver * 100.0,
+                            treasury.exchange_rate()
+                        ),
+                        style,
+                    )));
+                }
+                let striking: Vec<String> = snapshot
+                    .shocks
+                    .0
+                    .iter()
+                    .filter(|active| active.nations.contains(&nation))
+                    .map(|active| format!("{} {}틱", active.label, active.ends_at.saturating_sub(snapshot.tick)))
+                    .collect();
+                if !striking.is_empty() {
+                    nation_lines.push(Line::from(Span::styled(
+                        format!("  충격 {}", striking.join(" · ")),
+                        Style::default().fg(Color::LightRed),
+                    )));
+                }
+                if let Some(sample) = snapshot.epidemics.latest(nation).filter(|sample| sample.infected > 0) {
+                    let style = if snapshot.epidemics.outbreak(nation).is_some() {
+                        Style::default().fg(Color::LightRed)
+                    } else {
+                        Style::default().fg(Color::DarkGray)
+                    };
+                    nation_lines.push(Line::from(Span::styled(
+                        format!(
+                            "  질병 감수성 {} · 감염 {} · 회복 {}",
+                            sample.susceptible, sample.infected, sample.recovered
+                        ),
+                        style,
+                    )));
+                }
+                if let Some(state) = snapshot.research.0.get(&nation) {
+                    nation_lines.push(Line::from(format!(
+                        "  기술 {} · 연구 {}",
+                        state.known.len(),
+                        state.current.unwrap_or("-")
+                    )));
+                }
+                let (fielded, soldiers) = snapshot
+                    .armies
+                    .iter()
+                    .filter(|army| army.nation == nation)
+                    .fold((0, 0.0), |(count, size), army| (count + 1, size + army.size));
+                if fielded > 0 {
+                    nation_lines.push(Line::from(format!("  군대 {} · 병력 {:.0}", fielded, soldiers)));
+                }
+                for entry in snapshot.diplomacy.iter().filter(|entry| entry.nations.0 == nation || entry.nations.1 == nation) {
+                    if entry.treaties.is_empty() {
+                        continue;
+                    }
+                    let other = if entry.nations.0 == nation { entry.nations.1 } else { entry.nations.0 };
+                    let treaties: Vec<&str> = entry.treaties.iter().map(|kind| treaty_label(*kind)).collect();
+                    nation_lines.push(Line::from(vec![
+                        Span::styled("  ", Style::default()),
+                        Span::styled(other.name(), Style::default().fg(other.color())),
+                        Span::from(format!(" {} · 호감 {:+.0}", treaties.join(", "), entry.opinion)),
+                    ]));
+                }
+                for war in snapshot.wars.iter().filter(|war| war.involves(nation)) {
+                    let enemy = war.opponent_of(nation);
+                    nation_lines.push(Line::from(vec![
+                        Span::styled("  전쟁 vs ", Style::default().fg(Color::Red)),
+                        Span::styled(enemy.name(), Style::default().fg(enemy.color())),
+                        Span::from(format!(" {:+.0}", war.score_for(nation))),
+                    ]));
+                }
             }
             let nation_paragraph = Paragraph::new(nation_lines).scroll((0, 0));
             frame.render_widget(nation_paragraph, nations_layout[i]);
@@ -218,14 +498,53 @@ fn render_world_state_panel(frame: &mut Frame, area: Rect, snapshot: &ObserverSn
         Span::styled("+", Style::default().fg(Color::Green).bold()),
         Span::from("] ["),
         Span::styled("R", Style::default().fg(Color::Yellow).bold()),
+        Span::from("] ["),
+        Span::styled("W", Style::default().fg(Color::Cyan).bold()),
         Span::from("]"),
     ]));
+    // With the overlay on, the last line doubles as the map's weather legend.
+    if weather_overlay {
+        speed_lines.push(Line::from(
+            Weather::ALL
+                .iter()
+                .flat_map(|weather| {
+                    [
+                        Span::styled(weather_glyph(*weather), Style::default().fg(weather_color(*weather)).bold()),
+                        Span::from(format!("{} ", weather_label(*weather))),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ));
+    } else {
+        speed_lines.push(Line::from(Span::styled("[W] 날씨 지도", Style::default().fg(Color::DarkGray))));
+    }
     let speed_paragraph = Paragraph::new(speed_lines);
     frame.render_widget(speed_paragraph, panel_layout[2]);
 }
 
 struct MapWidget<'a> {
     snapshot: &'a ObserverSnapshot,
+    weather_overlay: bool,
+}
+
+fn weather_glyph(weather: Weather) -> &'static str {
+    match weather {
+        Weather::Clear => "··",
+        Weather::Rain => "∴∴",
+        Weather::Heat => "^^",
+        Weather::Storm => "≈≈",
+        Weather::Sandstorm => "░░",
+    }
+}
+
+fn weather_color(weather: Weather) -> Color {
+    match weather {
+        Weather::Clear => Color::White,
+        Weather::Rain => Color::LightBlue,
+        Weather::Heat => Color::LightRed,
+        Weather::Storm => Color::LightMagenta,
+        Weather::Sandstorm => Color::Yellow,
+    }
 }
 
 impl<'a> Widget for MapWidget<'a> {
@@ -242,21 +561,52 @@ impl<'a> Widget for MapWidget<'a> {
             let screen_x = center_x as i32 + (coord.q * hex_width) + (coord.r * (hex_width / 2));
             let screen_y = center_y as i32 + (coord.r * hex_height * 3 / 4);
 
-            let hex_char = "██";
+            let mut hex_char = "██";
+            let mut style = Style::default();
 
             let mut color = hex.owner.color();
 
+            // The weather overlay draws each hex's skies over its owner's colour.
+            if self.weather_overlay {
+                let weather = self.snapshot.weather.weather(coord);
+                hex_char = weather_glyph(weather);
+                style = style.bg(color).bold();
+                color = weather_color(weather);
+            }
+
             // Twinkling effect for combat zones
-            if self.snapshot.combat_hexes.contains(&coord) {
-                if self.snapshot.tick % 2 == 0 {
-                    color = Color::White; // Bright color for twinkling
-                }
+            if self.snapshot.combat_hexes.contains(&coord) && self.snapshot.tick.is_multiple_of(2) {
+                color = Color::White; // Bright color for twinkling
             }
 
             // Draw the hex character
             if screen_x >= area.x as i32 && screen_x + hex_width <= (area.x + area.width) as i32 &&
                screen_y >= area.y as i32 && screen_y + hex_height <= (area.y + area.height) as i32 {
-                buf.set_string(screen_x as u16, screen_y as u16, hex_char, Style::default().fg(color));
+                buf.set_string(screen_x as u16, screen_y as u16, hex_char, style.fg(color));
+            }
+        }
+
+        // Armies are drawn over the hex they stand on, in their nation's colour.
+        for army in &self.snapshot.armies {
+            let coord = army.position;
+            let screen_x = center_x as i32 + (coord.q * 4) + (coord.r * 2);
+            let screen_y = center_y as i32 + (coord.r * 2 * 3 / 4);
+            if screen_x >= area.x as i32 && screen_x + 2 <= (area.x + area.width) as i32 &&
+               screen_y >= area.y as i32 && screen_y < (area.y + area.height) as i32 {
+                let style = Style::default().fg(Color::White).bg(army.nation.color()).bold();
+                buf.set_string(screen_x as u16, screen_y as u16, "⚔ ", style);
+            }
+        }
+
+        // Pilgrim bands walk beneath a sun in their nation's colour.
+        for band in &self.snapshot.pilgrimages {
+            let coord = band.position;
+            let screen_x = center_x as i32 + (coord.q * 4) + (coord.r * 2);
+            let screen_y = center_y as i32 + (coord.r * 2 * 3 / 4);
+            if screen_x >= area.x as i32 && screen_x + 2 <= (area.x + area.width) as i32 &&
+               screen_y >= area.y as i32 && screen_y < (area.y + area.height) as i32 {
+                let style = Style::default().fg(band.nation.color()).bold();
+                buf.set_string(screen_x as u16, screen_y as u16, "☀ ", style);
             }
         }
     }